@@ -8,7 +8,7 @@ fn add_functions<R>(name: &'static str, bundle: &mut FluentBundle<R>) {
     match name {
         "preferences" => {
             bundle
-                .add_function("PLATFORM", |_args, _named_args| "linux".into())
+                .add_function("PLATFORM", |_args, _named_args| Ok("linux".into()))
                 .expect("Failed to add a function to the bundle.");
         }
         _ => {}
@@ -9,8 +9,8 @@
 // Lastly, we'll also create a new formatter which will be memoizable.
 //
 // The type and its options are modelled after ECMA402 Intl.DateTimeFormat.
-use intl_memoizer_for_carbide::Memoizable;
 use icu::locid::Locale;
+use intl_memoizer_for_carbide::Memoizable;
 
 use fluent_bundle_for_carbide::types::FluentType;
 use fluent_bundle_for_carbide::{FluentArgs, FluentBundle, FluentResource, FluentValue};
@@ -105,7 +105,10 @@ impl FluentType for DateTime {
     fn duplicate(&self) -> Box<dyn FluentType + Send> {
         Box::new(DateTime::new(self.epoch, DateTimeOptions::default()))
     }
-    fn as_string(&self, intls: &intl_memoizer_for_carbide::IntlLangMemoizer) -> std::borrow::Cow<'static, str> {
+    fn as_string(
+        &self,
+        intls: &intl_memoizer_for_carbide::IntlLangMemoizer,
+    ) -> std::borrow::Cow<'static, str> {
         intls
             .with_try_get::<DateTimeFormatter, _, _>((self.options.clone(),), |dtf| {
                 dtf.format(self.epoch).into()
@@ -169,9 +172,9 @@ key-date = Today is { DATETIME($epoch, dateStyle: "long", timeStyle: "short") }
             Some(FluentValue::Number(n)) => {
                 let epoch = n.value as usize;
                 let options = named.into();
-                FluentValue::Custom(Box::new(DateTime::new(epoch, options)))
+                Ok(FluentValue::Custom(Box::new(DateTime::new(epoch, options))))
             }
-            _ => FluentValue::Error,
+            _ => Ok(FluentValue::Error),
         })
         .expect("Failed to add a function.");
     bundle.set_use_isolating(false);
@@ -1,4 +1,4 @@
-use fluent_bundle_for_carbide::{FluentArgs, FluentBundle, FluentResource, FluentValue};
+use fluent_bundle_for_carbide::{FluentArgs, FluentBundle, FluentResource};
 use icu::locid::locale;
 
 fn main() {
@@ -20,8 +20,7 @@ unread-emails =
         .add_resource(res)
         .expect("Failed to add FTL resources to the bundle.");
 
-    let mut args = FluentArgs::new();
-    args.set("name", FluentValue::from("John"));
+    let args = FluentArgs::new().with("name", "John");
 
     let msg = bundle
         .get_message("hello-world")
@@ -37,8 +36,7 @@ unread-emails =
     let value = bundle.format_pattern(pattern, Some(&args), &mut errors);
     println!("{}", value);
 
-    let mut args = FluentArgs::new();
-    args.set("emailCount", 1);
+    let args = FluentArgs::new().with("emailCount", 1u32);
 
     let msg = bundle
         .get_message("unread-emails")
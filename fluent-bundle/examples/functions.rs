@@ -1,4 +1,4 @@
-use fluent_bundle_for_carbide::{FluentBundle, FluentResource, FluentValue};
+use fluent_bundle_for_carbide::{FluentBundle, FluentFunctionError, FluentResource, FluentValue};
 use icu::locid::locale;
 
 fn main() {
@@ -16,7 +16,7 @@ fn main() {
 
     // Test for a simple function that returns a string
     bundle
-        .add_function("HELLO", |_args, _named_args| "I'm a function!".into())
+        .add_function("HELLO", |_args, _named_args| Ok("I'm a function!".into()))
         .expect("Failed to add a function to the bundle.");
 
     // Test for a function that accepts unnamed positional arguments
@@ -24,23 +24,23 @@ fn main() {
         .add_function("MEANING_OF_LIFE", |args, _named_args| {
             if let Some(arg0) = args.get(0) {
                 if *arg0 == 42.into() {
-                    return "The answer to life, the universe, and everything".into();
+                    return Ok("The answer to life, the universe, and everything".into());
                 }
             }
 
-            FluentValue::None
+            Ok(FluentValue::None)
         })
         .expect("Failed to add a function to the bundle.");
 
-    // Test for a function that accepts named arguments
+    // Test for a function that accepts named arguments, and fails without them
     bundle
         .add_function("BASE_OWNERSHIP", |_args, named_args| {
-            return match named_args.get("ownership") {
+            match named_args.get("ownership") {
                 Some(FluentValue::String(ref string)) => {
-                    format!("All your base belong to {}", string).into()
+                    Ok(format!("All your base belong to {}", string).into())
                 }
-                _ => FluentValue::Error,
-            };
+                _ => Err(FluentFunctionError::new("The ownership is missing.")),
+            }
         })
         .expect("Failed to add a function to the bundle.");
 
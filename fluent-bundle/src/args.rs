@@ -1,6 +1,11 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::iter::FromIterator;
 
+#[cfg(feature = "serde_json")]
+use crate::errors::FluentArgsError;
+#[cfg(feature = "serde_json")]
+use crate::types::FluentListOptions;
 use crate::types::FluentValue;
 
 /// Fluent messages can use arguments in order to programmatically add values to a
@@ -79,6 +84,41 @@ impl<'args> FluentArgs<'args> {
         }
     }
 
+    /// Gets a mutable reference to the [`FluentValue`] at the `key` if it exists.
+    pub fn get_mut<K>(&mut self, key: K) -> Option<&mut FluentValue<'args>>
+    where
+        K: Into<Cow<'args, str>>,
+    {
+        let key = key.into();
+        if let Ok(idx) = self.0.binary_search_by_key(&&key, |(k, _)| k) {
+            Some(&mut self.0[idx].1)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if there is a value at the `key`.
+    pub fn contains_key<K>(&self, key: K) -> bool
+    where
+        K: Into<Cow<'args, str>>,
+    {
+        let key = key.into();
+        self.0.binary_search_by_key(&&key, |(k, _)| k).is_ok()
+    }
+
+    /// Removes the value at the `key`, returning it if it existed.
+    pub fn remove<K>(&mut self, key: K) -> Option<FluentValue<'args>>
+    where
+        K: Into<Cow<'args, str>>,
+    {
+        let key = key.into();
+        if let Ok(idx) = self.0.binary_search_by_key(&&key, |(k, _)| k) {
+            Some(self.0.remove(idx).1)
+        } else {
+            None
+        }
+    }
+
     /// Sets the key value pair.
     pub fn set<K, V>(&mut self, key: K, value: V)
     where
@@ -92,6 +132,24 @@ impl<'args> FluentArgs<'args> {
         };
     }
 
+    /// Sets the key value pair and returns the arguments, allowing calls to be chained.
+    ///
+    /// ```
+    /// use fluent_bundle::FluentArgs;
+    ///
+    /// let args = FluentArgs::new()
+    ///     .with("name", "John")
+    ///     .with("emailCount", 1u32);
+    /// ```
+    pub fn with<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<Cow<'args, str>>,
+        V: Into<FluentValue<'args>>,
+    {
+        self.set(key, value);
+        self
+    }
+
     /// Iterate over a tuple of the key an [`FluentValue`].
     pub fn iter(&self) -> impl Iterator<Item = (&str, &FluentValue)> {
         self.0.iter().map(|(k, v)| (k.as_ref(), v))
@@ -122,6 +180,173 @@ where
     }
 }
 
+impl<'args, K, V> Extend<(K, V)> for FluentArgs<'args>
+where
+    K: Into<Cow<'args, str>>,
+    V: Into<FluentValue<'args>>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        for (k, v) in iter {
+            self.set(k, v);
+        }
+    }
+}
+
+impl<'args, K, V, S> From<HashMap<K, V, S>> for FluentArgs<'args>
+where
+    K: Into<Cow<'args, str>>,
+    V: Into<FluentValue<'args>>,
+{
+    fn from(map: HashMap<K, V, S>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+/// The arguments are serialized as a map from the argument names to the values.
+#[cfg(feature = "serde")]
+impl<'args> serde::Serialize for FluentArgs<'args> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'args> serde::Deserialize<'de> for FluentArgs<'args> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ArgsVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ArgsVisitor {
+            type Value = Vec<(String, FluentValue<'static>)>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map of arguments")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut args = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some(entry) = map.next_entry()? {
+                    args.push(entry);
+                }
+                Ok(args)
+            }
+        }
+
+        Ok(deserializer
+            .deserialize_map(ArgsVisitor)?
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<'args> FluentArgs<'args> {
+    /// Converts a JSON object into arguments, e.g. from the body of a request.
+    ///
+    /// Strings and numbers become [`FluentValue::String`] and [`FluentValue::Number`],
+    /// booleans the strings `"true"` and `"false"`, and arrays [`FluentValue::List`]s.
+    /// Nested objects are flattened, `{"user": {"name": "John"}}` becomes the argument
+    /// `$user.name`. `null` is an error, as it has no value.
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentArgs, FluentValue};
+    ///
+    /// let json = serde_json::json!({ "name": "John", "emailCount": 5 });
+    /// let args = FluentArgs::from_json(&json).expect("The arguments are valid.");
+    ///
+    /// assert_eq!(args.get("name"), Some(&FluentValue::from("John")));
+    /// assert_eq!(args.get("emailCount"), Some(&FluentValue::from(5)));
+    /// ```
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, FluentArgsError> {
+        let serde_json::Value::Object(object) = value else {
+            return Err(FluentArgsError::NotAnObject);
+        };
+
+        let mut args = FluentArgs::with_capacity(object.len());
+        args.insert_json_object("", object)?;
+        Ok(args)
+    }
+
+    fn insert_json_object(
+        &mut self,
+        prefix: &str,
+        object: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(), FluentArgsError> {
+        for (key, value) in object {
+            let key = format!("{}{}", prefix, key);
+            match value {
+                serde_json::Value::Object(object) => {
+                    self.insert_json_object(&format!("{}.", key), object)?;
+                }
+                value => {
+                    let value = json_to_value(value).ok_or_else(|| match value {
+                        serde_json::Value::Null => FluentArgsError::Null(key.clone()),
+                        _ => FluentArgsError::InvalidListItem(key.clone()),
+                    })?;
+                    self.set(key, value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Converts the arguments into a JSON object, the inverse of [`FluentArgs::from_json`].
+    ///
+    /// Flattened keys are kept as they are, and the values that have no JSON
+    /// equivalent, e.g. datetimes, are written as strings.
+    pub fn to_json(&self) -> serde_json::Value {
+        self.iter()
+            .map(|(key, value)| (key.to_string(), value_to_json(value)))
+            .collect::<serde_json::Map<_, _>>()
+            .into()
+    }
+}
+
+/// Returns `None` for `null` and objects, which have no [`FluentValue`].
+#[cfg(feature = "serde_json")]
+fn json_to_value(value: &serde_json::Value) -> Option<FluentValue<'static>> {
+    Some(match value {
+        serde_json::Value::String(s) => FluentValue::from(s.clone()),
+        serde_json::Value::Number(n) => {
+            if let Some(n) = n.as_i64() {
+                FluentValue::from(n)
+            } else if let Some(n) = n.as_u64() {
+                FluentValue::from(n)
+            } else {
+                FluentValue::from(n.as_f64()?)
+            }
+        }
+        serde_json::Value::Bool(b) => FluentValue::from(b.to_string()),
+        serde_json::Value::Array(items) => FluentValue::List(
+            items.iter().map(json_to_value).collect::<Option<_>>()?,
+            FluentListOptions::default(),
+        ),
+        serde_json::Value::Null | serde_json::Value::Object(_) => return None,
+    })
+}
+
+#[cfg(feature = "serde_json")]
+fn value_to_json(value: &FluentValue) -> serde_json::Value {
+    match value {
+        FluentValue::String(s) => serde_json::Value::from(s.as_ref()),
+        // Whole numbers are written without a fraction, so they read back the same.
+        FluentValue::Number(n) if n.value.fract() == 0.0 && n.value.abs() < i64::MAX as f64 => {
+            serde_json::Value::from(n.value as i64)
+        }
+        // Non-finite numbers become `null`.
+        FluentValue::Number(n) => serde_json::Value::from(n.value),
+        FluentValue::List(items, _) => items.iter().map(value_to_json).collect(),
+        FluentValue::None | FluentValue::Error => serde_json::Value::Null,
+        value => serde_json::Value::from(value.to_string()),
+    }
+}
+
 impl<'args> IntoIterator for FluentArgs<'args> {
     type Item = (Cow<'args, str>, FluentValue<'args>);
     type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -146,7 +371,10 @@ mod tests {
             args.get("name"),
             Some(&FluentValue::String(Cow::Borrowed("John")))
         );
-        assert_eq!(args.get("emailCount"), Some(&FluentValue::try_number("5")));
+        assert_eq!(
+            args.get("emailCount"),
+            Some(&FluentValue::number_unchecked("5"))
+        );
 
         args.set("name", "Jane");
         args.set("emailCount", 7);
@@ -155,6 +383,150 @@ mod tests {
             args.get("name"),
             Some(&FluentValue::String(Cow::Borrowed("Jane")))
         );
-        assert_eq!(args.get("emailCount"), Some(&FluentValue::try_number("7")));
+        assert_eq!(
+            args.get("emailCount"),
+            Some(&FluentValue::number_unchecked("7"))
+        );
+    }
+
+    #[test]
+    fn modify_arguments() {
+        let mut args = FluentArgs::new();
+
+        args.set("name", "John");
+        args.set("emailCount", 5);
+        assert!(args.contains_key("name"));
+        assert!(!args.contains_key("user"));
+
+        if let Some(FluentValue::String(name)) = args.get_mut("name") {
+            *name = Cow::Borrowed("Jane");
+        }
+        assert_eq!(
+            args.get("name"),
+            Some(&FluentValue::String(Cow::Borrowed("Jane")))
+        );
+        assert_eq!(args.get_mut("user"), None);
+
+        assert_eq!(
+            args.remove("emailCount"),
+            Some(FluentValue::number_unchecked("5"))
+        );
+        assert_eq!(args.remove("emailCount"), None);
+        assert!(!args.contains_key("emailCount"));
+        assert_eq!(args.0.len(), 1);
+    }
+
+    #[test]
+    fn collect_arguments() {
+        let mut args: FluentArgs = vec![("name", "John"), ("user", "Jane")]
+            .into_iter()
+            .collect();
+        assert_eq!(args.0.len(), 2);
+
+        args.extend(vec![("name".to_string(), FluentValue::from("Jim"))]);
+        assert_eq!(args.0.len(), 2);
+        assert_eq!(args.get("name"), Some(&FluentValue::from("Jim")));
+
+        let mut map = HashMap::new();
+        map.insert("emailCount".to_string(), 5);
+        map.insert("unreadCount".to_string(), 2);
+        let args = FluentArgs::from(map);
+        assert_eq!(
+            args.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+            vec!["emailCount", "unreadCount"]
+        );
+        assert_eq!(
+            args.get("unreadCount"),
+            Some(&FluentValue::number_unchecked("2"))
+        );
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn json_arguments() {
+        let json = serde_json::json!({
+            "name": "John",
+            "emailCount": 5,
+            "ratio": 0.5,
+            "admin": true,
+            "tags": ["a", 1, ["b"]],
+            "user": { "id": 7, "address": { "city": "Oslo" } },
+        });
+        let args = FluentArgs::from_json(&json).expect("The arguments are valid.");
+
+        assert_eq!(args.get("name"), Some(&FluentValue::from("John")));
+        assert_eq!(
+            args.get("emailCount"),
+            Some(&FluentValue::number_unchecked("5"))
+        );
+        assert_eq!(args.get("ratio"), Some(&FluentValue::from(0.5)));
+        assert_eq!(args.get("admin"), Some(&FluentValue::from("true")));
+        assert_eq!(
+            args.get("tags"),
+            Some(&FluentValue::List(
+                vec![
+                    FluentValue::from("a"),
+                    FluentValue::from(1),
+                    FluentValue::List(vec![FluentValue::from("b")], Default::default()),
+                ],
+                Default::default()
+            ))
+        );
+        assert_eq!(args.get("user.id"), Some(&FluentValue::from(7)));
+        assert_eq!(
+            args.get("user.address.city"),
+            Some(&FluentValue::from("Oslo"))
+        );
+        assert!(!args.contains_key("user"));
+
+        assert_eq!(
+            args.to_json(),
+            serde_json::json!({
+                "name": "John",
+                "emailCount": 5,
+                "ratio": 0.5,
+                "admin": "true",
+                "tags": ["a", 1, ["b"]],
+                "user.id": 7,
+                "user.address.city": "Oslo",
+            })
+        );
+
+        assert_eq!(
+            FluentArgs::from_json(&serde_json::json!(["John"])).unwrap_err(),
+            FluentArgsError::NotAnObject
+        );
+        assert_eq!(
+            FluentArgs::from_json(&serde_json::json!({ "user": { "name": null } })).unwrap_err(),
+            FluentArgsError::Null("user.name".into())
+        );
+        assert_eq!(
+            FluentArgs::from_json(&serde_json::json!({ "tags": [{}] })).unwrap_err(),
+            FluentArgsError::InvalidListItem("tags".into())
+        );
+    }
+
+    #[test]
+    fn chain_numeric_arguments() {
+        let args = FluentArgs::new()
+            .with("i8", 1i8)
+            .with("i16", 1i16)
+            .with("i32", 1i32)
+            .with("i64", 1i64)
+            .with("i128", 1i128)
+            .with("isize", 1isize)
+            .with("u8", 1u8)
+            .with("u16", 1u16)
+            .with("u32", 1u32)
+            .with("u64", 1u64)
+            .with("u128", 1u128)
+            .with("usize", 1usize)
+            .with("f32", 1f32)
+            .with("f64", 1f64);
+
+        assert_eq!(args.0.len(), 14);
+        for (_, value) in args.iter() {
+            assert_eq!(value, &FluentValue::number_unchecked("1"));
+        }
     }
 }
@@ -7,23 +7,32 @@
 use rustc_hash::FxHashMap;
 use std::borrow::Borrow;
 use std::borrow::Cow;
+use std::cell::RefCell;
 use std::collections::hash_map::Entry as HashEntry;
 use std::default::Default;
 use std::fmt;
+use std::io;
+use std::sync::Arc;
 
 use fluent_syntax_for_carbide::ast;
-use intl_memoizer_for_carbide::IntlLangMemoizer;
 use icu::locid::Locale;
+use icu::plurals::{PluralCategory, PluralRuleType};
+use intl_memoizer_for_carbide::IntlLangMemoizer;
 
 use crate::args::FluentArgs;
 use crate::entry::Entry;
 use crate::entry::GetEntry;
-use crate::errors::{EntryKind, FluentError};
+use crate::errors::{EntryKind, FluentError, FluentFunctionError, FluentWarning};
 use crate::memoizer::MemoizerKind;
-use crate::message::FluentMessage;
-use crate::resolver::{ResolveValue, Scope, WriteValue};
+use crate::message::{FluentMessage, FluentTerm};
+use crate::parts::{FluentPart, PartsSink, PartsWriter};
+use crate::resolver::errors::ReferenceKind;
+use crate::resolver::{ResolveValue, ResolverError, Scope, WriteValue};
 use crate::resource::FluentResource;
-use crate::types::FluentValue;
+use crate::types::{
+    plural_category, FluentDate, FluentDateTime, FluentDuration, FluentListOptions,
+    FluentNumberType, FluentRelativeTime, FluentTime, FluentValue,
+};
 
 /// A collection of localization messages for a single locale, which are meant
 /// to be used together in a single view, widget or any other UI abstraction.
@@ -139,6 +148,7 @@ pub struct FluentBundle<R, M> {
     pub(crate) entries: FxHashMap<String, Entry>,
     pub(crate) intls: M,
     pub(crate) use_isolating: bool,
+    pub(crate) max_recursion_depth: usize,
     pub(crate) transform: Option<fn(&str) -> Cow<str>>,
     pub(crate) formatter: Option<fn(&FluentValue, &Locale, &M) -> Option<String>>,
 }
@@ -239,6 +249,14 @@ impl<R, M> FluentBundle<R, M> {
     ///
     /// If any entry in the resource uses the same identifier as an already
     /// existing key in the bundle, the entry will override the previous one.
+    /// This is useful for hot-reloading, where individual messages are updated
+    /// without rebuilding the bundle.
+    ///
+    /// Adding the resource can not fail, so it is always applied as a whole. The
+    /// only exception are messages and terms that share their identifier with a
+    /// registered function: functions are never overridden and those entries are
+    /// skipped. Overridden entries are no longer reachable, but their resource
+    /// stays in the bundle.
     ///
     /// The method can take any type that can be borrowed as FluentResource:
     ///   - FluentResource
@@ -312,11 +330,256 @@ impl<R, M> FluentBundle<R, M> {
                 _ => continue,
             };
 
-            self.entries.insert(id.to_string(), entry);
+            match self.entries.entry(id.to_string()) {
+                HashEntry::Occupied(existing) if matches!(existing.get(), Entry::Function(..)) => {}
+                HashEntry::Occupied(mut existing) => {
+                    existing.insert(entry);
+                }
+                HashEntry::Vacant(empty) => {
+                    empty.insert(entry);
+                }
+            }
         }
         self.resources.push(r);
     }
 
+    /// Combines two bundles into one, where the messages, terms and functions of
+    /// `overlay` take precedence over the ones of `base`.
+    ///
+    /// The new bundle has the locales and settings of `base`, and the resources of
+    /// both bundles. Messages and terms of `overlay` replace the ones of `base` like
+    /// [`FluentBundle::add_resource_overriding`], and functions of `overlay` replace the
+    /// ones of `base` like [`FluentBundle::add_function_override`].
+    ///
+    /// An entry of `overlay` whose name is used by an entry of another kind in `base`,
+    /// such as a message named like a function, is skipped and reported as a
+    /// [`FluentError::Overriding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let core = FluentResource::try_new("hello = Hi!\nbye = Bye!".to_string())
+    ///     .expect("Could not parse an FTL string.");
+    /// let app = FluentResource::try_new("hello = Welcome to the app!".to_string())
+    ///     .expect("Could not parse an FTL string.");
+    ///
+    /// let mut base = FluentBundle::new(vec![locale!("en-US")]);
+    /// base.add_resource(core).expect("Failed to add FTL resources to the bundle.");
+    /// let mut overlay = FluentBundle::new(vec![locale!("en-US")]);
+    /// overlay.add_resource(app).expect("Failed to add FTL resources to the bundle.");
+    ///
+    /// let (bundle, errors) = FluentBundle::merge(base, overlay);
+    /// assert!(errors.is_empty());
+    ///
+    /// let mut errors = vec![];
+    /// assert_eq!(bundle.format_value("hello", None, &mut errors).as_deref(), Some("Welcome to the app!"));
+    /// assert_eq!(bundle.format_value("bye", None, &mut errors).as_deref(), Some("Bye!"));
+    /// ```
+    pub fn merge(base: Self, overlay: Self) -> (Self, Vec<FluentError>) {
+        let mut bundle = base;
+        let mut errors = vec![];
+        let res_offset = bundle.resources.len();
+
+        for (id, entry) in overlay.entries {
+            let entry = match entry {
+                Entry::Message((res_pos, entry_pos)) => {
+                    Entry::Message((res_offset + res_pos, entry_pos))
+                }
+                Entry::Term((res_pos, entry_pos)) => Entry::Term((res_offset + res_pos, entry_pos)),
+                Entry::Function(func) => Entry::Function(func),
+            };
+
+            match bundle.entries.entry(id) {
+                HashEntry::Occupied(mut existing)
+                    if matches!(existing.get(), Entry::Function(_))
+                        == matches!(entry, Entry::Function(_)) =>
+                {
+                    existing.insert(entry);
+                }
+                HashEntry::Occupied(existing) => {
+                    let kind = match entry {
+                        Entry::Message(..) => EntryKind::Message,
+                        Entry::Term(..) => EntryKind::Term,
+                        Entry::Function(..) => EntryKind::Function,
+                    };
+                    errors.push(FluentError::Overriding {
+                        kind,
+                        id: existing.key().clone(),
+                    });
+                }
+                HashEntry::Vacant(empty) => {
+                    empty.insert(entry);
+                }
+            }
+        }
+        bundle.resources.extend(overlay.resources);
+
+        (bundle, errors)
+    }
+
+    /// Creates a copy of the bundle with the `additional` resources added on top, e.g. a
+    /// bundle for a single request that extends a shared bundle with generated messages.
+    ///
+    /// The new bundle has the locales, settings and functions of this bundle, the functions
+    /// are shared rather than copied. Messages and terms of `additional` replace the ones of
+    /// this bundle like [`FluentBundle::add_resource_overriding`].
+    ///
+    /// The resources are cloned, so `R` should be cheap to clone, such as
+    /// `&FluentResource` or [`Arc<FluentResource>`](std::sync::Arc).
+    ///
+    /// # Errors
+    ///
+    /// Messages and terms of `additional` that are named like a function are reported as
+    /// [`FluentError::Overriding`] and are not added. The new bundle is returned together
+    /// with the errors, with all the other entries of `additional` added, like
+    /// [`FluentResource::try_new`] returns the entries it could parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let core = FluentResource::try_new("hello = Hi!\nbye = Bye!".to_string())
+    ///     .expect("Could not parse an FTL string.");
+    /// let request = FluentResource::try_new("hello = Your order was placed!".to_string())
+    ///     .expect("Could not parse an FTL string.");
+    ///
+    /// let mut base = FluentBundle::new(vec![locale!("en-US")]);
+    /// base.add_resource(&core).expect("Failed to add FTL resources to the bundle.");
+    ///
+    /// let bundle = base.clone_with_resources(vec![&request])
+    ///     .unwrap_or_else(|(bundle, _errors)| bundle);
+    ///
+    /// let mut errors = vec![];
+    /// assert_eq!(bundle.format_value("hello", None, &mut errors).as_deref(), Some("Your order was placed!"));
+    /// assert_eq!(bundle.format_value("bye", None, &mut errors).as_deref(), Some("Bye!"));
+    /// assert_eq!(base.format_value("hello", None, &mut errors).as_deref(), Some("Hi!"));
+    /// ```
+    pub fn clone_with_resources(&self, additional: Vec<R>) -> Result<Self, (Self, Vec<FluentError>)>
+    where
+        R: Borrow<FluentResource> + Clone,
+        M: MemoizerKind,
+    {
+        let mut bundle = Self {
+            locales: self.locales.clone(),
+            resources: self.resources.clone(),
+            entries: self.entries.clone(),
+            // The formatters are cached per thread and locale, so a new memoizer reuses them.
+            intls: M::new(self.intls.language().clone()),
+            use_isolating: self.use_isolating,
+            max_recursion_depth: self.max_recursion_depth,
+            transform: self.transform,
+            formatter: self.formatter,
+        };
+        let mut errors = vec![];
+
+        for r in additional {
+            for entry in r.borrow().entries() {
+                let (id, kind) = match entry {
+                    ast::Entry::Message(ast::Message { id, .. }) => (id.name, EntryKind::Message),
+                    ast::Entry::Term(ast::Term { id, .. }) => (id.name, EntryKind::Term),
+                    _ => continue,
+                };
+                if matches!(bundle.entries.get(id), Some(Entry::Function(_))) {
+                    errors.push(FluentError::Overriding {
+                        kind,
+                        id: id.to_string(),
+                    });
+                }
+            }
+            bundle.add_resource_overriding(r);
+        }
+
+        if errors.is_empty() {
+            Ok(bundle)
+        } else {
+            Err((bundle, errors))
+        }
+    }
+
+    /// Adds a resource to the bundle like [`FluentBundle::add_resource`], and then
+    /// checks the bundle for cyclic references with
+    /// [`FluentBundle::detect_circular_references`].
+    ///
+    /// The resource is added even if an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentError, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let resource = FluentResource::try_new("a = { b }\nb = { a }".to_string())
+    ///     .expect("Could not parse an FTL string.");
+    /// let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    ///
+    /// assert_eq!(
+    ///     bundle.add_resource_checked(resource),
+    ///     Err(vec![FluentError::CyclicReference {
+    ///         ids: vec!["a".to_string(), "b".to_string(), "a".to_string()],
+    ///     }])
+    /// );
+    /// ```
+    pub fn add_resource_checked(&mut self, r: R) -> Result<(), Vec<FluentError>>
+    where
+        R: Borrow<FluentResource>,
+    {
+        let mut errors = self.add_resource(r).err().unwrap_or_default();
+        errors.extend(self.detect_circular_references());
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Finds the cycles of messages and terms that reference each other, without
+    /// formatting them.
+    ///
+    /// Every cycle is reported once as a [`FluentError::CyclicReference`], with the
+    /// ids in the form the resolver uses: `msg`, `msg.attr`, `-term` and `-term.attr`.
+    /// All references of a pattern are followed, also the ones in variants that
+    /// might never be selected.
+    ///
+    /// The check walks every pattern of the bundle, so it is not run by
+    /// [`FluentBundle::add_resource`], use [`FluentBundle::add_resource_checked`]
+    /// or call it once all resources are added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentError, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let resource = FluentResource::try_new("
+    /// -brand = { -brand.short }
+    ///     .short = { -brand }
+    /// ok = { -brand }
+    /// ".to_string())
+    ///     .expect("Could not parse an FTL string.");
+    /// let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    /// bundle.add_resource(resource)
+    ///     .expect("Failed to add FTL resources to the bundle.");
+    ///
+    /// assert_eq!(
+    ///     bundle.detect_circular_references(),
+    ///     vec![FluentError::CyclicReference {
+    ///         ids: vec!["-brand".to_string(), "-brand.short".to_string(), "-brand".to_string()],
+    ///     }]
+    /// );
+    /// ```
+    pub fn detect_circular_references(&self) -> Vec<FluentError>
+    where
+        R: Borrow<FluentResource>,
+    {
+        crate::cycles::detect_circular_references(self)
+    }
+
     /// When formatting patterns, `FluentBundle` inserts
     /// Unicode Directionality Isolation Marks to indicate
     /// that the direction of a placeable may differ from
@@ -332,6 +595,14 @@ impl<R, M> FluentBundle<R, M> {
         self.use_isolating = value;
     }
 
+    /// Sets how deeply messages and terms may reference each other while formatting,
+    /// 64 by default. Deeper references are written as their id and reported as
+    /// [`FluentError::RecursionDepthExceeded`], so adversarial resources can not
+    /// overflow the stack.
+    pub fn set_max_recursion_depth(&mut self, depth: usize) {
+        self.max_recursion_depth = depth;
+    }
+
     /// This method allows to specify a function that will
     /// be called on all textual fragments of the pattern
     /// during formatting.
@@ -378,6 +649,97 @@ impl<R, M> FluentBundle<R, M> {
         self.get_entry_message(id).is_some()
     }
 
+    /// Returns true if this bundle contains a term with the given id.
+    ///
+    /// The id is the name of the term without the leading `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let ftl_string = String::from("-brand-name = Firefox");
+    /// let resource = FluentResource::try_new(ftl_string)
+    ///     .expect("Failed to parse an FTL string.");
+    /// let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    /// bundle.add_resource(&resource)
+    ///     .expect("Failed to add FTL resources to the bundle.");
+    /// assert_eq!(true, bundle.has_term("brand-name"));
+    /// assert_eq!(false, bundle.has_message("brand-name"));
+    /// ```
+    pub fn has_term(&self, id: &str) -> bool
+    where
+        R: Borrow<FluentResource>,
+    {
+        self.get_entry_term(id).is_some()
+    }
+
+    /// Returns an iterator over the ids of all messages in this bundle, in no particular order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let ftl_string = String::from("
+    /// hello = Hi!
+    /// goodbye = Bye!
+    /// -brand-name = Firefox
+    /// ");
+    /// let resource = FluentResource::try_new(ftl_string)
+    ///     .expect("Failed to parse an FTL string.");
+    /// let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    /// bundle.add_resource(&resource)
+    ///     .expect("Failed to add FTL resources to the bundle.");
+    ///
+    /// let mut ids = bundle.message_ids().collect::<Vec<_>>();
+    /// ids.sort();
+    /// assert_eq!(ids, ["goodbye", "hello"]);
+    /// ```
+    pub fn message_ids(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().filter_map(|(id, entry)| match entry {
+            Entry::Message(_) => Some(id.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the ids of all terms in this bundle, without the
+    /// leading `-`, in no particular order.
+    pub fn term_ids(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().filter_map(|(id, entry)| match entry {
+            Entry::Term(_) => Some(id.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns an iterator over the names of all functions added to this bundle,
+    /// including the builtin functions, in no particular order.
+    pub fn function_names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().filter_map(|(id, entry)| match entry {
+            Entry::Function(_) => Some(id.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns true if a function with the given name was added to this bundle,
+    /// including the builtin functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let bundle: FluentBundle<FluentResource> = FluentBundle::new(vec![locale!("en-US")]);
+    /// assert_eq!(true, bundle.has_function("NUMBER"));
+    /// assert_eq!(false, bundle.has_function("CUSTOM"));
+    /// ```
+    pub fn has_function(&self, name: &str) -> bool {
+        matches!(self.entries.get(name), Some(Entry::Function(_)))
+    }
+
     /// Retrieves a `FluentMessage` from a bundle.
     ///
     /// # Examples
@@ -406,6 +768,36 @@ impl<R, M> FluentBundle<R, M> {
         self.get_entry_message(id).map(Into::into)
     }
 
+    /// Retrieves a `FluentTerm` from a bundle.
+    ///
+    /// The id is the name of the term without the leading `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let ftl_string = String::from("-brand-name = Firefox");
+    /// let resource = FluentResource::try_new(ftl_string)
+    ///     .expect("Failed to parse an FTL string.");
+    ///
+    /// let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    ///
+    /// bundle.add_resource(&resource)
+    ///     .expect("Failed to add FTL resources to the bundle.");
+    ///
+    /// let term = bundle.get_term("brand-name");
+    /// assert_eq!(term.is_some(), true);
+    /// assert_eq!(bundle.get_message("brand-name").is_some(), false);
+    /// ```
+    pub fn get_term<'l>(&'l self, id: &str) -> Option<FluentTerm<'l>>
+    where
+        R: Borrow<FluentResource>,
+    {
+        self.get_entry_term(id).map(Into::into)
+    }
+
     /// Writes a formatted pattern which comes from a `FluentMessage`.
     ///
     /// # Example
@@ -453,6 +845,71 @@ impl<R, M> FluentBundle<R, M> {
         pattern.write(w, &mut scope)
     }
 
+    /// Appends a formatted pattern which comes from a `FluentMessage` to a byte buffer.
+    ///
+    /// The UTF-8 encoded message is added after any data already present in `buf`,
+    /// which avoids allocating an intermediate `String` when the output ends up
+    /// in a pre-allocated response buffer anyway.
+    pub fn format_pattern_buf<'bundle>(
+        &'bundle self,
+        buf: &mut Vec<u8>,
+        pattern: &'bundle ast::Pattern<&str>,
+        args: Option<&'bundle FluentArgs>,
+        errors: &mut Vec<FluentError>,
+    ) where
+        R: Borrow<FluentResource>,
+        M: MemoizerKind,
+    {
+        let mut scope = Scope::new(self, args, Some(errors));
+        pattern
+            .write(&mut ByteBufWriter(buf), &mut scope)
+            .expect("Failed to write to a byte buffer.");
+    }
+
+    /// Writes a formatted pattern which comes from a `FluentMessage` directly to `writer`,
+    /// without building the whole message in an intermediate `String` first.
+    pub fn format_pattern_to_writer<'bundle, W>(
+        &'bundle self,
+        pattern: &'bundle ast::Pattern<&str>,
+        args: Option<&'bundle FluentArgs>,
+        errors: &mut Vec<FluentError>,
+        writer: &mut W,
+    ) -> fmt::Result
+    where
+        R: Borrow<FluentResource>,
+        W: fmt::Write,
+        M: MemoizerKind,
+    {
+        self.write_pattern(writer, pattern, args, errors)
+    }
+
+    /// Writes a formatted pattern which comes from a `FluentMessage` as UTF-8 to an
+    /// [`io::Write`](std::io::Write) sink, such as a file or a socket.
+    pub fn format_pattern_to_io_writer<'bundle, W>(
+        &'bundle self,
+        pattern: &'bundle ast::Pattern<&str>,
+        args: Option<&'bundle FluentArgs>,
+        errors: &mut Vec<FluentError>,
+        writer: &mut W,
+    ) -> io::Result<()>
+    where
+        R: Borrow<FluentResource>,
+        W: io::Write,
+        M: MemoizerKind,
+    {
+        let mut writer = IoWriter {
+            inner: writer,
+            error: None,
+        };
+
+        match self.write_pattern(&mut writer, pattern, args, errors) {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(writer
+                .error
+                .unwrap_or_else(|| io::Error::other("Failed to format the pattern."))),
+        }
+    }
+
     /// Formats a pattern which comes from a `FluentMessage`.
     ///
     /// # Example
@@ -493,36 +950,283 @@ impl<R, M> FluentBundle<R, M> {
         M: MemoizerKind,
     {
         let mut scope = Scope::new(self, args, Some(errors));
+
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "format_pattern",
+            message.id = tracing::field::Empty,
+            locale = %self.intls.language(),
+        )
+        .entered();
+        // Looking up the id searches the entries, so it is only done for enabled spans.
+        #[cfg(feature = "tracing")]
+        if !span.is_disabled() {
+            if let Some(id) = scope.pattern_id(pattern) {
+                span.record("message.id", id.as_str());
+            }
+        }
+
         let value = pattern.resolve(&mut scope);
-        value.into_string(&scope)
+        value.into_string(&scope).unwrap_or_else(|err| {
+            scope.add_format_error(err);
+            "".into()
+        })
     }
 
-    /// Makes the provided rust function available to messages with the name `id`. See
-    /// the [FTL syntax guide] to learn how these are used in messages.
+    /// Formats a pattern like [`format_pattern`](FluentBundle::format_pattern), but reports
+    /// non-fatal issues as [`FluentWarning`]s instead of errors.
     ///
-    /// FTL functions accept both positional and named args. The rust function you
-    /// provide therefore has two parameters: a slice of values for the positional
-    /// args, and a `FluentArgs` for named args.
+    /// References to missing messages and terms, which are written as their id, go into
+    /// `warnings` together with arguments that were not used, so `errors` only receives
+    /// errors that make the output incorrect.
     ///
-    /// # Examples
+    /// # Example
     ///
     /// ```
-    /// use fluent_bundle::{FluentBundle, FluentResource, FluentValue};
-    /// use icu::locid::langid;
+    /// use fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentWarning};
+    /// use icu::locid::locale;
     ///
-    /// let ftl_string = String::from("length = { STRLEN(\"12345\") }");
-    /// let resource = FluentResource::try_new(ftl_string)
-    ///     .expect("Could not parse an FTL string.");
-    /// let langid_en = langid!("en-US");
-    /// let mut bundle = FluentBundle::new(vec![langid_en]);
+    /// let resource = FluentResource::try_new("hello = Hello, { missing }!".to_string())
+    ///     .expect("Failed to parse an FTL string.");
+    ///
+    /// let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    /// bundle.set_use_isolating(false);
     /// bundle.add_resource(&resource)
     ///     .expect("Failed to add FTL resources to the bundle.");
     ///
-    /// // Register a fn that maps from string to string length
-    /// bundle.add_function("STRLEN", |positional, _named| match positional {
-    ///     [FluentValue::String(str)] => str.len().into(),
-    ///     _ => FluentValue::Error,
-    /// }).expect("Failed to add a function to the bundle.");
+    /// let mut args = FluentArgs::new();
+    /// args.set("name", "John");
+    ///
+    /// let pattern = bundle.get_message("hello").and_then(|msg| msg.value())
+    ///     .expect("Message doesn't exist.");
+    /// let mut errors = vec![];
+    /// let mut warnings = vec![];
+    /// let result = bundle.format_pattern_with_warnings(pattern, Some(&args), &mut errors, &mut warnings);
+    ///
+    /// assert_eq!(result, "Hello, {missing}!");
+    /// assert!(errors.is_empty());
+    /// assert_eq!(warnings.len(), 2);
+    /// assert_eq!(warnings[1], FluentWarning::UnusedArgument("name".to_string()));
+    /// ```
+    pub fn format_pattern_with_warnings<'bundle>(
+        &'bundle self,
+        pattern: &'bundle ast::Pattern<&'bundle str>,
+        args: Option<&FluentArgs>,
+        errors: &mut Vec<FluentError>,
+        warnings: &mut Vec<FluentWarning>,
+    ) -> Cow<'bundle, str>
+    where
+        R: Borrow<FluentResource>,
+        M: MemoizerKind,
+    {
+        let mut scope = Scope::new(self, args, Some(errors));
+        scope.warnings = Some(warnings);
+        let value = pattern.resolve(&mut scope);
+        scope.warn_unused_args();
+        value.into_string(&scope).unwrap_or_else(|err| {
+            scope.add_format_error(err);
+            "".into()
+        })
+    }
+
+    /// Formats a pattern into parts, which tell the text of the pattern apart from the
+    /// pieces of numbers and dates, e.g. to style the currency symbol of a price.
+    ///
+    /// Joining the values of the parts gives the result of
+    /// [`format_pattern`](FluentBundle::format_pattern). Numbers and dates that are only
+    /// formatted in part, e.g. as an argument of a function, and values of a custom
+    /// [`formatter`](FluentBundle::set_formatter) are literal parts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentArgs, FluentBundle, FluentPart, FluentResource, NumberPartType};
+    /// use icu::locid::locale;
+    ///
+    /// let resource = FluentResource::try_new("price = Price: { NUMBER($amount, style: \"currency\", currency: \"USD\") }".to_string())
+    ///     .expect("Failed to parse an FTL string.");
+    ///
+    /// let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    /// bundle.set_use_isolating(false);
+    /// bundle.add_resource(&resource)
+    ///     .expect("Failed to add FTL resources to the bundle.");
+    ///
+    /// let mut args = FluentArgs::new();
+    /// args.set("amount", 1234.5);
+    ///
+    /// let pattern = bundle.get_message("price").and_then(|msg| msg.value())
+    ///     .expect("Message doesn't exist.");
+    /// let mut errors = vec![];
+    /// let parts = bundle.format_to_parts(pattern, Some(&args), &mut errors);
+    ///
+    /// assert_eq!(parts[0], FluentPart::Literal("Price: ".into()));
+    /// assert_eq!(parts[1], FluentPart::Number { value: "$".into(), part_type: NumberPartType::Currency });
+    /// assert_eq!(parts.iter().map(FluentPart::value).collect::<String>(), "Price: $1,234.50");
+    /// ```
+    pub fn format_to_parts(
+        &self,
+        pattern: &ast::Pattern<&str>,
+        args: Option<&FluentArgs>,
+        errors: &mut Vec<FluentError>,
+    ) -> Vec<FluentPart<'static>>
+    where
+        R: Borrow<FluentResource>,
+        M: MemoizerKind,
+    {
+        let parts = RefCell::new(Vec::new());
+        let mut writer = PartsWriter(&parts);
+
+        let mut scope = Scope::new(self, args, Some(errors));
+        scope.parts = Some(PartsSink::new(&writer));
+        pattern
+            .write(&mut writer, &mut scope)
+            .expect("Failed to write to the parts.");
+        drop(scope);
+
+        parts.into_inner()
+    }
+
+    /// Formats the value of the message with the given id, combining
+    /// [`get_message`](FluentBundle::get_message), [`FluentMessage::value`] and
+    /// [`format_pattern`](FluentBundle::format_pattern).
+    ///
+    /// Returns `None` if the message doesn't exist or has no value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let resource = FluentResource::try_new("hello-world = Hello World!".to_string())
+    ///     .expect("Failed to parse an FTL string.");
+    ///
+    /// let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    /// bundle.add_resource(&resource)
+    ///     .expect("Failed to add FTL resources to the bundle.");
+    ///
+    /// let mut errors = vec![];
+    /// let result = bundle.format_value("hello-world", None, &mut errors);
+    ///
+    /// assert_eq!(result.as_deref(), Some("Hello World!"));
+    /// ```
+    pub fn format_value<'bundle>(
+        &'bundle self,
+        id: &str,
+        args: Option<&FluentArgs>,
+        errors: &mut Vec<FluentError>,
+    ) -> Option<Cow<'bundle, str>>
+    where
+        R: Borrow<FluentResource>,
+        M: MemoizerKind,
+    {
+        let pattern = self.get_message(id)?.value()?;
+        Some(self.format_pattern(pattern, args, errors))
+    }
+
+    /// Formats the attribute `attr` of the message with the given id.
+    ///
+    /// Returns `None` if the message or the attribute doesn't exist, and adds a
+    /// [`ResolverError::Reference`] to `errors` whose attribute is `None` if the
+    /// message is missing, and the name of the attribute if only the attribute is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentResource};
+    /// use icu::locid::locale;
+    ///
+    /// let ftl_string = String::from("hello = Hello!\n    .title = Greeting");
+    /// let resource = FluentResource::try_new(ftl_string)
+    ///     .expect("Failed to parse an FTL string.");
+    /// let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    /// bundle.add_resource(&resource)
+    ///     .expect("Failed to add FTL resources to the bundle.");
+    ///
+    /// let mut errors = vec![];
+    /// let result = bundle.format_attribute("hello", "title", None, &mut errors);
+    ///
+    /// assert_eq!(result.as_deref(), Some("Greeting"));
+    /// assert!(errors.is_empty());
+    /// ```
+    pub fn format_attribute<'bundle>(
+        &'bundle self,
+        id: &str,
+        attr: &str,
+        args: Option<&FluentArgs>,
+        errors: &mut Vec<FluentError>,
+    ) -> Option<Cow<'bundle, str>>
+    where
+        R: Borrow<FluentResource>,
+        M: MemoizerKind,
+    {
+        let missing = |attribute: Option<&str>| FluentError::ResolverError {
+            id: Some(id.to_owned()),
+            error: ResolverError::Reference(ReferenceKind::Message {
+                id: id.to_owned(),
+                attribute: attribute.map(str::to_owned),
+            }),
+        };
+
+        let Some(message) = self.get_message(id) else {
+            errors.push(missing(None));
+            return None;
+        };
+        let Some(attribute) = message.get_attribute(attr) else {
+            errors.push(missing(Some(attr)));
+            return None;
+        };
+        Some(self.format_pattern(attribute.value(), args, errors))
+    }
+
+    /// Formats the value of the message with the given id like
+    /// [`format_value`](FluentBundle::format_value), but returns `default`
+    /// if the message doesn't exist or has no value.
+    pub fn format_value_or_default<'bundle>(
+        &'bundle self,
+        id: &str,
+        default: &'bundle str,
+        args: Option<&FluentArgs>,
+        errors: &mut Vec<FluentError>,
+    ) -> Cow<'bundle, str>
+    where
+        R: Borrow<FluentResource>,
+        M: MemoizerKind,
+    {
+        self.format_value(id, args, errors)
+            .unwrap_or(Cow::Borrowed(default))
+    }
+
+    /// Makes the provided rust function available to messages with the name `id`. See
+    /// the [FTL syntax guide] to learn how these are used in messages.
+    ///
+    /// FTL functions accept both positional and named args. The rust function you
+    /// provide therefore has two parameters: a slice of values for the positional
+    /// args, and a `FluentArgs` for named args.
+    ///
+    /// A function that fails returns a [`FluentFunctionError`]. It is reported as a
+    /// [`FluentError::FunctionError`] and the function is replaced by its first positional
+    /// argument, or by its name if it has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentBundle, FluentFunctionError, FluentResource, FluentValue};
+    /// use icu::locid::langid;
+    ///
+    /// let ftl_string = String::from("length = { STRLEN(\"12345\") }");
+    /// let resource = FluentResource::try_new(ftl_string)
+    ///     .expect("Could not parse an FTL string.");
+    /// let langid_en = langid!("en-US");
+    /// let mut bundle = FluentBundle::new(vec![langid_en]);
+    /// bundle.add_resource(&resource)
+    ///     .expect("Failed to add FTL resources to the bundle.");
+    ///
+    /// // Register a fn that maps from string to string length
+    /// bundle.add_function("STRLEN", |positional, _named| match positional {
+    ///     [FluentValue::String(str)] => Ok(str.len().into()),
+    ///     _ => Err(FluentFunctionError::new("STRLEN expects a single string.")),
+    /// }).expect("Failed to add a function to the bundle.");
     ///
     /// let msg = bundle.get_message("length").expect("Message doesn't exist.");
     /// let mut errors = vec![];
@@ -531,14 +1235,34 @@ impl<R, M> FluentBundle<R, M> {
     /// assert_eq!(&value, "5");
     /// ```
     ///
+    /// # Errors
+    ///
+    /// Returns [`FluentError::InvalidFunctionName`] if `id` is not a valid FTL function name,
+    /// see [`is_valid_function_name`], [`FluentError::FunctionNameConflict`] if `id` is the
+    /// name of a builtin function, and [`FluentError::Overriding`] if an entry with the same
+    /// name was already added.
+    ///
     /// [FTL syntax guide]: https://projectfluent.org/fluent/guide/functions.html
     pub fn add_function<F>(&mut self, id: &str, func: F) -> Result<(), FluentError>
     where
-        F: for<'a> Fn(&[FluentValue<'a>], &FluentArgs) -> FluentValue<'a> + Sync + Send + 'static,
+        F: for<'a> Fn(
+                &[FluentValue<'a>],
+                &FluentArgs,
+            ) -> Result<FluentValue<'a>, FluentFunctionError>
+            + Sync
+            + Send
+            + 'static,
     {
+        if !is_valid_function_name(id) {
+            return Err(FluentError::InvalidFunctionName { id: id.to_owned() });
+        }
+        if BUILTIN_FUNCTIONS.contains(&id) {
+            return Err(FluentError::FunctionNameConflict { id: id.to_owned() });
+        }
+
         match self.entries.entry(id.to_owned()) {
             HashEntry::Vacant(entry) => {
-                entry.insert(Entry::Function(Box::new(func)));
+                entry.insert(Entry::Function(Arc::new(func)));
                 Ok(())
             }
             HashEntry::Occupied(_) => Err(FluentError::Overriding {
@@ -547,6 +1271,523 @@ impl<R, M> FluentBundle<R, M> {
             }),
         }
     }
+
+    /// Makes the provided rust function available to messages with the name `id` like
+    /// [`add_function`](FluentBundle::add_function), but replaces a function that was
+    /// already added with the same name, including the builtin functions.
+    ///
+    /// This is meant for tests and for intentionally changing how builtins like `NUMBER`
+    /// behave.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FluentError::InvalidFunctionName`] if `id` is not a valid FTL function
+    /// name, and [`FluentError::Overriding`] if a message or term has the same name.
+    pub fn add_function_override<F>(&mut self, id: &str, func: F) -> Result<(), FluentError>
+    where
+        F: for<'a> Fn(
+                &[FluentValue<'a>],
+                &FluentArgs,
+            ) -> Result<FluentValue<'a>, FluentFunctionError>
+            + Sync
+            + Send
+            + 'static,
+    {
+        if !is_valid_function_name(id) {
+            return Err(FluentError::InvalidFunctionName { id: id.to_owned() });
+        }
+
+        match self.entries.entry(id.to_owned()) {
+            HashEntry::Occupied(entry) if !matches!(entry.get(), Entry::Function(_)) => {
+                Err(FluentError::Overriding {
+                    kind: EntryKind::Function,
+                    id: id.to_owned(),
+                })
+            }
+            HashEntry::Occupied(mut entry) => {
+                entry.insert(Entry::Function(Arc::new(func)));
+                Ok(())
+            }
+            HashEntry::Vacant(entry) => {
+                entry.insert(Entry::Function(Arc::new(func)));
+                Ok(())
+            }
+        }
+    }
+
+    /// Adds the builtin functions listed in [`BUILTIN_FUNCTIONS`], shared by
+    /// [`FluentBundle::new`] and [`FluentBundle::new_concurrent`](crate::concurrent::FluentBundle::new_concurrent).
+    pub(crate) fn add_builtin_functions(&mut self) {
+        self.add_function_override("NUMBER", |args, named_args| {
+            if args.len() != 1 {
+                return Ok(FluentValue::Error);
+            }
+
+            let arg = args[0].clone();
+
+            //println!("NUMBER CALLED: {:#?}", arg);
+            //println!("NUMBER NEW: {:#?}", named_args);
+
+            let res = match arg {
+                FluentValue::Number(mut num) => {
+                    num.options.merge(named_args);
+                    if let Some(option) = num.options.missing_option() {
+                        return Err(FluentFunctionError::MissingArgument(option.to_string()));
+                    }
+                    FluentValue::Number(num)
+                }
+                _ => FluentValue::Error,
+            };
+
+            //println!("NUMBER RES: {:#?}", res);
+
+            Ok(res)
+        })
+        .unwrap();
+
+        self.add_function_override("ORDINAL", |args, named_args| {
+            if args.len() != 1 {
+                return Ok(FluentValue::Error);
+            }
+
+            // The number is formatted as usual, only the variant selection uses the ordinal rules.
+            Ok(match args[0].clone() {
+                FluentValue::Number(mut num) => {
+                    num.options.merge(named_args);
+                    num.options.plural_type = FluentNumberType::Ordinal;
+                    FluentValue::Number(num)
+                }
+                _ => FluentValue::Error,
+            })
+        })
+        .unwrap();
+
+        self.add_function_override("DATETIME", |args, named_args| {
+            if args.len() != 1 {
+                return Ok(FluentValue::Error);
+            }
+
+            // Dates and times are formatted as datetimes that hide the other part,
+            // and numbers are taken as Unix timestamps in seconds.
+            let arg = match args[0].clone() {
+                FluentValue::Date(date) => FluentValue::DateTime(date.to_datetime()),
+                FluentValue::Time(time) => FluentValue::DateTime(time.to_datetime()),
+                FluentValue::Number(num) if num.value.is_finite() => {
+                    FluentValue::DateTime(FluentDateTime::from(num.value as i64))
+                }
+                arg => arg,
+            };
+
+            let res = match arg {
+                FluentValue::DateTime(mut dt) => {
+                    dt.options.merge(named_args);
+                    if dt.validate().is_err() {
+                        return Ok(FluentValue::Error);
+                    }
+                    FluentValue::DateTime(dt)
+                }
+                _ => FluentValue::Error,
+            };
+
+            Ok(res)
+        })
+        .unwrap();
+
+        self.add_function_override("DATE", |args, named_args| {
+            if args.len() != 1 {
+                return Ok(FluentValue::Error);
+            }
+
+            let res = match args[0].clone() {
+                FluentValue::Date(mut date) => {
+                    date.merge(named_args);
+                    FluentValue::Date(date)
+                }
+                FluentValue::DateTime(dt) => {
+                    let mut date = FluentDate::from(dt.value.date_naive());
+                    date.merge(named_args);
+                    FluentValue::Date(date)
+                }
+                _ => FluentValue::Error,
+            };
+
+            Ok(res)
+        })
+        .unwrap();
+
+        self.add_function_override("TIME", |args, named_args| {
+            if args.len() != 1 {
+                return Ok(FluentValue::Error);
+            }
+
+            let res = match args[0].clone() {
+                FluentValue::Time(mut time) => {
+                    time.merge(named_args);
+                    FluentValue::Time(time)
+                }
+                FluentValue::DateTime(dt) => {
+                    let mut time = FluentTime::from(dt.value.time());
+                    time.merge(named_args);
+                    FluentValue::Time(time)
+                }
+                _ => FluentValue::Error,
+            };
+
+            Ok(res)
+        })
+        .unwrap();
+
+        self.add_function_override("RELTIME", |args, named_args| {
+            if args.len() != 1 {
+                return Ok(FluentValue::Error);
+            }
+
+            let arg = args[0].clone();
+
+            let res = match arg {
+                FluentValue::Number(num) => {
+                    let mut rt =
+                        FluentRelativeTime::new(num.value, Default::default(), Default::default());
+                    rt.merge(named_args);
+                    FluentValue::RelativeTime(rt)
+                }
+                FluentValue::RelativeTime(mut rt) => {
+                    rt.merge(named_args);
+                    FluentValue::RelativeTime(rt)
+                }
+                _ => FluentValue::Error,
+            };
+
+            Ok(res)
+        })
+        .unwrap();
+
+        self.add_function_override("DURATION", |args, named_args| {
+            if args.len() != 1 {
+                return Ok(FluentValue::Error);
+            }
+
+            let arg = args[0].clone();
+
+            let res = match arg {
+                // Plain numbers are taken as milliseconds, negative, infinite and
+                // too large numbers have no duration.
+                FluentValue::Number(num) => {
+                    match std::time::Duration::try_from_secs_f64(num.value / 1000.0) {
+                        Ok(duration) => {
+                            let mut duration = FluentDuration::from(duration);
+                            duration.options.merge(named_args);
+                            FluentValue::Duration(duration)
+                        }
+                        Err(_) => FluentValue::Error,
+                    }
+                }
+                FluentValue::Duration(mut duration) => {
+                    duration.options.merge(named_args);
+                    FluentValue::Duration(duration)
+                }
+                _ => FluentValue::Error,
+            };
+
+            Ok(res)
+        })
+        .unwrap();
+
+        self.add_function_override("LIST", |args, named_args| {
+            let mut options = FluentListOptions::default();
+            options.merge(named_args);
+
+            // Lists passed as arguments are flattened into the new list.
+            let items = args
+                .iter()
+                .flat_map(|arg| match arg {
+                    FluentValue::List(items, _) => items.clone(),
+                    arg => vec![arg.clone()],
+                })
+                .collect();
+
+            Ok(FluentValue::List(items, options))
+        })
+        .unwrap();
+
+        let locale = self.locales.first().cloned().unwrap_or_default();
+        self.add_function_override("STRING", string_function(locale.clone()))
+            .unwrap();
+        self.add_function_override("PLURAL", plural_function(locale))
+            .unwrap();
+    }
+
+    /// Removes the function with the given name from the bundle, returning `false`
+    /// if no function with that name was added.
+    ///
+    /// Builtin functions can be removed as well, e.g. to replace them completely.
+    /// Their names stay reserved for [`FluentBundle::add_function`], so use
+    /// [`FluentBundle::add_function_override`] to add a function of the same name again.
+    ///
+    /// Messages and terms are never removed, even if they have the same name.
+    pub fn remove_function(&mut self, name: &str) -> bool {
+        match self.entries.entry(name.to_owned()) {
+            HashEntry::Occupied(entry) if matches!(entry.get(), Entry::Function(_)) => {
+                entry.remove();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The names of the functions that [`FluentBundle::new`] and
+/// [`FluentBundle::new_concurrent`](crate::concurrent::FluentBundle::new_concurrent) add.
+pub const BUILTIN_FUNCTIONS: &[&str] = &[
+    "NUMBER", "ORDINAL", "DATETIME", "DATE", "TIME", "RELTIME", "DURATION", "LIST", "STRING",
+    "PLURAL",
+];
+
+/// How deeply messages and terms may reference each other by default, see
+/// [`FluentBundle::set_max_recursion_depth`].
+pub const DEFAULT_MAX_RECURSION_DEPTH: usize = 64;
+
+/// Returns whether `name` can be used to call a function from FTL, which requires an
+/// uppercase ASCII letter followed by uppercase ASCII letters, digits, `_` or `-`.
+///
+/// # Example
+///
+/// ```
+/// use fluent_bundle::bundle::is_valid_function_name;
+///
+/// assert!(is_valid_function_name("STRLEN"));
+/// assert!(is_valid_function_name("TO_UPPER2"));
+/// assert!(!is_valid_function_name("strlen"));
+/// assert!(!is_valid_function_name("2UPPER"));
+/// ```
+pub fn is_valid_function_name(name: &str) -> bool {
+    let mut bytes = name.bytes();
+
+    bytes.next().is_some_and(|b| b.is_ascii_uppercase())
+        && bytes.all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_' || b == b'-')
+}
+
+/// The builtin `STRING` function, which converts its argument to a string for the
+/// `locale`, like values are converted when they are written into a pattern.
+///
+/// The named argument `transform` can be `"uppercase"` or `"lowercase"` to change
+/// the case of the string.
+pub(crate) fn string_function(
+    locale: Locale,
+) -> impl for<'a> Fn(&[FluentValue<'a>], &FluentArgs) -> Result<FluentValue<'a>, FluentFunctionError>
+       + Send
+       + Sync {
+    move |args, named_args| {
+        if args.len() != 1 || matches!(args[0], FluentValue::Error) {
+            return Ok(FluentValue::Error);
+        }
+
+        let string = args[0]
+            .as_string_for_locale(&locale)
+            .map_err(|err| FluentFunctionError::new(err.to_string()))?;
+
+        Ok(match named_args.get("transform") {
+            None => FluentValue::String(string),
+            Some(FluentValue::String(transform)) if transform == "uppercase" => {
+                string.to_uppercase().into()
+            }
+            Some(FluentValue::String(transform)) if transform == "lowercase" => {
+                string.to_lowercase().into()
+            }
+            Some(_) => FluentValue::Error,
+        })
+    }
+}
+
+/// The builtin `PLURAL` function, which returns the name of the plural category of its
+/// numeric argument in the `locale`, e.g. `"one"` or `"few"`.
+///
+/// Named arguments are applied like for `NUMBER`, so `type: "ordinal"` selects the
+/// ordinal rules and the fraction digits options change the category of a number.
+pub(crate) fn plural_function(
+    locale: Locale,
+) -> impl for<'a> Fn(&[FluentValue<'a>], &FluentArgs) -> Result<FluentValue<'a>, FluentFunctionError>
+       + Send
+       + Sync {
+    move |args, named_args| {
+        if args.len() != 1 {
+            return Ok(FluentValue::Error);
+        }
+
+        let mut num = match args[0].clone() {
+            FluentValue::Number(num) => num,
+            _ => return Ok(FluentValue::Error),
+        };
+        num.options.merge(named_args);
+
+        let plural_rule_type = match num.options.plural_type {
+            FluentNumberType::Cardinal => PluralRuleType::Cardinal,
+            FluentNumberType::Ordinal => PluralRuleType::Ordinal,
+        };
+
+        let category = match plural_category(&locale, plural_rule_type, &num) {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        };
+
+        Ok(category.into())
+    }
+}
+
+/// A builder for a [`FluentBundle`], allowing locales, resources, functions and
+/// options to be chained before the bundle is built.
+///
+/// # Examples
+///
+/// ```
+/// use fluent_bundle::bundle::FluentBundleBuilder;
+/// use fluent_bundle::FluentResource;
+/// use icu::locid::locale;
+///
+/// let resource = FluentResource::try_new("intro = Welcome, { $name }.".to_string())
+///     .expect("Could not parse an FTL string.");
+///
+/// let bundle = FluentBundleBuilder::new()
+///     .with_locales(vec![locale!("en-US")])
+///     .with_use_isolating(false)
+///     .with_resource(resource)
+///     .expect("Failed to add FTL resources to the bundle.")
+///     .build()
+///     .expect("Failed to build the bundle.");
+///
+/// assert!(bundle.has_message("intro"));
+/// ```
+pub struct FluentBundleBuilder<R, M> {
+    pub(crate) bundle: FluentBundle<R, M>,
+}
+
+impl<R, M: MemoizerKind> FluentBundleBuilder<R, M> {
+    /// Sets the locales of the bundle. The first locale is the language the
+    /// bundle represents, see [`FluentBundle::new`].
+    pub fn with_locales(mut self, locales: Vec<Locale>) -> Self {
+        self.bundle.locales = locales;
+        self
+    }
+
+    /// Adds a resource, see [`FluentBundle::add_resource`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`FluentBundle::add_resource`] if any entry of the
+    /// resource is already registered.
+    pub fn with_resource(mut self, r: R) -> Result<Self, Vec<FluentError>>
+    where
+        R: Borrow<FluentResource>,
+    {
+        self.bundle.add_resource(r)?;
+        Ok(self)
+    }
+
+    /// Adds a function, see [`FluentBundle::add_function`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`FluentBundle::add_function`], e.g.
+    /// [`FluentError::FunctionNameConflict`] for the names of builtin functions.
+    pub fn with_function<F>(mut self, id: &str, func: F) -> Result<Self, FluentError>
+    where
+        F: for<'a> Fn(
+                &[FluentValue<'a>],
+                &FluentArgs,
+            ) -> Result<FluentValue<'a>, FluentFunctionError>
+            + Sync
+            + Send
+            + 'static,
+    {
+        self.bundle.add_function(id, func)?;
+        Ok(self)
+    }
+
+    /// See [`FluentBundle::set_use_isolating`].
+    pub fn with_use_isolating(mut self, value: bool) -> Self {
+        self.bundle.set_use_isolating(value);
+        self
+    }
+
+    /// See [`FluentBundle::set_max_recursion_depth`].
+    pub fn with_max_recursion_depth(mut self, depth: usize) -> Self {
+        self.bundle.set_max_recursion_depth(depth);
+        self
+    }
+
+    /// See [`FluentBundle::set_transform`].
+    pub fn with_transform(mut self, func: Option<fn(&str) -> Cow<str>>) -> Self {
+        self.bundle.set_transform(func);
+        self
+    }
+
+    /// Builds the bundle.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FluentError::MissingLocale`] if no locales were provided.
+    pub fn build(mut self) -> Result<FluentBundle<R, M>, FluentError> {
+        let first_locale = self
+            .bundle
+            .locales
+            .first()
+            .cloned()
+            .ok_or(FluentError::MissingLocale)?;
+        // The builtin functions were added before the locales were known.
+        self.bundle
+            .add_function_override("STRING", string_function(first_locale.clone()))?;
+        self.bundle
+            .add_function_override("PLURAL", plural_function(first_locale.clone()))?;
+        self.bundle.intls = M::new(first_locale);
+        Ok(self.bundle)
+    }
+}
+
+impl<R> FluentBundleBuilder<R, IntlLangMemoizer> {
+    /// Constructs a builder for a [`FluentBundle`](crate::FluentBundle).
+    ///
+    /// See [`FluentBundleBuilder::new_concurrent`] for the concurrent version.
+    pub fn new() -> Self {
+        Self {
+            bundle: FluentBundle::new(vec![]),
+        }
+    }
+}
+
+impl<R> Default for FluentBundleBuilder<R, IntlLangMemoizer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adapts a byte buffer to [`fmt::Write`], so patterns can be written
+/// into it without an intermediate `String`.
+struct ByteBufWriter<'buf>(&'buf mut Vec<u8>);
+
+impl fmt::Write for ByteBufWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+/// Adapts an [`io::Write`] to [`fmt::Write`], keeping the underlying error.
+struct IoWriter<'w, W: io::Write> {
+    inner: &'w mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
 }
 
 impl<R> Default for FluentBundle<R, IntlLangMemoizer> {
@@ -571,12 +1812,16 @@ impl<R> FluentBundle<R, IntlLangMemoizer> {
     ///
     /// let langid_en = langid!("en-US");
     /// let mut bundle: FluentBundle<FluentResource> = FluentBundle::new(vec![langid_en]);
+    ///
+    /// // Any iterator of locales works, such as an array.
+    /// let mut bundle: FluentBundle<FluentResource> = FluentBundle::new([langid!("en-US"), langid!("en")]);
     /// ```
     ///
     /// # Errors
     ///
     /// This will panic if no formatters can be found for the locales.
-    pub fn new(locales: Vec<Locale>) -> Self {
+    pub fn new(locales: impl IntoIterator<Item = Locale>) -> Self {
+        let locales: Vec<Locale> = locales.into_iter().collect();
         let first_locale = locales.get(0).cloned().unwrap_or_default();
         let mut res = Self {
             locales,
@@ -584,50 +1829,12 @@ impl<R> FluentBundle<R, IntlLangMemoizer> {
             entries: FxHashMap::default(),
             intls: IntlLangMemoizer::new(first_locale),
             use_isolating: true,
+            max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
             transform: None,
             formatter: None,
         };
 
-        res.add_function("NUMBER", |args, named_args| {
-            if args.len() != 1 {
-                return FluentValue::Error
-            }
-
-            let arg = args[0].clone();
-
-            //println!("NUMBER CALLED: {:#?}", arg);
-            //println!("NUMBER NEW: {:#?}", named_args);
-
-            let res = match arg {
-                FluentValue::Number(mut num) => {
-                    num.options.merge(named_args);
-                    FluentValue::Number(num)
-                }
-                _ => FluentValue::Error
-            };
-
-            //println!("NUMBER RES: {:#?}", res);
-
-            res
-        }).unwrap();
-
-        res.add_function("DATETIME", |args, named_args| {
-            if args.len() != 1 {
-                return FluentValue::Error
-            }
-
-            let arg = args[0].clone();
-
-            let res = match arg {
-                FluentValue::DateTime(mut dt) => {
-                    dt.options.merge(named_args);
-                    FluentValue::DateTime(dt)
-                }
-                _ => FluentValue::Error
-            };
-
-            res
-        }).unwrap();
+        res.add_builtin_functions();
 
         res
     }
@@ -1,8 +1,8 @@
+use icu::locid::Locale;
 use intl_memoizer_for_carbide::{concurrent::IntlLangMemoizer, Memoizable};
 use rustc_hash::FxHashMap;
-use icu::locid::Locale;
-use crate::FluentValue;
 
+use crate::bundle::DEFAULT_MAX_RECURSION_DEPTH;
 use crate::memoizer::MemoizerKind;
 use crate::types::FluentType;
 
@@ -30,7 +30,8 @@ impl<R> FluentBundle<R> {
     /// let mut bundle: FluentBundle<FluentResource> =
     ///     FluentBundle::new_concurrent(vec![locale_en]);
     /// ```
-    pub fn new_concurrent(locales: Vec<Locale>) -> Self {
+    pub fn new_concurrent(locales: impl IntoIterator<Item = Locale>) -> Self {
+        let locales: Vec<Locale> = locales.into_iter().collect();
         let first_locale = locales.get(0).cloned().unwrap_or_default();
         let mut res = Self {
             locales,
@@ -38,50 +39,12 @@ impl<R> FluentBundle<R> {
             entries: FxHashMap::default(),
             intls: IntlLangMemoizer::new(first_locale),
             use_isolating: true,
+            max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
             transform: None,
             formatter: None,
         };
 
-        res.add_function("NUMBER", |args, named_args| {
-            if args.len() != 1 {
-                return FluentValue::Error
-            }
-
-            let arg = args[0].clone();
-
-            //println!("NUMBER CALLED: {:#?}", arg);
-            //println!("NUMBER NEW: {:#?}", named_args);
-
-            let res = match arg {
-                FluentValue::Number(mut num) => {
-                    num.options.merge(named_args);
-                    FluentValue::Number(num)
-                }
-                _ => FluentValue::Error
-            };
-
-            //println!("NUMBER RES: {:#?}", res);
-
-            res
-        }).unwrap();
-
-        res.add_function("DATETIME", |args, named_args| {
-            if args.len() != 1 {
-                return FluentValue::Error
-            }
-
-            let arg = args[0].clone();
-
-            let res = match arg {
-                FluentValue::DateTime(mut dt) => {
-                    dt.options.merge(named_args);
-                    FluentValue::DateTime(dt)
-                }
-                _ => FluentValue::Error
-            };
-
-            res
-        }).unwrap();
+        res.add_builtin_functions();
 
         res
     }
@@ -89,18 +52,18 @@ impl<R> FluentBundle<R> {
 
 impl MemoizerKind for IntlLangMemoizer {
     fn new(lang: Locale) -> Self
-        where
-            Self: Sized,
+    where
+        Self: Sized,
     {
         Self::new(lang)
     }
 
     fn with_try_get_threadsafe<I, R, U>(&self, args: I::Args, cb: U) -> Result<R, I::Error>
-        where
-            Self: Sized,
-            I: Memoizable + Send + Sync + 'static,
-            I::Args: Send + Sync + 'static,
-            U: FnOnce(&I) -> R,
+    where
+        Self: Sized,
+        I: Memoizable + Send + Sync + 'static,
+        I::Args: Send + Sync + 'static,
+        U: FnOnce(&I) -> R,
     {
         self.with_try_get(args, cb)
     }
@@ -112,4 +75,14 @@ impl MemoizerKind for IntlLangMemoizer {
     fn language(&self) -> &Locale {
         &self.lang()
     }
-}
\ No newline at end of file
+}
+
+impl<R> crate::bundle::FluentBundleBuilder<R, IntlLangMemoizer> {
+    /// A constructor analogous to [`FluentBundleBuilder::new`](crate::bundle::FluentBundleBuilder::new)
+    /// building a concurrent [`FluentBundle`].
+    pub fn new_concurrent() -> Self {
+        Self {
+            bundle: FluentBundle::new_concurrent(vec![]),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Static detection of cyclic references between the messages and terms of a
+//! [`FluentBundle`], see [`FluentBundle::detect_circular_references`].
+
+use std::borrow::Borrow;
+
+use fluent_syntax_for_carbide::ast;
+use fluent_syntax_for_carbide::visitor::{self, Visitor};
+use rustc_hash::FxHashMap;
+
+use crate::bundle::FluentBundle;
+use crate::entry::{Entry, GetEntry};
+use crate::errors::FluentError;
+use crate::resource::FluentResource;
+
+/// The patterns of a bundle, keyed by the id the resolver uses in a
+/// [`FluentError::CyclicReference`], e.g. `msg`, `msg.attr` or `-term`.
+type Graph = FxHashMap<String, Vec<String>>;
+
+/// Collects the ids of the messages and terms a pattern references.
+#[derive(Default)]
+struct References(Vec<String>);
+
+impl<'ast> Visitor<'ast, &'ast str> for References {
+    fn visit_inline_expression(&mut self, expression: &'ast ast::InlineExpression<&'ast str>) {
+        match expression {
+            ast::InlineExpression::MessageReference { id, attribute, .. } => {
+                self.0.push(node_id("", id, attribute.as_ref()));
+            }
+            ast::InlineExpression::TermReference { id, attribute, .. } => {
+                self.0.push(node_id("-", id, attribute.as_ref()));
+            }
+            _ => {}
+        }
+        visitor::walk_inline_expression(self, expression);
+    }
+}
+
+fn node_id(
+    prefix: &str,
+    id: &ast::Identifier<&str>,
+    attribute: Option<&ast::Identifier<&str>>,
+) -> String {
+    match attribute {
+        Some(attribute) => format!("{}{}.{}", prefix, id.name, attribute.name),
+        None => format!("{}{}", prefix, id.name),
+    }
+}
+
+fn add_node(graph: &mut Graph, id: String, pattern: &ast::Pattern<&str>) {
+    let mut references = References::default();
+    references.visit_pattern(pattern);
+    graph.insert(id, references.0);
+}
+
+fn build_graph<R, M>(bundle: &FluentBundle<R, M>) -> Graph
+where
+    R: Borrow<FluentResource>,
+{
+    let mut graph = Graph::default();
+
+    for (id, entry) in &bundle.entries {
+        let (prefix, value, attributes) = match entry {
+            Entry::Message(_) => match bundle.get_entry_message(id) {
+                Some(message) => ("", message.value.as_ref(), &message.attributes),
+                None => continue,
+            },
+            Entry::Term(_) => match bundle.get_entry_term(id) {
+                Some(term) => ("-", Some(&term.value), &term.attributes),
+                None => continue,
+            },
+            Entry::Function(_) => continue,
+        };
+
+        if let Some(value) = value {
+            add_node(&mut graph, format!("{}{}", prefix, id), value);
+        }
+        for attribute in attributes {
+            add_node(
+                &mut graph,
+                format!("{}{}.{}", prefix, id, attribute.id.name),
+                &attribute.value,
+            );
+        }
+    }
+
+    graph
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    InProgress,
+    Done,
+}
+
+fn visit<'g>(
+    graph: &'g Graph,
+    id: &'g str,
+    states: &mut FxHashMap<&'g str, State>,
+    path: &mut Vec<&'g str>,
+    errors: &mut Vec<FluentError>,
+) {
+    states.insert(id, State::InProgress);
+    path.push(id);
+
+    let mut references: Vec<&str> = graph[id].iter().map(String::as_str).collect();
+    references.sort_unstable();
+    references.dedup();
+
+    for reference in references {
+        // References to missing entries can not be part of a cycle.
+        let Some((reference, _)) = graph.get_key_value(reference) else {
+            continue;
+        };
+        match states.get(reference.as_str()) {
+            Some(State::InProgress) => {
+                let start = path
+                    .iter()
+                    .position(|id| *id == reference)
+                    .expect("An entry in progress is on the path.");
+                let ids = path[start..]
+                    .iter()
+                    .chain(std::iter::once(&reference.as_str()))
+                    .map(|id| id.to_string())
+                    .collect();
+                errors.push(FluentError::CyclicReference { ids });
+            }
+            Some(State::Done) => {}
+            None => visit(graph, reference, states, path, errors),
+        }
+    }
+
+    path.pop();
+    states.insert(id, State::Done);
+}
+
+pub(crate) fn detect_circular_references<R, M>(bundle: &FluentBundle<R, M>) -> Vec<FluentError>
+where
+    R: Borrow<FluentResource>,
+{
+    let graph = build_graph(bundle);
+
+    let mut ids: Vec<&str> = graph.keys().map(String::as_str).collect();
+    ids.sort_unstable();
+
+    let mut states = FxHashMap::default();
+    let mut errors = vec![];
+    for id in ids {
+        if !states.contains_key(id) {
+            visit(&graph, id, &mut states, &mut vec![], &mut errors);
+        }
+    }
+    errors
+}
@@ -2,22 +2,28 @@
 //! `FluentBundle` instances.
 
 use std::borrow::Borrow;
+use std::sync::Arc;
 
 use fluent_syntax_for_carbide::ast;
 
 use crate::args::FluentArgs;
 use crate::bundle::FluentBundle;
+use crate::errors::FluentFunctionError;
 use crate::resource::FluentResource;
 use crate::types::FluentValue;
 
-pub type FluentFunction =
-    Box<dyn for<'a> Fn(&[FluentValue<'a>], &FluentArgs) -> FluentValue<'a> + Send + Sync>;
+pub type FluentFunction = Arc<
+    dyn for<'a> Fn(&[FluentValue<'a>], &FluentArgs) -> Result<FluentValue<'a>, FluentFunctionError>
+        + Send
+        + Sync,
+>;
 
 type ResourceIdx = usize;
 type EntryIdx = usize;
 
 /// The [`Entry`] stores indexes into the [`FluentBundle`]'s resources for Messages and Terms,
-/// and owns the [`Box`] pointers to the [`FluentFunction`].
+/// and shares the [`Arc`] pointers to the [`FluentFunction`], so cloned bundles reuse the functions.
+#[derive(Clone)]
 pub enum Entry {
     Message((ResourceIdx, EntryIdx)),
     Term((ResourceIdx, EntryIdx)),
@@ -1,6 +1,8 @@
+use crate::resolver::errors::ReferenceKind;
 use crate::resolver::ResolverError;
 use fluent_syntax_for_carbide::parser::ParserError;
 use std::error::Error;
+use std::io;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum EntryKind {
@@ -51,12 +53,69 @@ pub enum FluentError {
     ///
     /// assert!(bundle.add_resource(&res2).is_err());
     /// ```
-    Overriding {
-        kind: EntryKind,
-        id: String,
+    Overriding { kind: EntryKind, id: String },
+    /// A syntax error in the source of a [`FluentResource`](crate::FluentResource), see
+    /// [`FluentError::parser_error`].
+    ParserError {
+        error: ParserError,
+        /// The line of the error, starting at 1.
+        line: usize,
+        /// The column of the error in characters, starting at 1.
+        column: usize,
     },
-    ParserError(ParserError),
-    ResolverError(ResolverError),
+    /// An error that occurred while resolving a pattern.
+    ResolverError {
+        /// The id of the message whose pattern failed to resolve, or `None` if the
+        /// pattern does not belong to a message of the bundle.
+        id: Option<String>,
+        error: ResolverError,
+    },
+    /// A timezone style was set on a [`FluentDateTime`](crate::types::FluentDateTime)
+    /// that was created without a timezone.
+    NaiveTimezone,
+    /// A message or term references itself, directly or through other messages and terms.
+    CyclicReference {
+        /// The ids of the messages and terms in the cycle, ending with the one it
+        /// started with, e.g. `["a", "b", "a"]`.
+        ids: Vec<String>,
+    },
+    /// A chain of references was deeper than allowed by
+    /// [`FluentBundle::set_max_recursion_depth`](crate::bundle::FluentBundle::set_max_recursion_depth).
+    RecursionDepthExceeded {
+        /// The id of the message or term that was not resolved, e.g. `msg` or `-term`.
+        message_id: String,
+        /// The depth of the reference, starting at 1 for a reference in the formatted pattern.
+        depth: usize,
+    },
+    /// ICU failed to create a formatter for, or to format, a
+    /// [`FluentDateTime`](crate::types::FluentDateTime).
+    DateTimeFormat(String),
+    /// ICU failed to create a formatter for a [`FluentNumber`](crate::types::FluentNumber).
+    NumberFormat(String),
+    /// A [`FluentRelativeTime`](crate::types::FluentRelativeTime) was not finite, or ICU
+    /// failed to create a formatter for it.
+    RelativeTimeFormat(String),
+    /// ICU failed to create a formatter for a [`FluentDuration`](crate::types::FluentDuration).
+    DurationFormat(String),
+    /// ICU failed to create a formatter for a list of values, see
+    /// [`FluentListOptions`](crate::types::FluentListOptions).
+    ListFormat(String),
+    /// A function was added with the name of a builtin function, see
+    /// [`FluentBundle::add_function_override`](crate::bundle::FluentBundle::add_function_override)
+    /// to replace a builtin.
+    FunctionNameConflict { id: String },
+    /// A function was added with a name that can not be called from FTL, see
+    /// [`is_valid_function_name`](crate::bundle::is_valid_function_name).
+    InvalidFunctionName { id: String },
+    /// A [`FluentBundleBuilder`](crate::bundle::FluentBundleBuilder) was built
+    /// without any locales.
+    MissingLocale,
+    /// A function added with [`FluentBundle::add_function`](crate::bundle::FluentBundle::add_function)
+    /// returned a [`FluentFunctionError`].
+    FunctionError { name: String, message: String },
+    /// A function was called without a named argument that it requires, e.g. `NUMBER`
+    /// with `style: "currency"` but no `currency`.
+    MissingFunctionArgument { function: String, argument: String },
 }
 
 impl std::fmt::Display for FluentError {
@@ -65,22 +124,312 @@ impl std::fmt::Display for FluentError {
             Self::Overriding { kind, id } => {
                 write!(f, "Attempt to override an existing {}: \"{}\".", kind, id)
             }
-            Self::ParserError(err) => write!(f, "Parser error: {}", err),
-            Self::ResolverError(err) => write!(f, "Resolver error: {}", err),
+            Self::ParserError {
+                error,
+                line,
+                column,
+            } => {
+                write!(f, "Parser error at {}:{}: {}", line, column, error)
+            }
+            Self::ResolverError {
+                id: Some(id),
+                error,
+            } => {
+                write!(f, "Resolver error in \"{}\": {}", id, error)
+            }
+            Self::ResolverError { id: None, error } => write!(f, "Resolver error: {}", error),
+            Self::CyclicReference { ids } => write!(f, "Cyclic reference: {}", ids.join(" -> ")),
+            Self::RecursionDepthExceeded { message_id, depth } => {
+                write!(
+                    f,
+                    "Reference to \"{}\" exceeds the maximum recursion depth: {}",
+                    message_id, depth
+                )
+            }
+            Self::NaiveTimezone => {
+                f.write_str("A datetime without a timezone can not display a timezone.")
+            }
+            Self::DateTimeFormat(err) => write!(f, "Failed to format a datetime: {}", err),
+            Self::NumberFormat(err) => write!(f, "Failed to format a number: {}", err),
+            Self::RelativeTimeFormat(err) => write!(f, "Failed to format a relative time: {}", err),
+            Self::DurationFormat(err) => write!(f, "Failed to format a duration: {}", err),
+            Self::ListFormat(err) => write!(f, "Failed to format a list: {}", err),
+            Self::FunctionNameConflict { id } => {
+                write!(
+                    f,
+                    "The function name \"{}\" is reserved for a builtin function.",
+                    id
+                )
+            }
+            Self::InvalidFunctionName { id } => write!(f, "Invalid function name: \"{}\".", id),
+            Self::MissingLocale => f.write_str("A bundle requires at least one locale."),
+            Self::FunctionError { name, message } => {
+                write!(f, "Function {}() failed: {}", name, message)
+            }
+            Self::MissingFunctionArgument { function, argument } => {
+                write!(
+                    f,
+                    "Function {}() requires the argument \"{}\".",
+                    function, argument
+                )
+            }
         }
     }
 }
 
-impl Error for FluentError {}
+impl FluentError {
+    /// Creates a [`FluentError::ParserError`], looking up the line and column of the
+    /// error in the `source` that was parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fluent_bundle::{FluentError, FluentResource};
+    ///
+    /// use fluent_syntax::parser::parse_runtime;
+    ///
+    /// let source = "hello = Hello\nbroken = {";
+    /// let (_, errors) = parse_runtime(source).expect_err("The source has a syntax error.");
+    ///
+    /// let error = FluentError::parser_error(errors[0].clone(), source);
+    /// assert!(matches!(error, FluentError::ParserError { line: 2, .. }));
+    /// ```
+    pub fn parser_error(error: ParserError, source: &str) -> Self {
+        let before = &source[..error.pos.start.min(source.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+            + 1;
+
+        Self::ParserError {
+            error,
+            line,
+            column,
+        }
+    }
 
-impl From<ResolverError> for FluentError {
-    fn from(error: ResolverError) -> Self {
-        Self::ResolverError(error)
+    /// Formats the error like [`Display`](std::fmt::Display), and for a
+    /// [`FluentError::ParserError`] adds the offending line of the `source` with a
+    /// caret pointing at the error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fluent_bundle::FluentResource;
+    ///
+    /// let source = "hello = Hello\nbroken = {";
+    /// let (_, errors) = FluentResource::try_new(source.to_string())
+    ///     .expect_err("The source has a syntax error.");
+    ///
+    /// assert_eq!(
+    ///     errors[0].display_with_source(source),
+    ///     "Parser error at 2:11: Expected an inline expression\n  |\n2 | broken = {\n  |           ^"
+    /// );
+    /// ```
+    pub fn display_with_source(&self, source: &str) -> String {
+        let (line, column) = match self {
+            Self::ParserError { line, column, .. } => (*line, *column),
+            _ => return self.to_string(),
+        };
+
+        let text = source.lines().nth(line - 1).unwrap_or_default();
+        let gutter = " ".repeat(line.to_string().len());
+
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}^",
+            self,
+            gutter,
+            line,
+            text,
+            gutter,
+            " ".repeat(column - 1),
+        )
+    }
+}
+
+impl Error for FluentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ParserError { error, .. } => Some(error),
+            Self::ResolverError { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The error of [`FluentResource::try_from_file`](crate::FluentResource::try_from_file).
+#[derive(Debug)]
+pub enum FluentResourceError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file was read, but has syntax errors.
+    Parse(Vec<FluentError>),
+}
+
+impl std::fmt::Display for FluentResourceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to read the resource: {}", err),
+            Self::Parse(errors) => {
+                write!(f, "The resource has {} syntax error(s)", errors.len())?;
+                if let Some(err) = errors.first() {
+                    write!(f, ", the first is: {}", err)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for FluentResourceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Parse(errors) => errors.first().map(|err| err as &(dyn Error + 'static)),
+        }
     }
 }
 
-impl From<ParserError> for FluentError {
-    fn from(error: ParserError) -> Self {
-        Self::ParserError(error)
+impl From<io::Error> for FluentResourceError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// The error of [`FluentArgs::from_json`](crate::FluentArgs::from_json).
+#[cfg(feature = "serde_json")]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FluentArgsError {
+    /// The JSON value is not an object.
+    NotAnObject,
+    /// The argument at the key is `null`, which has no [`FluentValue`](crate::FluentValue).
+    Null(String),
+    /// An item of the array at the key is an object or `null`.
+    InvalidListItem(String),
+}
+
+#[cfg(feature = "serde_json")]
+impl std::fmt::Display for FluentArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotAnObject => f.write_str("The arguments must be a JSON object."),
+            Self::Null(key) => write!(f, "The argument \"{}\" is null.", key),
+            Self::InvalidListItem(key) => {
+                write!(
+                    f,
+                    "The list \"{}\" may only contain strings, numbers, booleans and lists.",
+                    key
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl Error for FluentArgsError {}
+
+/// The error of a function that was added to a bundle, see
+/// [`FluentBundle::add_function`](crate::bundle::FluentBundle::add_function).
+///
+/// The bundle reports it as a [`FluentError::FunctionError`] together with the name of the function.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FluentFunctionError {
+    /// The function failed, the message tells why.
+    Message(String),
+    /// A named argument that the function requires was not passed, reported as
+    /// [`FluentError::MissingFunctionArgument`].
+    MissingArgument(String),
+}
+
+impl FluentFunctionError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self::Message(message.into())
+    }
+}
+
+impl std::fmt::Display for FluentFunctionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Message(message) => f.write_str(message),
+            Self::MissingArgument(argument) => write!(f, "Missing argument: {}", argument),
+        }
+    }
+}
+
+impl Error for FluentFunctionError {}
+
+impl From<String> for FluentFunctionError {
+    fn from(message: String) -> Self {
+        Self::Message(message)
+    }
+}
+
+impl From<&str> for FluentFunctionError {
+    fn from(message: &str) -> Self {
+        Self::new(message)
+    }
+}
+
+/// A non-fatal issue found while resolving a pattern, the output is still usable.
+///
+/// Warnings are only collected by
+/// [`FluentBundle::format_pattern_with_warnings`](crate::bundle::FluentBundle::format_pattern_with_warnings),
+/// the other formatting methods report missing references as [`FluentError`]s.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FluentWarning {
+    /// A message or term was referenced but not found, and its id was written instead.
+    MissingReference {
+        /// The id of the message that contains the reference, if known.
+        id: Option<String>,
+        reference: ReferenceKind,
+    },
+    /// An argument was passed, but not used while resolving the pattern.
+    UnusedArgument(String),
+    /// A named argument was passed to a function, but has no effect with the other
+    /// arguments, e.g. `currency` for `NUMBER` without `style: "currency"`.
+    UnusedFunctionArgument { function: String, argument: String },
+}
+
+impl std::fmt::Display for FluentWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingReference {
+                id: Some(id),
+                reference,
+            } => {
+                write!(
+                    f,
+                    "Missing reference in \"{}\": {}",
+                    id,
+                    ResolverError::Reference(reference.clone())
+                )
+            }
+            Self::MissingReference {
+                id: None,
+                reference,
+            } => {
+                write!(
+                    f,
+                    "Missing reference: {}",
+                    ResolverError::Reference(reference.clone())
+                )
+            }
+            Self::UnusedArgument(name) => write!(f, "Unused argument: ${}", name),
+            Self::UnusedFunctionArgument { function, argument } => {
+                write!(f, "Unused argument of {}(): {}", function, argument)
+            }
+        }
+    }
+}
+
+/// The message id is unknown, use [`Scope::add_error`](crate::resolver::Scope::add_error)
+/// to report errors during resolving.
+impl From<ResolverError> for FluentError {
+    fn from(error: ResolverError) -> Self {
+        Self::ResolverError { id: None, error }
     }
 }
@@ -101,17 +101,20 @@
 mod args;
 pub mod bundle;
 pub mod concurrent;
+mod cycles;
 mod entry;
 mod errors;
 #[doc(hidden)]
 pub mod memoizer;
 mod message;
+mod parts;
 #[doc(hidden)]
 pub mod resolver;
 mod resource;
 pub mod types;
 
 pub use args::FluentArgs;
+pub use bundle::FluentBundleBuilder;
 /// Specialized [`FluentBundle`](crate::bundle::FluentBundle) over
 /// non-concurrent [`IntlLangMemoizer`](intl_memoizer::IntlLangMemoizer).
 ///
@@ -120,8 +123,11 @@ pub use args::FluentArgs;
 /// The concurrent specialization can be constructed with
 /// [`FluentBundle::new_concurrent`](crate::concurrent::FluentBundle::new_concurrent).
 pub type FluentBundle<R> = bundle::FluentBundle<R, intl_memoizer_for_carbide::IntlLangMemoizer>;
-pub use errors::FluentError;
-pub use message::{FluentAttribute, FluentMessage};
+#[cfg(feature = "serde_json")]
+pub use errors::FluentArgsError;
+pub use errors::{FluentError, FluentFunctionError, FluentResourceError, FluentWarning};
+pub use message::{FluentAttribute, FluentMessage, FluentTerm};
+pub use parts::{DateTimePartType, FluentPart, NumberPartType};
 pub use resource::FluentResource;
 #[doc(inline)]
 pub use types::FluentValue;
@@ -272,3 +272,77 @@ impl<'m> From<&'m ast::Message<&'m str>> for FluentMessage<'m> {
         FluentMessage { node: msg }
     }
 }
+
+/// [`FluentTerm`] is a translation unit that can only be referenced from other
+/// messages and terms, such as a brand name.
+///
+/// The instance of a term is returned from the
+/// [`FluentBundle::get_term`](crate::bundle::FluentBundle::get_term) method, for tools
+/// that inspect the content of a bundle.
+///
+/// # Example
+///
+/// ```
+/// use fluent_bundle::{FluentResource, FluentBundle};
+///
+/// let source = r#"
+///
+/// -brand-name = Firefox
+///     .gender = masculine
+///
+/// "#;
+///
+/// let resource = FluentResource::try_new(source.to_string())
+///     .expect("Failed to parse the resource.");
+///
+/// let mut bundle = FluentBundle::default();
+/// bundle.add_resource(resource)
+///     .expect("Failed to add a resource.");
+///
+/// let term = bundle.get_term("brand-name")
+///     .expect("Failed to retrieve a term.");
+///
+/// let mut err = vec![];
+/// assert_eq!(bundle.format_pattern(term.value(), None, &mut err), "Firefox");
+///
+/// let gender = term.get_attribute("gender")
+///     .expect("Failed to retrieve an attribute.");
+/// assert_eq!(bundle.format_pattern(gender.value(), None, &mut err), "masculine");
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct FluentTerm<'m> {
+    node: &'m ast::Term<&'m str>,
+}
+
+impl<'m> FluentTerm<'m> {
+    /// Retrieves the id of the term, without the leading `-`.
+    pub fn id(&self) -> &'m str {
+        self.node.id.name
+    }
+
+    /// Retrieves the value of the term, which unlike the value of a message is
+    /// always present.
+    pub fn value(&self) -> &'m ast::Pattern<&'m str> {
+        &self.node.value
+    }
+
+    /// An iterator over [`FluentAttribute`] elements.
+    pub fn attributes(&self) -> impl Iterator<Item = FluentAttribute<'m>> {
+        self.node.attributes.iter().map(Into::into)
+    }
+
+    /// Retrieve a single [`FluentAttribute`] element.
+    pub fn get_attribute(&self, key: &str) -> Option<FluentAttribute<'m>> {
+        self.node
+            .attributes
+            .iter()
+            .find(|attr| attr.id.name == key)
+            .map(Into::into)
+    }
+}
+
+impl<'m> From<&'m ast::Term<&'m str>> for FluentTerm<'m> {
+    fn from(term: &'m ast::Term<&'m str>) -> Self {
+        FluentTerm { node: term }
+    }
+}
@@ -0,0 +1,149 @@
+//! Structured output of [`FluentBundle::format_to_parts`](crate::bundle::FluentBundle::format_to_parts).
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt;
+
+/// A piece of a formatted pattern, together with where it came from.
+///
+/// Joining the values of all parts gives the same string as
+/// [`FluentBundle::format_pattern`](crate::bundle::FluentBundle::format_pattern).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FluentPart<'s> {
+    /// Text of the pattern, of strings and of values without parts, like lists.
+    Literal(Cow<'s, str>),
+    /// A piece of a formatted number.
+    Number {
+        value: Cow<'s, str>,
+        part_type: NumberPartType,
+    },
+    /// A piece of a formatted date or time.
+    DateTime {
+        value: Cow<'s, str>,
+        part_type: DateTimePartType,
+    },
+}
+
+impl FluentPart<'_> {
+    /// Returns the text of the part.
+    pub fn value(&self) -> &str {
+        match self {
+            Self::Literal(value) | Self::Number { value, .. } | Self::DateTime { value, .. } => {
+                value
+            }
+        }
+    }
+}
+
+/// The kind of a piece of a formatted number, named like the part types of
+/// `Intl.NumberFormat.prototype.formatToParts`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NumberPartType {
+    Integer,
+    Group,
+    Decimal,
+    Fraction,
+    MinusSign,
+    PlusSign,
+    Currency,
+    PercentSign,
+    Unit,
+    ExponentSeparator,
+    Exponent,
+    Compact,
+    Literal,
+}
+
+/// The kind of a piece of a formatted date or time.
+///
+/// ICU does not report which field produced which text, so the parts are told apart by
+/// their characters: digits are numeric fields like the day or the hour, letters are
+/// textual fields like month names, and everything else is literal text of the pattern.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum DateTimePartType {
+    Numeric,
+    Text,
+    Literal,
+}
+
+/// Splits `text` into runs of the same type, `classify` returns the type of the text at
+/// the start of the rest of the string and its length in bytes.
+pub(crate) fn split_runs<T: PartialEq>(
+    text: &str,
+    mut classify: impl FnMut(&str) -> (T, usize),
+) -> Vec<(T, &str)> {
+    let mut runs: Vec<(T, &str)> = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+
+    while end < text.len() {
+        let (part_type, len) = classify(&text[end..]);
+        let len = len.max(text[end..].chars().next().map_or(1, char::len_utf8));
+
+        match runs.last_mut() {
+            Some((last_type, last)) if *last_type == part_type => *last = &text[start..end + len],
+            _ => {
+                start = end;
+                runs.push((part_type, &text[start..end + len]));
+            }
+        }
+        end += len;
+    }
+
+    runs
+}
+
+/// Appends a part, extending the last part if both are literal text.
+fn push_part(parts: &mut Vec<FluentPart<'static>>, part: FluentPart<'static>) {
+    match (parts.last_mut(), part) {
+        (Some(FluentPart::Literal(last)), FluentPart::Literal(value)) => {
+            last.to_mut().push_str(&value)
+        }
+        (_, part) => parts.push(part),
+    }
+}
+
+/// Receives the text of a pattern as literal parts.
+pub(crate) struct PartsWriter<'p>(pub(crate) &'p RefCell<Vec<FluentPart<'static>>>);
+
+impl fmt::Write for PartsWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !s.is_empty() {
+            push_part(
+                &mut self.0.borrow_mut(),
+                FluentPart::Literal(s.to_string().into()),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Lets the resolver add numbers and dates as parts instead of text.
+///
+/// Values are only split into parts when they are written to the [`PartsWriter`] itself,
+/// values written into temporary strings, e.g. for selectors or function arguments, stay text.
+pub(crate) struct PartsSink<'p> {
+    writer: *const (),
+    parts: &'p RefCell<Vec<FluentPart<'static>>>,
+}
+
+impl<'p> PartsSink<'p> {
+    pub(crate) fn new(writer: &PartsWriter<'p>) -> Self {
+        Self {
+            writer: writer as *const PartsWriter as *const (),
+            parts: writer.0,
+        }
+    }
+
+    /// Returns whether `w` is the writer that receives the parts.
+    pub(crate) fn is_writer<W>(&self, w: &W) -> bool {
+        std::ptr::eq(w as *const W as *const (), self.writer)
+    }
+
+    pub(crate) fn extend(&self, parts: Vec<FluentPart<'static>>) {
+        let mut all = self.parts.borrow_mut();
+        for part in parts {
+            push_part(&mut all, part);
+        }
+    }
+}
@@ -31,7 +31,7 @@ where
             InlineExpression::FunctionReference { id, .. } => Self::Function {
                 id: id.name.to_string(),
             },
-            InlineExpression::MessageReference { id, attribute } => Self::Message {
+            InlineExpression::MessageReference { id, attribute, .. } => Self::Message {
                 id: id.name.to_string(),
                 attribute: attribute.as_ref().map(|i| i.name.to_string()),
             },
@@ -54,7 +54,6 @@ pub enum ResolverError {
     Reference(ReferenceKind),
     NoValue(String),
     MissingDefault,
-    Cyclic,
     TooManyPlaceables,
 }
 
@@ -83,7 +82,6 @@ impl std::fmt::Display for ResolverError {
             },
             Self::NoValue(id) => write!(f, "No value: {}", id),
             Self::MissingDefault => f.write_str("No default"),
-            Self::Cyclic => f.write_str("Cyclical dependency detected"),
             Self::TooManyPlaceables => f.write_str("Too many placeables"),
         }
     }
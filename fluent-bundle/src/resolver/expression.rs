@@ -1,3 +1,4 @@
+use super::inline_expression::resolve_number_literal;
 use super::scope::Scope;
 use super::WriteValue;
 
@@ -24,15 +25,17 @@ impl<'bundle> WriteValue<'bundle> for ast::Expression<&'bundle str> {
     {
         match self {
             Self::Inline(exp) => exp.write(w, scope),
-            Self::Select { selector, variants } => {
+            Self::Select {
+                selector, variants, ..
+            } => {
                 let selector = selector.resolve(scope);
                 match selector {
-                    FluentValue::String(_) | FluentValue::Number(_) => {
+                    FluentValue::String(_) | FluentValue::Number(_) | FluentValue::List(..) => {
                         for variant in variants {
                             let key = match variant.key {
-                                ast::VariantKey::Identifier { name } => name.into(),
-                                ast::VariantKey::NumberLiteral { value } => {
-                                    FluentValue::try_number(value)
+                                ast::VariantKey::Identifier { name, .. } => name.into(),
+                                ast::VariantKey::NumberLiteral { value, .. } => {
+                                    resolve_number_literal(value)
                                 }
                             };
                             if key.matches(&selector, scope) {
@@ -24,8 +24,8 @@ impl<'bundle> WriteValue<'bundle> for ast::InlineExpression<&'bundle str> {
         M: MemoizerKind,
     {
         match self {
-            Self::StringLiteral { value } => unescape_unicode(w, value),
-            Self::MessageReference { id, attribute } => {
+            Self::StringLiteral { value, .. } => unescape_unicode(w, value),
+            Self::MessageReference { id, attribute, .. } => {
                 if let Some(msg) = scope.bundle.get_entry_message(id.name) {
                     if let Some(attr) = attribute {
                         msg.attributes
@@ -53,11 +53,14 @@ impl<'bundle> WriteValue<'bundle> for ast::InlineExpression<&'bundle str> {
                     scope.write_ref_error(w, self)
                 }
             }
-            Self::NumberLiteral { value } => FluentValue::try_number(value).write(w, scope),
+            Self::NumberLiteral { value, .. } => scope
+                .try_write_value(w, &resolve_number_literal(value))
+                .unwrap_or_else(|err| scope.write_format_error(w, self, err)),
             Self::TermReference {
                 id,
                 attribute,
                 arguments,
+                ..
             } => {
                 let (_, resolved_named_args) = scope.get_arguments(arguments.as_ref());
 
@@ -82,28 +85,42 @@ impl<'bundle> WriteValue<'bundle> for ast::InlineExpression<&'bundle str> {
                 scope.local_args = None;
                 result
             }
-            Self::FunctionReference { id, arguments } => {
+            Self::FunctionReference { id, arguments, .. } => {
                 let (resolved_positional_args, resolved_named_args) =
                     scope.get_arguments(Some(arguments));
 
                 let func = scope.bundle.get_entry_function(id.name);
 
                 if let Some(func) = func {
-                    let result = func(resolved_positional_args.as_slice(), &resolved_named_args);
-                    if let FluentValue::Error = result {
-                        self.write_error(w)
-                    } else {
-                        w.write_str(&result.into_string(scope))
+                    match scope.call_function(
+                        id.name,
+                        func,
+                        resolved_positional_args,
+                        &resolved_named_args,
+                    ) {
+                        Some(FluentValue::Error) => self.write_error(w),
+                        Some(result) => scope
+                            .try_write_value(w, &result)
+                            .unwrap_or_else(|err| scope.write_format_error(w, self, err)),
+                        None => {
+                            w.write_char('{')?;
+                            self.write_error(w)?;
+                            w.write_char('}')
+                        }
                     }
                 } else {
                     scope.write_ref_error(w, self)
                 }
             }
-            Self::VariableReference { id } => {
+            Self::VariableReference { id, .. } => {
+                scope.use_arg(id.name);
                 let args = scope.local_args.as_ref().or(scope.args);
 
                 if let Some(arg) = args.and_then(|args| args.get(id.name)) {
-                    arg.write(w, scope)
+                    match scope.try_write_value(w, arg) {
+                        Ok(result) => result,
+                        Err(err) => scope.write_format_error(w, self, err),
+                    }
                 } else {
                     if scope.local_args.is_none() {
                         scope.add_error(self.into());
@@ -113,7 +130,7 @@ impl<'bundle> WriteValue<'bundle> for ast::InlineExpression<&'bundle str> {
                     w.write_char('}')
                 }
             }
-            Self::Placeable { expression } => expression.write(w, scope),
+            Self::Placeable { expression, .. } => expression.write(w, scope),
         }
     }
 
@@ -125,10 +142,12 @@ impl<'bundle> WriteValue<'bundle> for ast::InlineExpression<&'bundle str> {
             Self::MessageReference {
                 id,
                 attribute: Some(attribute),
+                ..
             } => write!(w, "{}.{}", id.name, attribute.name),
             Self::MessageReference {
                 id,
                 attribute: None,
+                ..
             } => w.write_str(id.name),
             Self::TermReference {
                 id,
@@ -141,7 +160,7 @@ impl<'bundle> WriteValue<'bundle> for ast::InlineExpression<&'bundle str> {
                 ..
             } => write!(w, "-{}", id.name),
             Self::FunctionReference { id, .. } => write!(w, "{}()", id.name),
-            Self::VariableReference { id } => write!(w, "${}", id.name),
+            Self::VariableReference { id, .. } => write!(w, "${}", id.name),
             _ => unreachable!(),
         }
     }
@@ -157,9 +176,10 @@ impl<'bundle> ResolveValue<'bundle> for ast::InlineExpression<&'bundle str> {
         M: MemoizerKind,
     {
         match self {
-            Self::StringLiteral { value } => unescape_unicode_to_string(value).into(),
-            Self::NumberLiteral { value } => FluentValue::try_number(value),
-            Self::VariableReference { id } => {
+            Self::StringLiteral { value, .. } => unescape_unicode_to_string(value).into(),
+            Self::NumberLiteral { value, .. } => resolve_number_literal(value),
+            Self::VariableReference { id, .. } => {
+                scope.use_arg(id.name);
                 if let Some(local_args) = &scope.local_args {
                     if let Some(arg) = local_args.get(id.name) {
                         return arg.clone();
@@ -173,15 +193,21 @@ impl<'bundle> ResolveValue<'bundle> for ast::InlineExpression<&'bundle str> {
                 }
                 FluentValue::Error
             }
-            Self::FunctionReference { id, arguments } => {
+            Self::FunctionReference { id, arguments, .. } => {
                 let (resolved_positional_args, resolved_named_args) =
                     scope.get_arguments(Some(arguments));
 
                 let func = scope.bundle.get_entry_function(id.name);
 
                 if let Some(func) = func {
-                    let result = func(resolved_positional_args.as_slice(), &resolved_named_args);
-                    result
+                    scope
+                        .call_function(
+                            id.name,
+                            func,
+                            resolved_positional_args,
+                            &resolved_named_args,
+                        )
+                        .unwrap_or(FluentValue::Error)
                 } else {
                     FluentValue::Error
                 }
@@ -194,3 +220,9 @@ impl<'bundle> ResolveValue<'bundle> for ast::InlineExpression<&'bundle str> {
         }
     }
 }
+
+/// The parser only produces valid number literals, but should one fail to parse
+/// it is kept as written.
+pub(super) fn resolve_number_literal(value: &str) -> FluentValue<'_> {
+    FluentValue::try_number(value).unwrap_or_else(|_| value.into())
+}
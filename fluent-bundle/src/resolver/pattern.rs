@@ -32,14 +32,14 @@ impl<'bundle> WriteValue<'bundle> for ast::Pattern<&'bundle str> {
             }
 
             match elem {
-                ast::PatternElement::TextElement { value } => {
+                ast::PatternElement::TextElement { value, .. } => {
                     if let Some(ref transform) = scope.bundle.transform {
                         w.write_str(&transform(value))?;
                     } else {
                         w.write_str(value)?;
                     }
                 }
-                ast::PatternElement::Placeable { ref expression } => {
+                ast::PatternElement::Placeable { ref expression, .. } => {
                     scope.placeables += 1;
                     if scope.placeables > MAX_PLACEABLES {
                         scope.dirty = true;
@@ -92,11 +92,23 @@ impl<'bundle> ResolveValue<'bundle> for ast::Pattern<&'bundle str> {
         let len = self.elements.len();
 
         if len == 1 {
-            if let ast::PatternElement::TextElement { value } = self.elements[0] {
-                return scope
-                    .bundle
-                    .transform
-                    .map_or_else(|| value.into(), |transform| transform(value).into());
+            match &self.elements[0] {
+                ast::PatternElement::TextElement { value, .. } => {
+                    return scope
+                        .bundle
+                        .transform
+                        .map_or_else(|| (*value).into(), |transform| transform(value).into());
+                }
+                // A lone string literal is never isolated, so it can borrow from the resource
+                // unless it contains escape sequences.
+                ast::PatternElement::Placeable {
+                    expression:
+                        ast::Expression::Inline(
+                            expression @ ast::InlineExpression::StringLiteral { .. },
+                        ),
+                    ..
+                } => return expression.resolve(scope),
+                _ => {}
             }
         }
 
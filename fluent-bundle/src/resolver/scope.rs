@@ -1,8 +1,11 @@
 use crate::bundle::FluentBundle;
+use crate::entry::{FluentFunction, GetEntry};
 use crate::memoizer::MemoizerKind;
+use crate::parts::PartsSink;
+use crate::resolver::errors::ReferenceKind;
 use crate::resolver::{ResolveValue, ResolverError, WriteValue};
 use crate::types::FluentValue;
-use crate::{FluentArgs, FluentError, FluentResource};
+use crate::{FluentArgs, FluentError, FluentFunctionError, FluentResource, FluentWarning};
 use fluent_syntax_for_carbide::ast;
 use std::borrow::Borrow;
 use std::fmt;
@@ -22,6 +25,13 @@ pub struct Scope<'bundle, 'ast, 'args, 'errors, R, M> {
     travelled: smallvec::SmallVec<[&'ast ast::Pattern<&'bundle str>; 2]>,
     /// Track errors accumulated during resolving.
     pub errors: Option<&'errors mut Vec<FluentError>>,
+    /// Track warnings accumulated during resolving. Without it, missing references
+    /// are reported as errors.
+    pub warnings: Option<&'errors mut Vec<FluentWarning>>,
+    /// The arguments used while resolving, tracked only when collecting warnings.
+    used_args: Vec<&'bundle str>,
+    /// Receives numbers and dates as parts, when formatting a pattern into parts.
+    pub(crate) parts: Option<PartsSink<'errors>>,
     /// Makes the resolver bail.
     pub dirty: bool,
 }
@@ -39,16 +49,115 @@ impl<'bundle, 'ast, 'args, 'errors, R, M> Scope<'bundle, 'ast, 'args, 'errors, R
             placeables: 0,
             travelled: Default::default(),
             errors,
+            warnings: None,
+            used_args: Vec::new(),
+            parts: None,
             dirty: false,
         }
     }
 
-    pub fn add_error(&mut self, error: ResolverError) {
+    /// Records a resolver error, together with the id of the message that is being resolved.
+    pub fn add_error(&mut self, error: ResolverError)
+    where
+        R: Borrow<FluentResource>,
+    {
+        if self.errors.is_none() {
+            return;
+        }
+
+        let id = self.message_id();
+        if let Some(errors) = self.errors.as_mut() {
+            errors.push(FluentError::ResolverError { id, error });
+        }
+    }
+
+    /// Records the error of a value that failed to format.
+    pub fn add_format_error(&mut self, error: FluentError) {
         if let Some(errors) = self.errors.as_mut() {
-            errors.push(error.into());
+            errors.push(error);
+        }
+    }
+
+    pub fn add_warning(&mut self, warning: FluentWarning) {
+        if let Some(warnings) = self.warnings.as_mut() {
+            warnings.push(warning);
         }
     }
 
+    /// Records that the argument `name` passed by the developer is used, unless the
+    /// reference is resolved with the local arguments of a term.
+    pub(super) fn use_arg(&mut self, name: &'bundle str) {
+        if self.warnings.is_some()
+            && self.local_args.is_none()
+            && self.args.is_some_and(|args| args.get(name).is_some())
+            && !self.used_args.contains(&name)
+        {
+            self.used_args.push(name);
+        }
+    }
+
+    /// Adds a warning for every argument passed by the developer that was not used.
+    pub(crate) fn warn_unused_args(&mut self) {
+        if let Some(args) = self.args {
+            for (name, _) in args.iter() {
+                if !self.used_args.contains(&name) {
+                    self.add_warning(FluentWarning::UnusedArgument(name.to_string()));
+                }
+            }
+        }
+    }
+
+    /// Returns the id of the innermost message being resolved. Patterns don't know
+    /// which message they belong to, so the messages of the bundle are searched.
+    fn message_id(&self) -> Option<String>
+    where
+        R: Borrow<FluentResource>,
+    {
+        self.travelled
+            .iter()
+            .rev()
+            .find_map(|pattern| {
+                self.bundle.entries.keys().find(|id| {
+                    self.bundle.get_entry_message(id).is_some_and(|message| {
+                        message
+                            .value
+                            .as_ref()
+                            .is_some_and(|value| std::ptr::eq(value, *pattern))
+                            || message
+                                .attributes
+                                .iter()
+                                .any(|attribute| std::ptr::eq(&attribute.value, *pattern))
+                    })
+                })
+            })
+            .cloned()
+    }
+
+    /// Returns the id of the message or term that owns the `pattern`, with the name
+    /// of the attribute for attributes, e.g. `hello`, `hello.title` or `-brand`.
+    pub(crate) fn pattern_id(&self, pattern: &ast::Pattern<&str>) -> Option<String>
+    where
+        R: Borrow<FluentResource>,
+    {
+        self.bundle.entries.keys().find_map(|id| {
+            let (prefix, value, attributes) = match self.bundle.get_entry_message(id) {
+                Some(message) => ("", message.value.as_ref(), &message.attributes),
+                None => {
+                    let term = self.bundle.get_entry_term(id)?;
+                    ("-", Some(&term.value), &term.attributes)
+                }
+            };
+
+            if value.is_some_and(|value| std::ptr::eq(value, pattern)) {
+                return Some(format!("{}{}", prefix, id));
+            }
+            attributes
+                .iter()
+                .find(|attribute| std::ptr::eq(&attribute.value, pattern))
+                .map(|attribute| format!("{}{}.{}", prefix, id, attribute.id.name))
+        })
+    }
+
     /// This method allows us to lazily add Pattern on the stack, only if the
     /// Pattern::resolve has been called on an empty stack.
     ///
@@ -89,8 +198,36 @@ impl<'bundle, 'ast, 'args, 'errors, R, M> Scope<'bundle, 'ast, 'args, 'errors, R
         W: fmt::Write,
         M: MemoizerKind,
     {
-        if self.travelled.contains(&pattern) {
-            self.add_error(ResolverError::Cyclic);
+        // Patterns are compared by address, as different entries can have equal patterns.
+        if let Some(start) = self
+            .travelled
+            .iter()
+            .position(|travelled| std::ptr::eq(*travelled, pattern))
+        {
+            if self.errors.is_some() {
+                // The ids of the entries in the cycle, ending with the entry it started with.
+                let ids = self.travelled[start..]
+                    .iter()
+                    .chain(std::iter::once(&pattern))
+                    .map(|pattern| self.pattern_id(pattern).unwrap_or_default())
+                    .collect();
+                if let Some(errors) = self.errors.as_mut() {
+                    errors.push(FluentError::CyclicReference { ids });
+                }
+            }
+            w.write_char('{')?;
+            exp.write_error(w)?;
+            w.write_char('}')
+        } else if self.travelled.len() > self.bundle.max_recursion_depth {
+            if self.errors.is_some() {
+                let error = FluentError::RecursionDepthExceeded {
+                    message_id: self.pattern_id(pattern).unwrap_or_default(),
+                    depth: self.travelled.len(),
+                };
+                if let Some(errors) = self.errors.as_mut() {
+                    errors.push(error);
+                }
+            }
             w.write_char('{')?;
             exp.write_error(w)?;
             w.write_char('}')
@@ -107,15 +244,118 @@ impl<'bundle, 'ast, 'args, 'errors, R, M> Scope<'bundle, 'ast, 'args, 'errors, R
         w: &mut W,
         exp: &ast::InlineExpression<&str>,
     ) -> fmt::Result
+    where
+        R: Borrow<FluentResource>,
+        W: fmt::Write,
+    {
+        match (exp.into(), self.warnings.is_some()) {
+            (
+                ResolverError::Reference(
+                    reference @ (ReferenceKind::Message { .. } | ReferenceKind::Term { .. }),
+                ),
+                true,
+            ) => {
+                let id = self.message_id();
+                self.add_warning(FluentWarning::MissingReference { id, reference });
+            }
+            (error, _) => self.add_error(error),
+        }
+        w.write_char('{')?;
+        exp.write_error(w)?;
+        w.write_char('}')
+    }
+
+    /// Writes a resolved value, returning the error of a value that failed to format
+    /// instead of writing it.
+    pub fn try_write_value<W>(
+        &self,
+        w: &mut W,
+        value: &FluentValue,
+    ) -> Result<fmt::Result, FluentError>
+    where
+        R: Borrow<FluentResource>,
+        W: fmt::Write,
+        M: MemoizerKind,
+    {
+        let locale = self.bundle.locales.first().unwrap();
+        if let Some(parts) = self.parts.as_ref().filter(|parts| parts.is_writer(w)) {
+            match (value, &self.bundle.formatter) {
+                (FluentValue::DateTime(d), None) => {
+                    parts.extend(d.to_parts(locale)?);
+                    return Ok(Ok(()));
+                }
+                (FluentValue::Number(n), None) => {
+                    parts.extend(n.to_parts(locale)?);
+                    return Ok(Ok(()));
+                }
+                _ => {}
+            }
+        }
+        value.write(w, self)
+    }
+
+    pub fn write_format_error<W>(
+        &mut self,
+        w: &mut W,
+        exp: &ast::InlineExpression<&str>,
+        error: FluentError,
+    ) -> fmt::Result
     where
         W: fmt::Write,
     {
-        self.add_error(exp.into());
+        self.add_format_error(error);
         w.write_char('{')?;
         exp.write_error(w)?;
         w.write_char('}')
     }
 
+    /// Calls a function of the bundle. A function that fails is reported as a
+    /// [`FluentError`] and replaced by its first positional argument, or `None` if it
+    /// has no arguments.
+    ///
+    /// Named arguments that have no effect on a returned number are reported as warnings.
+    pub(super) fn call_function(
+        &mut self,
+        name: &str,
+        func: &FluentFunction,
+        positional: Vec<FluentValue<'bundle>>,
+        named: &FluentArgs,
+    ) -> Option<FluentValue<'bundle>> {
+        match func(positional.as_slice(), named) {
+            Ok(result) => {
+                if let FluentValue::Number(number) = &result {
+                    for option in number.options.unused_options() {
+                        if named.get(option).is_some() {
+                            self.add_warning(FluentWarning::UnusedFunctionArgument {
+                                function: name.to_string(),
+                                argument: option.to_string(),
+                            });
+                        }
+                    }
+                }
+                Some(result)
+            }
+            Err(error) => {
+                let error = match error {
+                    FluentFunctionError::Message(message) => FluentError::FunctionError {
+                        name: name.to_string(),
+                        message,
+                    },
+                    FluentFunctionError::MissingArgument(argument) => {
+                        FluentError::MissingFunctionArgument {
+                            function: name.to_string(),
+                            argument,
+                        }
+                    }
+                };
+                if let Some(errors) = self.errors.as_mut() {
+                    errors.push(error);
+                }
+                positional.into_iter().next()
+            }
+        }
+    }
+
     pub fn get_arguments(
         &mut self,
         arguments: Option<&'ast ast::CallArguments<&'bundle str>>,
@@ -124,7 +364,10 @@ impl<'bundle, 'ast, 'args, 'errors, R, M> Scope<'bundle, 'ast, 'args, 'errors, R
         R: Borrow<FluentResource>,
         M: MemoizerKind,
     {
-        if let Some(ast::CallArguments { positional, named }) = arguments {
+        if let Some(ast::CallArguments {
+            positional, named, ..
+        }) = arguments
+        {
             let positional = positional.iter().map(|expr| expr.resolve(self)).collect();
 
             let named = named
@@ -1,7 +1,14 @@
 use fluent_syntax_for_carbide::ast;
-use fluent_syntax_for_carbide::parser::{parse_runtime, ParserError};
+use fluent_syntax_for_carbide::parser::parse_runtime;
+
+use crate::errors::FluentResourceError;
+use crate::FluentError;
 
 use self_cell::self_cell;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
 
 type Resource<'s> = ast::Resource<&'s str>;
 
@@ -47,8 +54,27 @@ self_cell!(
 ///
 /// A resource owns the source string and the AST contains references
 /// to the slices of the source.
-#[derive(Debug)]
-pub struct FluentResource(InnerFluentResource);
+///
+/// Both are shared between clones of a resource, so cloning is cheap and
+/// the same resource can be added to several bundles.
+///
+/// ```
+/// use fluent_bundle::{FluentBundle, FluentResource};
+/// use icu::locid::locale;
+///
+/// let resource = FluentResource::try_new("hello-world = Hello World!".to_string())
+///     .expect("Failed to parse FTL.");
+///
+/// let mut first = FluentBundle::new(vec![locale!("en-US")]);
+/// first.add_resource(resource.clone())
+///     .expect("Failed to add FTL resources to the bundle.");
+///
+/// let mut second = FluentBundle::new(vec![locale!("en-US")]);
+/// second.add_resource(resource)
+///     .expect("Failed to add FTL resources to the bundle.");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FluentResource(Arc<InnerFluentResource>);
 
 impl FluentResource {
     /// A fallible constructor of a new [`FluentResource`].
@@ -78,8 +104,8 @@ impl FluentResource {
     /// The method will return the resource irrelevant of parse errors
     /// encountered during parsing of the source, but in case of errors,
     /// the `Err` variant will contain both the structure and a vector
-    /// of errors.
-    pub fn try_new(source: String) -> Result<Self, (Self, Vec<ParserError>)> {
+    /// of [`FluentError::ParserError`]s with the line and column of each error.
+    pub fn try_new(source: String) -> Result<Self, (Self, Vec<FluentError>)> {
         let mut errors = None;
 
         let res = InnerFluentResource::new(source, |source| match parse_runtime(source.as_str()) {
@@ -91,14 +117,76 @@ impl FluentResource {
         });
 
         match errors {
-            None => Ok(Self(res)),
-            Some(err) => Err((Self(res), err)),
+            None => Ok(Self(Arc::new(res))),
+            Some(err) => {
+                let source = res.borrow_owner();
+                let err = err
+                    .into_iter()
+                    .map(|err| FluentError::parser_error(err, source))
+                    .collect();
+                Err((Self(Arc::new(res)), err))
+            }
+        }
+    }
+
+    /// Reads a `Fluent Translation List` file and parses it like
+    /// [`FluentResource::try_new`].
+    ///
+    /// The resource is returned together with the parse errors, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`io::Error`] if the file can not be read, or is not valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use fluent_bundle::FluentResource;
+    ///
+    /// let (resource, errors) = FluentResource::from_file("locales/en-US/main.ftl")
+    ///     .expect("Failed to read the file.");
+    ///
+    /// assert!(errors.is_empty());
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<(Self, Vec<FluentError>), io::Error> {
+        let source = fs::read_to_string(path)?;
+
+        Ok(match Self::try_new(source) {
+            Ok(res) => (res, vec![]),
+            Err((res, errors)) => (res, errors),
+        })
+    }
+
+    /// Reads a `Fluent Translation List` file and parses it, failing on
+    /// parse errors as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FluentResourceError::Io`] if the file can not be read, and
+    /// [`FluentResourceError::Parse`] with the [`FluentError::ParserError`]s if it
+    /// has syntax errors. Use [`FluentResource::from_file`] to keep the entries
+    /// that did parse.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use fluent_bundle::FluentResource;
+    ///
+    /// let resource = FluentResource::try_from_file("locales/en-US/main.ftl")
+    ///     .expect("Failed to load the resource.");
+    /// ```
+    pub fn try_from_file<P: AsRef<Path>>(path: P) -> Result<Self, FluentResourceError> {
+        match Self::from_file(path)? {
+            (res, errors) if errors.is_empty() => Ok(res),
+            (_, errors) => Err(FluentResourceError::Parse(errors)),
         }
     }
 
     /// Returns a reference to the source string that was used
     /// to construct the [`FluentResource`].
     ///
+    /// The [`span`](fluent_syntax::ast::Span)s of the entries are byte offsets into it.
+    ///
     /// # Example
     ///
     /// ```
@@ -0,0 +1,133 @@
+use crate::args::FluentArgs;
+use crate::errors::FluentError;
+use crate::types::{
+    FluentDateStyle, FluentDateTime, FluentDateTimeOptions, FluentTimeStyle, FluentValue,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime};
+use icu::locid::Locale;
+use std::borrow::Cow;
+
+/// A date without a time of day, e.g. a birthday.
+///
+/// Only the [`FluentDateStyle`] can be chosen, with the `dateStyle` argument of the `DATE` function.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct FluentDate {
+    pub value: NaiveDate,
+    pub style: FluentDateStyle,
+}
+
+impl FluentDate {
+    pub fn new(value: NaiveDate, style: FluentDateStyle) -> Self {
+        Self { value, style }
+    }
+
+    pub fn merge(&mut self, opts: &FluentArgs) {
+        for (key, value) in opts.iter() {
+            if let ("dateStyle", FluentValue::String(n)) = (key, value) {
+                self.style = n.as_ref().into();
+            }
+        }
+    }
+
+    /// Converts the date into a naive [`FluentDateTime`] at midnight, that only displays the date.
+    pub fn to_datetime(&self) -> FluentDateTime {
+        let options = FluentDateTimeOptions {
+            date_style: self.style,
+            time_style: FluentTimeStyle::Hidden,
+            ..Default::default()
+        };
+
+        FluentDateTime::from(self.value.and_time(NaiveTime::MIN)).with_options(options)
+    }
+
+    /// Formats the date for the given locale.
+    pub fn as_string(&self, locale: &Locale) -> Result<Cow<'static, str>, FluentError> {
+        // A hidden date would display nothing at all.
+        if self.style == FluentDateStyle::Hidden {
+            return Self::new(self.value, FluentDateStyle::default()).as_string(locale);
+        }
+
+        self.to_datetime().as_string(locale)
+    }
+}
+
+/// A time of day without a date, e.g. an opening hour.
+///
+/// Only the [`FluentTimeStyle`] can be chosen, with the `timeStyle` argument of the `TIME` function.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct FluentTime {
+    pub value: NaiveTime,
+    pub style: FluentTimeStyle,
+}
+
+impl FluentTime {
+    pub fn new(value: NaiveTime, style: FluentTimeStyle) -> Self {
+        Self { value, style }
+    }
+
+    pub fn merge(&mut self, opts: &FluentArgs) {
+        for (key, value) in opts.iter() {
+            if let ("timeStyle", FluentValue::String(n)) = (key, value) {
+                self.style = n.as_ref().into();
+            }
+        }
+    }
+
+    /// Converts the time into a naive [`FluentDateTime`] on 1970-01-01, that only displays the time.
+    pub fn to_datetime(&self) -> FluentDateTime {
+        let options = FluentDateTimeOptions {
+            date_style: FluentDateStyle::Hidden,
+            time_style: self.style,
+            ..Default::default()
+        };
+
+        FluentDateTime::from(DateTime::UNIX_EPOCH.date_naive().and_time(self.value))
+            .with_options(options)
+    }
+
+    /// Formats the time for the given locale.
+    pub fn as_string(&self, locale: &Locale) -> Result<Cow<'static, str>, FluentError> {
+        // A hidden time would display nothing at all.
+        if self.style == FluentTimeStyle::Hidden {
+            return Self::new(self.value, FluentTimeStyle::default()).as_string(locale);
+        }
+
+        self.to_datetime().as_string(locale)
+    }
+}
+
+impl From<NaiveDate> for FluentDate {
+    fn from(value: NaiveDate) -> Self {
+        Self::new(value, Default::default())
+    }
+}
+
+impl From<NaiveTime> for FluentTime {
+    fn from(value: NaiveTime) -> Self {
+        Self::new(value, Default::default())
+    }
+}
+
+impl<'l> From<FluentDate> for FluentValue<'l> {
+    fn from(input: FluentDate) -> Self {
+        FluentValue::Date(input)
+    }
+}
+
+impl<'l> From<FluentTime> for FluentValue<'l> {
+    fn from(input: FluentTime) -> Self {
+        FluentValue::Time(input)
+    }
+}
+
+impl<'l> From<NaiveDate> for FluentValue<'l> {
+    fn from(input: NaiveDate) -> Self {
+        FluentValue::Date(input.into())
+    }
+}
+
+impl<'l> From<NaiveTime> for FluentValue<'l> {
+    fn from(input: NaiveTime) -> Self {
+        FluentValue::Time(input.into())
+    }
+}
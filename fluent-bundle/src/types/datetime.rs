@@ -1,25 +1,72 @@
-use std::borrow::Cow;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::str::FromStr;
-use chrono::{Datelike, DateTime, FixedOffset, Timelike};
+use crate::parts::{split_runs, DateTimePartType, FluentPart};
+use crate::types::formatter_cache::{locale_cache, FormatterCache};
+use crate::types::IsoFormat::{Basic, Extended, UtcBasic, UtcExtended};
+use crate::types::IsoMinutes::Required;
+use crate::types::IsoSeconds::Optional;
+use crate::{FluentArgs, FluentError, FluentValue};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, TimeZone, Timelike};
+use icu::calendar::types::NanoSecond;
 use icu::calendar::{AnyCalendar, Calendar, Date, Gregorian};
-use icu::datetime::{DateFormatter, DateTimeFormatter, DateTimeFormatterOptions, TimeFormatter, ZonedDateTimeFormatter};
 use icu::datetime::input::{DateInput, IsoTimeInput};
-use icu::datetime::options::length;
 use icu::datetime::options::length::Time;
+use icu::datetime::options::{components, length, preferences};
+use icu::datetime::pattern::CoarseHourCycle;
+use icu::datetime::provider::calendar::TimeLengthsV1Marker;
+use icu::datetime::provider::Baked;
 use icu::datetime::time_zone::{FallbackFormat, TimeZoneFormatter, TimeZoneFormatterOptions};
-use icu::locid::Locale;
+use icu::datetime::{
+    DateFormatter, DateTimeFormatter, DateTimeFormatterOptions, TimeFormatter,
+    ZonedDateTimeFormatter,
+};
+use icu::locid::extensions::unicode::{key, value, Value};
+use icu::locid::{LanguageIdentifier, Locale};
 use icu::timezone::CustomTimeZone;
-use crate::{FluentArgs, FluentValue};
-use crate::types::IsoFormat::{Basic, Extended, UtcBasic, UtcExtended};
-use crate::types::IsoMinutes::Required;
-use crate::types::IsoSeconds::Optional;
+use icu_provider::{DataPayload, DataProvider, DataRequest};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Clone)]
+/// Two datetimes are equal, and hash the same, if they are the same instant with the same
+/// options, even if their offsets differ.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FluentDateTime {
+    /// Serialized as an RFC 3339 string, e.g. `2024-01-01T00:00:00+00:00`.
+    #[cfg_attr(feature = "serde", serde(with = "rfc3339"))]
     pub value: DateTime<FixedOffset>,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub options: FluentDateTimeOptions,
+
+    /// Whether the value was created without a timezone, in which case it is stored as UTC
+    /// and formatted without a timezone.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::ops::Not::not")
+    )]
+    pub naive: bool,
+}
+
+#[cfg(feature = "serde")]
+mod rfc3339 {
+    use chrono::{DateTime, FixedOffset};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &DateTime<FixedOffset>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_rfc3339())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<FixedOffset>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&value).map_err(de::Error::custom)
+    }
 }
 
 enum Formatter {
@@ -31,98 +78,381 @@ enum Formatter {
 }
 
 impl Formatter {
-    fn format_string<T: DateInput<Calendar = AnyCalendar> + IsoTimeInput>(&self, date: &T, zone: &CustomTimeZone) -> String {
+    fn format_string<T: DateInput<Calendar = AnyCalendar> + IsoTimeInput>(
+        &self,
+        date: &T,
+        zone: &CustomTimeZone,
+    ) -> Result<String, FluentError> {
         match self {
-            Formatter::Date(d) => d.format_to_string(date).unwrap(),
-            Formatter::DateTime(d) => d.format_to_string(date).unwrap(),
-            Formatter::Time(d) => d.format_to_string(date),
-            Formatter::ZonedDateTime(d) => d.format_to_string(date, zone).unwrap(),
-            Formatter::TimeZone(d) => d.format_to_string(zone),
+            Formatter::Date(d) => d.format_to_string(date).map_err(datetime_error),
+            Formatter::DateTime(d) => d.format_to_string(date).map_err(datetime_error),
+            Formatter::Time(d) => Ok(d.format_to_string(date)),
+            Formatter::ZonedDateTime(d) => d.format_to_string(date, zone).map_err(datetime_error),
+            Formatter::TimeZone(d) => Ok(d.format_to_string(zone)),
         }
     }
 
-    fn new(locale: &Locale, date: Option<length::Date>, time: Option<length::Time>, zone: Option<FallbackFormat>) -> Option<Formatter> {
+    fn new(
+        locale: &Locale,
+        date: Option<length::Date>,
+        time: Option<length::Time>,
+        zone: Option<FallbackFormat>,
+        options: &FluentDateTimeOptions,
+    ) -> Result<Option<Formatter>, FluentError> {
+        // ICU picks up the hour cycle and the calendar from the `hc` and `ca` unicode extensions of the locale.
+        let mut locale = locale.clone();
+        if let Some(hour_cycle) = options.hour_cycle {
+            let hour_cycle = match hour_cycle {
+                FluentHourCycle::H11 => value!("h11"),
+                FluentHourCycle::H12 => value!("h12"),
+                FluentHourCycle::H23 => value!("h23"),
+                FluentHourCycle::H24 => value!("h24"),
+            };
+            locale
+                .extensions
+                .unicode
+                .keywords
+                .set(key!("hc"), hour_cycle);
+        }
+        if let Some(calendar) = options
+            .calendar
+            .as_ref()
+            .and_then(|calendar| Value::try_from_bytes(calendar.as_bytes()).ok())
+        {
+            locale.extensions.unicode.keywords.set(key!("ca"), calendar);
+        }
+        let locale = &locale;
+
+        // The length styles can not display the era or fractional seconds, or choose the weekday, so a skeleton is
+        // built from the styles instead.
+        let era = match options.era {
+            FluentEraStyle::Long => Some(components::Text::Long),
+            FluentEraStyle::Short => Some(components::Text::Short),
+            FluentEraStyle::Narrow => Some(components::Text::Narrow),
+            FluentEraStyle::Hidden => None,
+        }
+        .filter(|_| date.is_some());
+        let fractional_second = options
+            .fractional_second_digits
+            .map(|digits| digits.clamp(1, 3))
+            .filter(|_| time.is_some());
+        let weekday = match options.weekday {
+            FluentWeekdayStyle::Long => Some(components::Text::Long),
+            FluentWeekdayStyle::Short => Some(components::Text::Short),
+            FluentWeekdayStyle::Narrow => Some(components::Text::Narrow),
+            FluentWeekdayStyle::Hidden => None,
+        };
+
+        if era.is_some() || fractional_second.is_some() || weekday.is_some() {
+            let mut bag = Self::components(date, time);
+            bag.era = era;
+            if weekday.is_some() {
+                bag.weekday = weekday;
+            }
+
+            if let Some(digits) = fractional_second {
+                bag.second = Some(components::Numeric::TwoDigit);
+                bag.fractional_second = Some(digits);
+            }
+
+            return Self::new_components(locale, bag, zone, options.hour_cycle).map(Some);
+        }
+
         match (date, time, zone) {
-            (None, None, None) => None,
-            (Some(date_style), None, None) => Some(Formatter::Date(DateFormatter::try_new_with_length(&locale.into(), date_style).expect("Failed to create DateFormatter instance."))),
+            (None, None, None) => Ok(None),
+            (Some(date_style), None, None) => {
+                let df = DateFormatter::try_new_with_length(&locale.into(), date_style)
+                    .map_err(datetime_error)?;
+
+                Ok(Some(Formatter::Date(df)))
+            }
             (Some(date_style), Some(time_style), None) => {
                 let time_style = match time_style {
-                    Time::Full |
-                    Time::Long |
-                    Time::Medium => Time::Medium,
+                    Time::Full | Time::Long | Time::Medium => Time::Medium,
                     Time::Short => Time::Short,
-                    _ => unimplemented!()
+                    _ => {
+                        return Err(FluentError::DateTimeFormat(format!(
+                            "Unsupported time style: {:?}",
+                            time_style
+                        )))
+                    }
                 };
 
-                let options =
-                    DateTimeFormatterOptions::Length(length::Bag::from_date_time_style(
-                        date_style,
-                        time_style,
-                    ));
+                let options = DateTimeFormatterOptions::Length(length::Bag::from_date_time_style(
+                    date_style, time_style,
+                ));
 
                 let dtf = DateTimeFormatter::try_new(&locale.into(), options.clone())
-                    .expect("Failed to create DateTimeFormatter instance.");
+                    .map_err(datetime_error)?;
 
-                Some(Formatter::DateTime(dtf))
+                Ok(Some(Formatter::DateTime(dtf)))
             }
             (Some(date_style), Some(time_style), Some(timezone_style)) => {
-                let timezone_options =
-                    TimeZoneFormatterOptions::from(timezone_style);
+                let timezone_options = TimeZoneFormatterOptions::from(timezone_style);
 
-                let options =
-                    DateTimeFormatterOptions::Length(length::Bag::from_date_time_style(
-                        date_style,
-                        time_style,
-                    ));
+                let options = DateTimeFormatterOptions::Length(length::Bag::from_date_time_style(
+                    date_style, time_style,
+                ));
 
-                let dtf = ZonedDateTimeFormatter::try_new(&locale.into(), options, timezone_options)
-                    .expect("Failed to create ZonedDateTimeFormatter instance.");
+                let dtf =
+                    ZonedDateTimeFormatter::try_new(&locale.into(), options, timezone_options)
+                        .map_err(datetime_error)?;
 
-                Some(Formatter::ZonedDateTime(dtf))
+                Ok(Some(Formatter::ZonedDateTime(dtf)))
             }
             (None, Some(time_format), None) => {
                 let time_format = match time_format {
-                    Time::Full |
-                    Time::Long |
-                    Time::Medium => Time::Medium,
+                    Time::Full | Time::Long | Time::Medium => Time::Medium,
                     Time::Short => Time::Short,
-                    _ => unimplemented!()
+                    _ => {
+                        return Err(FluentError::DateTimeFormat(format!(
+                            "Unsupported time style: {:?}",
+                            time_format
+                        )))
+                    }
                 };
 
                 let dtf = TimeFormatter::try_new_with_length(&locale.into(), time_format)
-                    .expect("Failed to create TimeFormatter instance.");
+                    .map_err(datetime_error)?;
 
-                Some(Formatter::Time(dtf))
+                Ok(Some(Formatter::Time(dtf)))
             }
             (None, None, Some(timezone_style)) => {
-                let dtf = TimeZoneFormatter::try_new(&locale.into(), TimeZoneFormatterOptions::from(timezone_style))
-                    .expect("Failed to create TimeZoneFormatter instance.");
+                let dtf = TimeZoneFormatter::try_new(
+                    &locale.into(),
+                    TimeZoneFormatterOptions::from(timezone_style),
+                )
+                .map_err(datetime_error)?;
+
+                Ok(Some(Formatter::TimeZone(dtf)))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Builds the skeleton that is closest to the given date and time styles.
+    fn components(date: Option<length::Date>, time: Option<length::Time>) -> components::Bag {
+        let mut bag = components::Bag::default();
+
+        match date {
+            Some(length::Date::Full) => {
+                bag.weekday = Some(components::Text::Long);
+                bag.year = Some(components::Year::Numeric);
+                bag.month = Some(components::Month::Long);
+                bag.day = Some(components::Day::NumericDayOfMonth);
+            }
+            Some(length::Date::Long) => {
+                bag.year = Some(components::Year::Numeric);
+                bag.month = Some(components::Month::Long);
+                bag.day = Some(components::Day::NumericDayOfMonth);
+            }
+            Some(length::Date::Short) => {
+                bag.year = Some(components::Year::TwoDigit);
+                bag.month = Some(components::Month::Numeric);
+                bag.day = Some(components::Day::NumericDayOfMonth);
+            }
+            Some(_) => {
+                bag.year = Some(components::Year::Numeric);
+                bag.month = Some(components::Month::Short);
+                bag.day = Some(components::Day::NumericDayOfMonth);
+            }
+            None => {}
+        }
 
-                Some(Formatter::TimeZone(dtf))
+        if let Some(time) = time {
+            bag.hour = Some(components::Numeric::Numeric);
+            bag.minute = Some(components::Numeric::TwoDigit);
+
+            if time != Time::Short {
+                bag.second = Some(components::Numeric::TwoDigit);
             }
-            _ => None,
         }
+
+        bag
+    }
+
+    fn new_components(
+        locale: &Locale,
+        mut bag: components::Bag,
+        zone: Option<FallbackFormat>,
+        hour_cycle: Option<FluentHourCycle>,
+    ) -> Result<Formatter, FluentError> {
+        // Skeletons default to a 24-hour clock instead of the hour cycle of the locale.
+        if bag.hour.is_some() {
+            let hour_cycle = match hour_cycle {
+                Some(FluentHourCycle::H11) => preferences::HourCycle::H11,
+                Some(FluentHourCycle::H12) => preferences::HourCycle::H12,
+                Some(FluentHourCycle::H23) => preferences::HourCycle::H23,
+                Some(FluentHourCycle::H24) => preferences::HourCycle::H24,
+                None => preferred_hour_cycle(locale),
+            };
+            bag.preferences = Some(preferences::Bag::from_hour_cycle(hour_cycle));
+        }
+
+        let options = DateTimeFormatterOptions::Components(bag);
+
+        if let Some(timezone_style) = zone {
+            let dtf = ZonedDateTimeFormatter::try_new_experimental(
+                &locale.into(),
+                options,
+                TimeZoneFormatterOptions::from(timezone_style),
+            )
+            .map_err(datetime_error)?;
+
+            Ok(Formatter::ZonedDateTime(dtf))
+        } else {
+            let dtf = DateTimeFormatter::try_new_experimental(&locale.into(), options)
+                .map_err(datetime_error)?;
+
+            Ok(Formatter::DateTime(dtf))
+        }
+    }
+}
+
+fn preferred_hour_cycle(locale: &Locale) -> preferences::HourCycle {
+    let payload: Option<DataPayload<TimeLengthsV1Marker>> = Baked
+        .load(DataRequest {
+            locale: &locale.into(),
+            metadata: Default::default(),
+        })
+        .and_then(|response| response.take_payload())
+        .ok();
+
+    match payload.map(|payload| payload.get().preferred_hour_cycle) {
+        Some(CoarseHourCycle::H11H12) => preferences::HourCycle::H12,
+        _ => preferences::HourCycle::H23,
     }
 }
 
+fn datetime_error<E: std::fmt::Display>(error: E) -> FluentError {
+    FluentError::DateTimeFormat(error.to_string())
+}
+
+type FormatterKey = (
+    FluentDateStyle,
+    FluentTimeStyle,
+    FluentTimezoneStyle,
+    Option<FluentHourCycle>,
+    Option<String>,
+    FluentEraStyle,
+    Option<u8>,
+    FluentWeekdayStyle,
+);
+
 thread_local! {
-    static FORMATTERS: RefCell<HashMap<Locale, HashMap<(FluentDateStyle, FluentTimeStyle, FluentTimezoneStyle), Formatter>>> = RefCell::new(HashMap::new());
+    static FORMATTERS: RefCell<FormatterCache<FormatterKey, Formatter>> = RefCell::new(HashMap::new());
 }
 
 impl FluentDateTime {
-    pub fn as_string(&self, locale: &Locale) -> Cow<'static, str> {
-        let typed_date = icu::calendar::DateTime::try_new_gregorian_datetime(
+    /// Returns the datetime with its options replaced by `options`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use fluent_bundle::types::{FluentDateStyle, FluentDateTime, FluentDateTimeOptions};
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    /// let date = FluentDateTime::from(date).with_options(FluentDateTimeOptions {
+    ///     date_style: FluentDateStyle::Long,
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(date.options.date_style, FluentDateStyle::Long);
+    /// ```
+    pub fn with_options(mut self, options: FluentDateTimeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the datetime with its options transformed by `f`, e.g. to change a
+    /// single option in a custom function.
+    pub fn map_options<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(FluentDateTimeOptions) -> FluentDateTimeOptions,
+    {
+        self.options = f(self.options);
+        self
+    }
+
+    /// Returns the datetime with its value replaced by `value`, keeping the options.
+    ///
+    /// The new value has a timezone, so the datetime is no longer naive.
+    pub fn with_value(mut self, value: DateTime<FixedOffset>) -> Self {
+        self.value = value;
+        self.naive = false;
+        self
+    }
+
+    /// Checks that the options can be applied to the value.
+    ///
+    /// A timezone style can not be used with a datetime without a timezone.
+    pub fn validate(&self) -> Result<(), FluentError> {
+        if self.naive && self.options.timezone_style != FluentTimezoneStyle::Hidden {
+            return Err(FluentError::NaiveTimezone);
+        }
+
+        Ok(())
+    }
+
+    /// Formats the datetime like [`as_string`](FluentDateTime::as_string), split into
+    /// numeric fields, textual fields and the literal text between them.
+    pub fn to_parts(&self, locale: &Locale) -> Result<Vec<FluentPart<'static>>, FluentError> {
+        let formatted = self.as_string(locale)?;
+
+        let runs = split_runs(&formatted, |rest| {
+            let c = rest.chars().next().unwrap_or_default();
+            let part_type = if c.is_numeric() {
+                DateTimePartType::Numeric
+            } else if c.is_alphabetic() {
+                DateTimePartType::Text
+            } else {
+                DateTimePartType::Literal
+            };
+            (part_type, c.len_utf8())
+        });
+
+        Ok(runs
+            .into_iter()
+            .map(|(part_type, value)| FluentPart::DateTime {
+                value: value.to_string().into(),
+                part_type,
+            })
+            .collect())
+    }
+
+    /// Formats the datetime for the given locale.
+    ///
+    /// Falls back to the formatting data of the root locale if data for the locale is missing,
+    /// and returns an error if the ICU formatter can not be created at all, e.g. because data
+    /// for the calendar is missing.
+    pub fn as_string(&self, locale: &Locale) -> Result<Cow<'static, str>, FluentError> {
+        let mut typed_date = icu::calendar::DateTime::try_new_gregorian_datetime(
             self.value.year(),
             self.value.month() as u8,
             self.value.day() as u8,
             self.value.hour() as u8,
             self.value.minute() as u8,
-            self.value.second() as u8
-        ).unwrap();
-
+            self.value.second() as u8,
+        )
+        .map_err(datetime_error)?;
+
+        // ICU displays every non-zero digit, so the nanoseconds are truncated to the requested digits.
+        // The public field may be set outside of the 1 to 3 digits accepted by `merge`.
+        if let Some(digits) = self
+            .options
+            .fractional_second_digits
+            .map(|digits| digits.clamp(1, 3))
+        {
+            let milliseconds = self.value.nanosecond() % 1_000_000_000 / 1_000_000;
+            let precision = 10u32.pow(9 - digits as u32);
+            typed_date.time.nanosecond =
+                NanoSecond::try_from(milliseconds * 1_000_000 / precision * precision)
+                    .map_err(datetime_error)?;
+        }
 
         let date = typed_date.to_iso().to_any();
-        let time_zone = CustomTimeZone::from_str(&self.value.timezone().to_string()).unwrap();
+        let time_zone =
+            CustomTimeZone::from_str(&self.value.timezone().to_string()).map_err(datetime_error)?;
 
         let date_style = match self.options.date_style {
             FluentDateStyle::Full => Some(length::Date::Full),
@@ -140,7 +470,24 @@ impl FluentDateTime {
             FluentTimeStyle::Hidden => None,
         };
 
-        let timezone_style = match self.options.timezone_style {
+        // A naive datetime has no timezone to display.
+        let timezone = if self.naive {
+            FluentTimezoneStyle::Hidden
+        } else {
+            self.options.timezone_style
+        };
+        let key = (
+            self.options.date_style,
+            self.options.time_style,
+            timezone,
+            self.options.hour_cycle,
+            self.options.calendar.clone(),
+            self.options.era,
+            self.options.fractional_second_digits,
+            self.options.weekday,
+        );
+
+        let timezone_style = match timezone {
             FluentTimezoneStyle::Hidden => None,
             FluentTimezoneStyle::LocalizedGmt => Some(FallbackFormat::LocalizedGmt),
             FluentTimezoneStyle::Iso8601(a, b, c) => {
@@ -166,38 +513,51 @@ impl FluentDateTime {
         };
 
         FORMATTERS.with(|cell| {
-            if let Some(formatter_map) = cell.borrow_mut().get_mut(locale) {
-                if let Some(formatter) = formatter_map.get_mut(&(self.options.date_style, self.options.time_style, self.options.timezone_style)) {
-                    return formatter.format_string(&date, &time_zone).into();
-                }
-
-                let new_formatter = Formatter::new(locale, date_style, time_style, timezone_style);
+            let mut cell = cell.borrow_mut();
+            let formatters = locale_cache(&mut cell, locale);
 
-                if let Some(new_formatter) = new_formatter {
-                    let res = new_formatter.format_string(&date, &time_zone).into();
-
-                    formatter_map.insert((self.options.date_style, self.options.time_style, self.options.timezone_style), new_formatter);
-
-                    return res;
-                } else {
-                    return Cow::Owned(format!("No formatter available for the style configuration"));
-                }
+            if let Some(formatter) = formatters.get(&key) {
+                return formatter.format_string(&date, &time_zone).map(Into::into);
             }
 
-            let mut map = HashMap::new();
+            // Formatters that fail to be created are not cached, so the error is reported every time.
+            let new_formatter = Formatter::new(
+                locale,
+                date_style,
+                time_style,
+                timezone_style,
+                &self.options,
+            )
+            .or_else(|error| {
+                if locale.id == LanguageIdentifier::UND {
+                    return Err(error);
+                }
 
-            let new_formatter = Formatter::new(locale, date_style, time_style, timezone_style);
+                // Fall back to the data of the root locale, keeping the unicode extensions.
+                let mut fallback = locale.clone();
+                fallback.id = LanguageIdentifier::UND;
+                Formatter::new(
+                    &fallback,
+                    date_style,
+                    time_style,
+                    timezone_style,
+                    &self.options,
+                )
+                .map_err(|_| error)
+            })?;
 
             if let Some(new_formatter) = new_formatter {
-                let res = new_formatter.format_string(&date, &time_zone).into();
-
-                map.insert((self.options.date_style, self.options.time_style, self.options.timezone_style), new_formatter);
+                let res = new_formatter
+                    .format_string(&date, &time_zone)
+                    .map(Into::into);
 
-                cell.borrow_mut().insert(locale.clone(), map);
+                formatters.put(key, new_formatter);
 
                 res
             } else {
-                Cow::Owned(format!("No formatter available for the style configuration"))
+                Err(FluentError::DateTimeFormat(
+                    "No formatter available for the style configuration".to_string(),
+                ))
             }
         })
     }
@@ -214,17 +574,169 @@ impl From<DateTime<FixedOffset>> for FluentDateTime {
         FluentDateTime {
             value,
             options: Default::default(),
+            naive: false,
         }
     }
 }
 
+impl From<NaiveDateTime> for FluentDateTime {
+    fn from(value: NaiveDateTime) -> Self {
+        let utc = FixedOffset::east_opt(0).expect("A zero offset is always in range");
+
+        FluentDateTime {
+            value: utc.from_utc_datetime(&value),
+            options: Default::default(),
+            naive: true,
+        }
+    }
+}
+
+impl<'l> From<NaiveDateTime> for FluentValue<'l> {
+    fn from(input: NaiveDateTime) -> Self {
+        FluentValue::DateTime(input.into())
+    }
+}
+
+/// Creates a datetime from a Unix timestamp, in seconds since 1970-01-01 00:00:00 UTC.
+///
+/// The timestamp has no sub-second precision, and is clamped to the range chrono supports.
+/// The timezone is hidden unless a timezone style is set.
+impl From<i64> for FluentDateTime {
+    fn from(timestamp: i64) -> Self {
+        let utc = FixedOffset::east_opt(0).expect("A zero offset is always in range");
+        let value = DateTime::from_timestamp(timestamp, 0).unwrap_or(if timestamp < 0 {
+            DateTime::<chrono::Utc>::MIN_UTC
+        } else {
+            DateTime::<chrono::Utc>::MAX_UTC
+        });
+
+        value.with_timezone(&utc).into()
+    }
+}
+
+/// Creates a datetime from a Unix timestamp in seconds and an offset from UTC in seconds,
+/// like the conversion from `i64`. An offset that is out of range
+/// is ignored.
+impl From<(i64, i32)> for FluentDateTime {
+    fn from((timestamp, utc_offset_seconds): (i64, i32)) -> Self {
+        let dt = FluentDateTime::from(timestamp);
+
+        match FixedOffset::east_opt(utc_offset_seconds) {
+            Some(offset) => dt.value.with_timezone(&offset).into(),
+            None => dt,
+        }
+    }
+}
+
+#[cfg(feature = "time-crate")]
+impl FluentDateTime {
+    /// Creates a [`FluentDateTime`] from a [`time::PrimitiveDateTime`], which carries
+    /// no timezone, by interpreting it as UTC.
+    pub fn from_primitive_utc(dt: time::PrimitiveDateTime) -> FluentDateTime {
+        let offset = FixedOffset::east_opt(0).expect("A zero offset is always in range");
+
+        offset.from_utc_datetime(&naive_from_primitive(dt)).into()
+    }
+
+    /// Creates a [`FluentDateTime`] from a [`time::PrimitiveDateTime`] that is local time
+    /// at the given offset from UTC.
+    ///
+    /// Returns `None` if the offset is out of range.
+    pub fn from_primitive_local(
+        dt: time::PrimitiveDateTime,
+        utc_offset_seconds: i32,
+    ) -> Option<FluentDateTime> {
+        let offset = FixedOffset::east_opt(utc_offset_seconds)?;
+
+        offset
+            .from_local_datetime(&naive_from_primitive(dt))
+            .single()
+            .map(Into::into)
+    }
+}
+
+#[cfg(feature = "time-crate")]
+impl From<time::OffsetDateTime> for FluentDateTime {
+    /// Keeps the offset of the datetime, or converts it to UTC if the offset is
+    /// larger than chrono supports.
+    fn from(dt: time::OffsetDateTime) -> Self {
+        let utc = dt.to_offset(time::UtcOffset::UTC);
+        let utc = time::PrimitiveDateTime::new(utc.date(), utc.time());
+
+        match FixedOffset::east_opt(dt.offset().whole_seconds()) {
+            Some(offset) => offset.from_utc_datetime(&naive_from_primitive(utc)).into(),
+            None => Self::from_primitive_utc(utc),
+        }
+    }
+}
+
+#[cfg(feature = "time-crate")]
+impl From<time::Date> for FluentDateTime {
+    /// Creates a naive datetime at midnight of the date.
+    fn from(date: time::Date) -> Self {
+        naive_from_primitive(date.midnight()).into()
+    }
+}
+
+#[cfg(feature = "time-crate")]
+impl From<time::Time> for FluentDateTime {
+    /// Creates a naive datetime at the time on 1970-01-01.
+    fn from(time: time::Time) -> Self {
+        naive_from_primitive(time::PrimitiveDateTime::new(
+            time::OffsetDateTime::UNIX_EPOCH.date(),
+            time,
+        ))
+        .into()
+    }
+}
+
+#[cfg(feature = "time-crate")]
+impl<'l> From<time::OffsetDateTime> for FluentValue<'l> {
+    fn from(input: time::OffsetDateTime) -> Self {
+        FluentValue::DateTime(input.into())
+    }
+}
+
+#[cfg(feature = "time-crate")]
+fn naive_from_primitive(dt: time::PrimitiveDateTime) -> chrono::NaiveDateTime {
+    let date =
+        chrono::NaiveDate::from_ymd_opt(dt.year(), u8::from(dt.month()) as u32, dt.day() as u32)
+            .expect("A date from the time crate is representable in chrono");
+    let time = chrono::NaiveTime::from_hms_nano_opt(
+        dt.hour() as u32,
+        dt.minute() as u32,
+        dt.second() as u32,
+        dt.nanosecond(),
+    )
+    .expect("A time from the time crate is representable in chrono");
+
+    date.and_time(time)
+}
 
 /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat#locale_options
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct FluentDateTimeOptions {
     pub date_style: FluentDateStyle,
     pub time_style: FluentTimeStyle,
     pub timezone_style: FluentTimezoneStyle,
+
+    /// Overrides the hour cycle of the locale, e.g. to force a 24-hour clock.
+    pub hour_cycle: Option<FluentHourCycle>,
+
+    /// The calendar to display the date in, as a unicode calendar identifier, e.g. `islamic` or `japanese`.
+    pub calendar: Option<String>,
+
+    /// How to display the era of the date, e.g. "AD" or the Japanese imperial era.
+    pub era: FluentEraStyle,
+
+    /// The number of fractional second digits to display, between 1 and 3.
+    pub fractional_second_digits: Option<u8>,
+
+    /// How to display the weekday, e.g. "Friday". It is added to the date and the time,
+    /// and can be used with both hidden to display only the weekday.
+    pub weekday: FluentWeekdayStyle,
 }
 
 impl FluentDateTimeOptions {
@@ -240,6 +752,22 @@ impl FluentDateTimeOptions {
                 ("timezoneStyle", FluentValue::String(n)) => {
                     self.timezone_style = n.as_ref().into();
                 }
+                ("hourCycle", FluentValue::String(n)) => {
+                    self.hour_cycle = FluentHourCycle::from_str(n.as_ref()).ok();
+                }
+                ("calendar", FluentValue::String(n)) => {
+                    self.calendar = Some(n.to_string());
+                }
+                ("era", FluentValue::String(n)) => {
+                    self.era = n.as_ref().into();
+                }
+                ("weekday", FluentValue::String(n)) => {
+                    self.weekday = n.as_ref().into();
+                }
+                ("fractionalSecondDigits", FluentValue::Number(n)) => {
+                    self.fractional_second_digits =
+                        Some(n.value as u8).filter(|digits| (1..=3).contains(digits));
+                }
                 _ => {}
             }
         }
@@ -248,6 +776,8 @@ impl FluentDateTimeOptions {
 
 // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat#datestyle
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum FluentDateStyle {
     Full,
     Long,
@@ -277,9 +807,10 @@ impl From<&str> for FluentDateStyle {
     }
 }
 
-
 // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat#timestyle
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum FluentTimeStyle {
     Full,
     Long,
@@ -309,9 +840,97 @@ impl From<&str> for FluentTimeStyle {
     }
 }
 
+// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat#hourcycle
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FluentHourCycle {
+    H11,
+    H12,
+    H23,
+    H24,
+}
+
+impl FromStr for FluentHourCycle {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "h11" => Ok(Self::H11),
+            "h12" => Ok(Self::H12),
+            "h23" => Ok(Self::H23),
+            "h24" => Ok(Self::H24),
+            _ => Err(()),
+        }
+    }
+}
+
+// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat#era
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FluentEraStyle {
+    Long,
+    Short,
+    Narrow,
+
+    /// Hides the era
+    Hidden,
+}
+
+impl std::default::Default for FluentEraStyle {
+    fn default() -> Self {
+        Self::Hidden
+    }
+}
+
+impl From<&str> for FluentEraStyle {
+    fn from(input: &str) -> Self {
+        match input {
+            "long" => Self::Long,
+            "short" => Self::Short,
+            "narrow" => Self::Narrow,
+            "hidden" => Self::Hidden,
+            _ => Self::default(),
+        }
+    }
+}
+
+// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat#weekday
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FluentWeekdayStyle {
+    Long,
+    Short,
+    Narrow,
+
+    /// Hides the weekday, unless the date style includes it
+    Hidden,
+}
+
+impl std::default::Default for FluentWeekdayStyle {
+    fn default() -> Self {
+        Self::Hidden
+    }
+}
+
+impl From<&str> for FluentWeekdayStyle {
+    fn from(input: &str) -> Self {
+        match input {
+            "long" => Self::Long,
+            "short" => Self::Short,
+            "narrow" => Self::Narrow,
+            "hidden" => Self::Hidden,
+            _ => Self::default(),
+        }
+    }
+}
 
 // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat#timestyle
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum FluentTimezoneStyle {
     LocalizedGmt,
     Iso8601(IsoFormat, IsoMinutes, IsoSeconds),
@@ -338,6 +957,8 @@ impl From<&str> for FluentTimezoneStyle {
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum IsoFormat {
     Basic,
     Extended,
@@ -346,13 +967,17 @@ pub enum IsoFormat {
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum IsoMinutes {
     Required,
     Optional,
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum IsoSeconds {
     Optional,
     Never,
-}
\ No newline at end of file
+}
@@ -0,0 +1,290 @@
+use crate::args::FluentArgs;
+use crate::errors::FluentError;
+use crate::types::formatter_cache::try_new_with_fallback;
+use crate::types::{FluentNumber, FluentNumberGrouping, FluentNumberOptions, FluentValue};
+use icu::list::{ListFormatter, ListLength};
+use icu::locid::Locale;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/DurationFormat#style
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FluentDurationStyle {
+    Long,
+    Short,
+    Narrow,
+    Digital,
+}
+
+impl std::default::Default for FluentDurationStyle {
+    fn default() -> Self {
+        Self::Short
+    }
+}
+
+impl From<&str> for FluentDurationStyle {
+    fn from(input: &str) -> Self {
+        match input {
+            "long" => Self::Long,
+            "short" => Self::Short,
+            "narrow" => Self::Narrow,
+            "digital" => Self::Digital,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/DurationFormat#hoursdisplay
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FluentDurationDisplay {
+    /// Only display the unit if it is not zero.
+    Auto,
+    Always,
+    /// Never display the unit, its value is carried over to the next smaller unit.
+    Hidden,
+}
+
+impl std::default::Default for FluentDurationDisplay {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl From<&str> for FluentDurationDisplay {
+    fn from(input: &str) -> Self {
+        match input {
+            "auto" => Self::Auto,
+            "always" => Self::Always,
+            "hidden" => Self::Hidden,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct FluentDurationOptions {
+    pub style: FluentDurationStyle,
+    pub hours_display: FluentDurationDisplay,
+    pub minutes_display: FluentDurationDisplay,
+    pub seconds_display: FluentDurationDisplay,
+}
+
+impl FluentDurationOptions {
+    pub fn merge(&mut self, opts: &FluentArgs) {
+        for (key, value) in opts.iter() {
+            match (key, value) {
+                ("style", FluentValue::String(n)) => {
+                    self.style = n.as_ref().into();
+                }
+                ("hoursDisplay", FluentValue::String(n)) => {
+                    self.hours_display = n.as_ref().into();
+                }
+                ("minutesDisplay", FluentValue::String(n)) => {
+                    self.minutes_display = n.as_ref().into();
+                }
+                ("secondsDisplay", FluentValue::String(n)) => {
+                    self.seconds_display = n.as_ref().into();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// A span of time, such as "1 hour, 30 minutes, 45 seconds".
+///
+/// The duration is displayed in hours, minutes and whole seconds. The numbers and the way
+/// the units are joined follow the locale, but ICU4X has no localized unit names yet,
+/// so the unit names are always English. The `digital` style, e.g. `1:30:45`, has no unit names.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FluentDuration {
+    pub value: Duration,
+    pub options: FluentDurationOptions,
+}
+
+thread_local! {
+    static FORMATTERS: RefCell<HashMap<Locale, HashMap<FluentDurationStyle, ListFormatter>>> = RefCell::new(HashMap::new());
+}
+
+impl FluentDuration {
+    pub const fn new(value: Duration, options: FluentDurationOptions) -> Self {
+        Self { value, options }
+    }
+
+    pub fn as_string(&self, locale: &Locale) -> Result<Cow<'static, str>, FluentError> {
+        let units = self.units();
+
+        if self.options.style == FluentDurationStyle::Digital {
+            return self.as_string_digital(locale, &units);
+        }
+
+        let mut parts = units
+            .iter()
+            .filter(|(_, value, display)| match display {
+                FluentDurationDisplay::Auto => *value != 0,
+                FluentDurationDisplay::Always => true,
+                FluentDurationDisplay::Hidden => false,
+            })
+            .map(|(unit, value, _)| self.format_unit(locale, *unit, *value))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // A zero duration is displayed in the smallest visible unit, e.g. "0 sec".
+        if parts.is_empty() {
+            if let Some((unit, value, _)) = units
+                .iter()
+                .rev()
+                .find(|(_, _, display)| *display != FluentDurationDisplay::Hidden)
+            {
+                parts.push(self.format_unit(locale, *unit, *value)?);
+            }
+        }
+
+        self.with_formatter(locale, |formatter| {
+            formatter.format_to_string(parts.iter()).into()
+        })
+    }
+
+    /// Splits the duration into the displayed units, carrying hidden units over to the next smaller one.
+    fn units(&self) -> [(Unit, u64, FluentDurationDisplay); 3] {
+        let mut seconds = self.value.as_secs();
+
+        let hours = if self.options.hours_display == FluentDurationDisplay::Hidden {
+            0
+        } else {
+            let hours = seconds / 3600;
+            seconds -= hours * 3600;
+            hours
+        };
+
+        let minutes = if self.options.minutes_display == FluentDurationDisplay::Hidden {
+            0
+        } else {
+            let minutes = seconds / 60;
+            seconds -= minutes * 60;
+            minutes
+        };
+
+        [
+            (Unit::Hour, hours, self.options.hours_display),
+            (Unit::Minute, minutes, self.options.minutes_display),
+            (Unit::Second, seconds, self.options.seconds_display),
+        ]
+    }
+
+    fn format_unit(&self, locale: &Locale, unit: Unit, value: u64) -> Result<String, FluentError> {
+        let number = FluentNumber::from(value).as_string(locale)?;
+
+        let (singular, plural, separator) = match (self.options.style, unit) {
+            (FluentDurationStyle::Long, Unit::Hour) => ("hour", "hours", " "),
+            (FluentDurationStyle::Long, Unit::Minute) => ("minute", "minutes", " "),
+            (FluentDurationStyle::Long, Unit::Second) => ("second", "seconds", " "),
+            (FluentDurationStyle::Narrow, Unit::Hour) => ("h", "h", ""),
+            (FluentDurationStyle::Narrow, Unit::Minute) => ("m", "m", ""),
+            (FluentDurationStyle::Narrow, Unit::Second) => ("s", "s", ""),
+            (_, Unit::Hour) => ("hr", "hr", " "),
+            (_, Unit::Minute) => ("min", "min", " "),
+            (_, Unit::Second) => ("sec", "sec", " "),
+        };
+
+        Ok(format!(
+            "{}{}{}",
+            number,
+            separator,
+            if value == 1 { singular } else { plural }
+        ))
+    }
+
+    fn as_string_digital(
+        &self,
+        locale: &Locale,
+        units: &[(Unit, u64, FluentDurationDisplay); 3],
+    ) -> Result<Cow<'static, str>, FluentError> {
+        let mut string = String::new();
+
+        for (_, value, display) in units {
+            if *display == FluentDurationDisplay::Hidden {
+                continue;
+            }
+
+            // Every unit but the first is padded to two digits, e.g. `1:05:09`.
+            let minimum_integer_digits = if string.is_empty() {
+                1
+            } else {
+                string.push(':');
+                2
+            };
+
+            let number = FluentNumber::new(
+                *value as f64,
+                FluentNumberOptions {
+                    minimum_integer_digits: Some(minimum_integer_digits),
+                    use_grouping: FluentNumberGrouping::Never,
+                    ..Default::default()
+                },
+            );
+
+            string.push_str(&number.as_string(locale)?);
+        }
+
+        Ok(string.into())
+    }
+
+    fn with_formatter<R, F: Fn(&ListFormatter) -> R>(
+        &self,
+        locale: &Locale,
+        f: F,
+    ) -> Result<R, FluentError> {
+        FORMATTERS.with(|cell| {
+            let mut cell = cell.borrow_mut();
+            let formatters = cell.entry(locale.clone()).or_default();
+
+            if let Some(formatter) = formatters.get(&self.options.style) {
+                return Ok(f(formatter));
+            }
+
+            let length = match self.options.style {
+                FluentDurationStyle::Long => ListLength::Wide,
+                FluentDurationStyle::Short | FluentDurationStyle::Digital => ListLength::Short,
+                FluentDurationStyle::Narrow => ListLength::Narrow,
+            };
+
+            let new_formatter =
+                try_new_with_fallback(locale, FluentError::DurationFormat, |locale| {
+                    ListFormatter::try_new_unit_with_length(locale, length)
+                })?;
+
+            let res = f(&new_formatter);
+
+            formatters.insert(self.options.style, new_formatter);
+
+            Ok(res)
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Unit {
+    Hour,
+    Minute,
+    Second,
+}
+
+impl From<Duration> for FluentDuration {
+    fn from(value: Duration) -> Self {
+        Self::new(value, Default::default())
+    }
+}
+
+impl<'l> From<FluentDuration> for FluentValue<'l> {
+    fn from(input: FluentDuration) -> Self {
+        FluentValue::Duration(input)
+    }
+}
+
+impl<'l> From<Duration> for FluentValue<'l> {
+    fn from(input: Duration) -> Self {
+        FluentValue::Duration(input.into())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::errors::FluentError;
+use icu::locid::{LanguageIdentifier, Locale};
+use icu_provider::DataLocale;
+use lru::LruCache;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+
+/// The number of formatters cached per locale by default, see [`set_formatter_cache_capacity`].
+pub const DEFAULT_FORMATTER_CACHE_CAPACITY: usize = 64;
+
+thread_local! {
+    static CAPACITY: Cell<NonZeroUsize> = const { Cell::new(match NonZeroUsize::new(DEFAULT_FORMATTER_CACHE_CAPACITY) {
+        Some(capacity) => capacity,
+        None => NonZeroUsize::MIN,
+    }) };
+}
+
+/// Sets the number of formatters cached per locale and per kind of formatter on the
+/// current thread. When the cache is full, the least recently used formatter is dropped.
+///
+/// The number and datetime formatters are cached for every combination of options,
+/// so a long-running process serving many locales and options can lower the capacity
+/// to bound its memory use. A capacity of `0` is treated as `1`.
+pub fn set_formatter_cache_capacity(capacity: usize) {
+    CAPACITY.with(|cell| cell.set(NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN)));
+}
+
+/// Returns the number of formatters cached per locale on the current thread.
+pub fn formatter_cache_capacity() -> usize {
+    CAPACITY.with(|cell| cell.get().get())
+}
+
+pub(crate) type FormatterCache<K, V> = HashMap<Locale, LruCache<K, V>>;
+
+/// Returns the cache of the locale, resized to the current capacity.
+pub(crate) fn locale_cache<'a, K: Hash + Eq, V>(
+    caches: &'a mut FormatterCache<K, V>,
+    locale: &Locale,
+) -> &'a mut LruCache<K, V> {
+    let capacity = CAPACITY.with(|cell| cell.get());
+    let cache = caches
+        .entry(locale.clone())
+        .or_insert_with(|| LruCache::new(capacity));
+    if cache.cap() != capacity {
+        cache.resize(capacity);
+    }
+    cache
+}
+
+/// Creates an ICU formatter for the locale, or for the root locale if the data of the locale is missing.
+///
+/// The error of the locale is converted with `error`, e.g. [`FluentError::NumberFormat`].
+pub(crate) fn try_new_with_fallback<T, E: std::fmt::Display>(
+    locale: &Locale,
+    error: fn(String) -> FluentError,
+    new: impl Fn(&DataLocale) -> Result<T, E>,
+) -> Result<T, FluentError> {
+    new(&locale.into())
+        .or_else(|err| {
+            if locale.id == LanguageIdentifier::UND {
+                Err(err)
+            } else {
+                new(&DataLocale::default()).map_err(|_| err)
+            }
+        })
+        .map_err(|err| error(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu::locid::locale;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        set_formatter_cache_capacity(2);
+        let mut caches: FormatterCache<u8, u8> = HashMap::new();
+        let en = locale!("en-US");
+
+        locale_cache(&mut caches, &en).put(1, 1);
+        locale_cache(&mut caches, &en).put(2, 2);
+        assert_eq!(locale_cache(&mut caches, &en).get(&1), Some(&1));
+        locale_cache(&mut caches, &en).put(3, 3);
+
+        let cache = locale_cache(&mut caches, &en);
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&1));
+        assert!(!cache.contains(&2));
+
+        // Every locale has its own cache.
+        locale_cache(&mut caches, &locale!("pl")).put(4, 4);
+        assert_eq!(locale_cache(&mut caches, &en).len(), 2);
+
+        set_formatter_cache_capacity(0);
+        assert_eq!(formatter_cache_capacity(), 1);
+        assert_eq!(locale_cache(&mut caches, &en).len(), 1);
+
+        set_formatter_cache_capacity(DEFAULT_FORMATTER_CACHE_CAPACITY);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::args::FluentArgs;
+use crate::errors::FluentError;
+use crate::types::formatter_cache::try_new_with_fallback;
+use crate::types::FluentValue;
+use icu::list::{ListFormatter, ListLength};
+use icu::locid::Locale;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/ListFormat/ListFormat#type
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FluentListType {
+    /// "a, b, and c"
+    Conjunction,
+    /// "a, b, or c"
+    Disjunction,
+    /// "a, b, c"
+    Unit,
+}
+
+impl std::default::Default for FluentListType {
+    fn default() -> Self {
+        Self::Conjunction
+    }
+}
+
+impl From<&str> for FluentListType {
+    fn from(input: &str) -> Self {
+        match input {
+            "conjunction" => Self::Conjunction,
+            "disjunction" => Self::Disjunction,
+            "unit" => Self::Unit,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/ListFormat/ListFormat#style
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FluentListStyle {
+    Long,
+    Short,
+    Narrow,
+}
+
+impl std::default::Default for FluentListStyle {
+    fn default() -> Self {
+        Self::Long
+    }
+}
+
+impl From<&str> for FluentListStyle {
+    fn from(input: &str) -> Self {
+        match input {
+            "long" => Self::Long,
+            "short" => Self::Short,
+            "narrow" => Self::Narrow,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct FluentListOptions {
+    pub list_type: FluentListType,
+    pub style: FluentListStyle,
+}
+
+impl FluentListOptions {
+    pub fn merge(&mut self, opts: &FluentArgs) {
+        for (key, value) in opts.iter() {
+            match (key, value) {
+                ("type", FluentValue::String(n)) => {
+                    self.list_type = n.as_ref().into();
+                }
+                ("style", FluentValue::String(n)) => {
+                    self.style = n.as_ref().into();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Joins the already formatted items, e.g. "apples, bananas, and oranges".
+    pub fn format<I: AsRef<str>>(
+        &self,
+        locale: &Locale,
+        items: &[I],
+    ) -> Result<String, FluentError> {
+        self.with_formatter(locale, |formatter| {
+            formatter.format_to_string(items.iter().map(|item| item.as_ref()))
+        })
+    }
+
+    fn with_formatter<R, F: Fn(&ListFormatter) -> R>(
+        &self,
+        locale: &Locale,
+        f: F,
+    ) -> Result<R, FluentError> {
+        FORMATTERS.with(|cell| {
+            let mut cell = cell.borrow_mut();
+            let formatters = cell.entry(locale.clone()).or_default();
+
+            if let Some(formatter) = formatters.get(self) {
+                return Ok(f(formatter));
+            }
+
+            let length = match self.style {
+                FluentListStyle::Long => ListLength::Wide,
+                FluentListStyle::Short => ListLength::Short,
+                FluentListStyle::Narrow => ListLength::Narrow,
+            };
+
+            let new_formatter = try_new_with_fallback(locale, FluentError::ListFormat, |locale| {
+                match self.list_type {
+                    FluentListType::Conjunction => {
+                        ListFormatter::try_new_and_with_length(locale, length)
+                    }
+                    FluentListType::Disjunction => {
+                        ListFormatter::try_new_or_with_length(locale, length)
+                    }
+                    FluentListType::Unit => ListFormatter::try_new_unit_with_length(locale, length),
+                }
+            })?;
+
+            let res = f(&new_formatter);
+
+            formatters.insert(*self, new_formatter);
+
+            Ok(res)
+        })
+    }
+}
+
+thread_local! {
+    static FORMATTERS: RefCell<HashMap<Locale, HashMap<FluentListOptions, ListFormatter>>> = RefCell::new(HashMap::new());
+}
+
+impl<'source, T> From<Vec<T>> for FluentValue<'source>
+where
+    T: Into<FluentValue<'source>>,
+{
+    fn from(v: Vec<T>) -> Self {
+        FluentValue::List(
+            v.into_iter().map(Into::into).collect(),
+            FluentListOptions::default(),
+        )
+    }
+}
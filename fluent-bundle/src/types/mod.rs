@@ -11,21 +11,38 @@
 //! For now, [`FluentValue`] can be a string, a number, or a custom [`FluentType`]
 //! which allows users of the library to implement their own types of values,
 //! such as dates, or more complex structures needed for their bindings.
+mod date;
+mod datetime;
+mod duration;
+mod formatter_cache;
+mod list;
 mod number;
 mod plural;
-mod datetime;
+mod relative_time;
 
-pub use number::*;
+pub use date::*;
 pub use datetime::*;
+pub use duration::*;
+pub use formatter_cache::{
+    formatter_cache_capacity, set_formatter_cache_capacity, DEFAULT_FORMATTER_CACHE_CAPACITY,
+};
+pub use list::*;
+pub use number::*;
+pub use plural::{plural_category, plural_category_safe};
+pub use relative_time::*;
 
+use icu::locid::Locale;
+use icu::plurals::PluralRuleType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::any::Any;
 use std::borrow::{Borrow, Cow};
 use std::fmt;
 use std::fmt::Write;
+use std::num::ParseFloatError;
 use std::str::FromStr;
-use icu::plurals::PluralRuleType::Cardinal;
-use plural::plural_category;
 
+use crate::errors::FluentError;
 use crate::memoizer::MemoizerKind;
 use crate::resolver::Scope;
 use crate::resource::FluentResource;
@@ -82,6 +99,12 @@ pub enum FluentValue<'source> {
     String(Cow<'source, str>),
     Number(FluentNumber),
     DateTime(FluentDateTime),
+    Date(FluentDate),
+    Time(FluentTime),
+    RelativeTime(FluentRelativeTime),
+    Duration(FluentDuration),
+    /// A list of values that is joined according to the locale, e.g. "apples, bananas, and oranges".
+    List(Vec<FluentValue<'source>>, FluentListOptions),
     Custom(Box<dyn FluentType + Send>),
     None,
     Error,
@@ -93,6 +116,11 @@ impl<'s> PartialEq for FluentValue<'s> {
             (FluentValue::String(s), FluentValue::String(s2)) => s == s2,
             (FluentValue::Number(s), FluentValue::Number(s2)) => s == s2,
             (FluentValue::DateTime(s), FluentValue::DateTime(s2)) => s == s2,
+            (FluentValue::Date(s), FluentValue::Date(s2)) => s == s2,
+            (FluentValue::Time(s), FluentValue::Time(s2)) => s == s2,
+            (FluentValue::RelativeTime(s), FluentValue::RelativeTime(s2)) => s == s2,
+            (FluentValue::Duration(s), FluentValue::Duration(s2)) => s == s2,
+            (FluentValue::List(s, o), FluentValue::List(s2, o2)) => s == s2 && o == o2,
             (FluentValue::Custom(s), FluentValue::Custom(s2)) => s == s2,
             _ => false,
         }
@@ -111,14 +139,118 @@ impl<'s> Clone for FluentValue<'s> {
             FluentValue::Error => FluentValue::Error,
             FluentValue::None => FluentValue::None,
             FluentValue::DateTime(d) => FluentValue::DateTime(d.clone()),
+            FluentValue::Date(d) => FluentValue::Date(*d),
+            FluentValue::Time(t) => FluentValue::Time(*t),
+            FluentValue::RelativeTime(r) => FluentValue::RelativeTime(r.clone()),
+            FluentValue::Duration(d) => FluentValue::Duration(d.clone()),
+            FluentValue::List(l, o) => FluentValue::List(l.clone(), *o),
         }
     }
 }
 
+/// Formats the value with the root locale, for debugging and logging.
+///
+/// Use [`FluentBundle::format_pattern`](crate::bundle::FluentBundle::format_pattern) to display a
+/// value to users, which formats it with the locale of the bundle.
+impl<'s> fmt::Display for FluentValue<'s> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let locale = Locale::UND;
+
+        match self {
+            FluentValue::String(s) => f.write_str(s),
+            FluentValue::Number(n) => n.fmt(f),
+            FluentValue::DateTime(d) => match d.as_string(&locale) {
+                Ok(s) => f.write_str(&s),
+                Err(_) => f.write_str("<error>"),
+            },
+            FluentValue::Date(d) => match d.as_string(&locale) {
+                Ok(s) => f.write_str(&s),
+                Err(_) => f.write_str("<error>"),
+            },
+            FluentValue::Time(t) => match t.as_string(&locale) {
+                Ok(s) => f.write_str(&s),
+                Err(_) => f.write_str("<error>"),
+            },
+            FluentValue::RelativeTime(r) => match r.as_string(&locale) {
+                Ok(s) => f.write_str(&s),
+                Err(_) => f.write_str("<error>"),
+            },
+            FluentValue::Duration(d) => match d.as_string(&locale) {
+                Ok(s) => f.write_str(&s),
+                Err(_) => f.write_str("<error>"),
+            },
+            FluentValue::List(l, o) => {
+                let items = l.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+                match o.format(&locale, &items) {
+                    Ok(s) => f.write_str(&s),
+                    Err(_) => f.write_str("<error>"),
+                }
+            }
+            FluentValue::Custom(s) => {
+                f.write_str(&s.as_string(&intl_memoizer_for_carbide::IntlLangMemoizer::new(locale)))
+            }
+            FluentValue::None => Ok(()),
+            FluentValue::Error => f.write_str("<error>"),
+        }
+    }
+}
+
+/// Strings are serialized as plain strings, numbers and datetimes as objects tagged with their
+/// `type`, e.g. `{"type":"number","value":3.14,"options":{...}}`, and [`FluentValue::None`] as `null`.
+///
+/// The other values can not be serialized.
+#[cfg(feature = "serde")]
+impl<'s> Serialize for FluentValue<'s> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(tag = "type", rename_all = "lowercase")]
+        enum Tagged<'a> {
+            Number(&'a FluentNumber),
+            DateTime(&'a FluentDateTime),
+        }
+
+        match self {
+            FluentValue::String(s) => serializer.serialize_str(s),
+            FluentValue::Number(n) => Tagged::Number(n).serialize(serializer),
+            FluentValue::DateTime(d) => Tagged::DateTime(d).serialize(serializer),
+            FluentValue::None => serializer.serialize_none(),
+            _ => Err(serde::ser::Error::custom(
+                "The value can not be serialized.",
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 's> Deserialize<'de> for FluentValue<'s> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(tag = "type", rename_all = "lowercase")]
+        enum Tagged {
+            Number(FluentNumber),
+            DateTime(FluentDateTime),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Untagged {
+            String(String),
+            Tagged(Tagged),
+            None(()),
+        }
+
+        Ok(match Untagged::deserialize(deserializer)? {
+            Untagged::String(s) => FluentValue::String(s.into()),
+            Untagged::Tagged(Tagged::Number(n)) => FluentValue::Number(n),
+            Untagged::Tagged(Tagged::DateTime(d)) => FluentValue::DateTime(d),
+            Untagged::None(()) => FluentValue::None,
+        })
+    }
+}
+
 impl<'source> FluentValue<'source> {
-    /// Attempts to parse the string representation of a `value` that supports
-    /// [`ToString`] into a [`FluentValue::Number`]. If it fails, it will instead
-    /// convert it to a [`FluentValue::String`].
+    /// Attempts to parse the string representation of a number into a
+    /// [`FluentValue::Number`].
     ///
     /// ```
     /// use fluent_bundle::types::{FluentNumber, FluentNumberOptions, FluentValue};
@@ -126,33 +258,36 @@ impl<'source> FluentValue<'source> {
     /// // "2" parses into a `FluentNumber`
     /// assert_eq!(
     ///     FluentValue::try_number("2"),
-    ///     FluentValue::Number(FluentNumber::new(2.0, FluentNumberOptions::default()))
+    ///     Ok(FluentValue::Number(FluentNumber::new(2.0, FluentNumberOptions::default())))
     /// );
     ///
     /// // Floats can be parsed as well.
     /// assert_eq!(
     ///     FluentValue::try_number("3.141569"),
-    ///     FluentValue::Number(FluentNumber::new(
+    ///     Ok(FluentValue::Number(FluentNumber::new(
     ///         3.141569,
     ///         FluentNumberOptions {
     ///             minimum_fraction_digits: Some(6),
     ///             ..Default::default()
     ///         }
-    ///     ))
+    ///     )))
     /// );
     ///
-    /// // When a value is not a valid number, it falls back to a `FluentValue::String`
-    /// assert_eq!(
-    ///     FluentValue::try_number("A string"),
-    ///     FluentValue::String("A string".into())
-    /// );
+    /// // When a value is not a valid number, the parse error is returned.
+    /// assert!(FluentValue::try_number("A string").is_err());
     /// ```
-    pub fn try_number(value: &'source str) -> Self {
-        if let Ok(number) = FluentNumber::from_str(value) {
-            number.into()
-        } else {
-            value.into()
-        }
+    pub fn try_number(value: &str) -> Result<FluentValue<'static>, ParseFloatError> {
+        FluentNumber::from_str(value).map(Into::into)
+    }
+
+    /// Parses the string representation of a number into a [`FluentValue::Number`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not a valid number, use [`FluentValue::try_number()`]
+    /// for values that are not known to be valid.
+    pub fn number_unchecked(value: &str) -> FluentValue<'static> {
+        Self::try_number(value).expect("The value should be a valid number")
     }
 
     /// Checks to see if two [`FluentValues`](FluentValue) match each other by having the
@@ -169,17 +304,17 @@ impl<'source> FluentValue<'source> {
     /// let scope = Scope::new(&bundle, None, None);
     ///
     /// // Matching examples:
-    /// assert!(FluentValue::try_number("2").matches(&FluentValue::try_number("2"), &scope));
+    /// assert!(FluentValue::number_unchecked("2").matches(&FluentValue::number_unchecked("2"), &scope));
     /// assert!(FluentValue::from("fluent").matches(&FluentValue::from("fluent"), &scope));
     /// assert!(
-    ///     FluentValue::from("one").matches(&FluentValue::try_number("1"), &scope),
+    ///     FluentValue::from("one").matches(&FluentValue::number_unchecked("1"), &scope),
     ///     "Plural rules are matched."
     /// );
     ///
     /// // Non-matching examples:
-    /// assert!(!FluentValue::try_number("2").matches(&FluentValue::try_number("3"), &scope));
+    /// assert!(!FluentValue::number_unchecked("2").matches(&FluentValue::number_unchecked("3"), &scope));
     /// assert!(!FluentValue::from("fluent").matches(&FluentValue::from("not fluent"), &scope));
-    /// assert!(!FluentValue::from("two").matches(&FluentValue::try_number("100"), &scope),);
+    /// assert!(!FluentValue::from("two").matches(&FluentValue::number_unchecked("100"), &scope),);
     /// ```
     pub fn matches<R: Borrow<FluentResource>, M>(
         &self,
@@ -204,31 +339,33 @@ impl<'source> FluentValue<'source> {
                 };
                 // This string matches a plural rule keyword. Check if the number
                 // matches the plural rule category.
-                plural_category(scope.bundle.locales.first().unwrap(), Cardinal, b) == cat
+                let plural_rule_type = match b.options.plural_type {
+                    FluentNumberType::Cardinal => PluralRuleType::Cardinal,
+                    FluentNumberType::Ordinal => PluralRuleType::Ordinal,
+                };
+                plural_category(scope.bundle.locales.first().unwrap(), plural_rule_type, b) == cat
+            }
+            // Lists are selected on by their length.
+            (&FluentValue::String(_), &FluentValue::List(ref b, _))
+            | (&FluentValue::Number(_), &FluentValue::List(ref b, _)) => {
+                self.matches(&FluentValue::from(b.len()), scope)
             }
             _ => false,
         }
     }
 
     /// Write out a string version of the [`FluentValue`] to `W`.
-    pub fn write<W, R, M>(&self, w: &mut W, scope: &Scope<R, M>) -> fmt::Result
+    ///
+    /// Returns the error of a value that fails to format, without writing anything.
+    pub fn write<W, R, M>(&self, w: &mut W, scope: &Scope<R, M>) -> Result<fmt::Result, FluentError>
     where
         W: fmt::Write,
         R: Borrow<FluentResource>,
         M: MemoizerKind,
     {
-        if let Some(formatter) = &scope.bundle.formatter {
-            if let Some(val) = formatter(self, &scope.bundle.intls.language(), &scope.bundle.intls) {
-                return w.write_str(&val);
-            }
-        }
         match self {
-            FluentValue::String(s) => w.write_str(s),
-            FluentValue::Number(n) => w.write_str(&n.as_string(scope.bundle.locales.first().unwrap())),
-            FluentValue::DateTime(d) => w.write_str(&d.as_string(scope.bundle.locales.first().unwrap())),
-            FluentValue::Custom(s) => w.write_str(&scope.bundle.intls.stringify_value(&**s)),
-            FluentValue::Error => Ok(()),
-            FluentValue::None => Ok(()),
+            FluentValue::String(s) if scope.bundle.formatter.is_none() => Ok(w.write_str(s)),
+            value => Ok(w.write_str(&value.as_string(scope)?)),
         }
     }
 
@@ -236,45 +373,153 @@ impl<'source> FluentValue<'source> {
     ///
     /// Clones inner values when owned, borrowed data is not cloned.
     /// Prefer using [`FluentValue::into_string()`] when possible.
-    pub fn as_string<R: Borrow<FluentResource>, M>(&self, scope: &Scope<R, M>) -> Cow<'source, str>
+    pub fn as_string<R: Borrow<FluentResource>, M>(
+        &self,
+        scope: &Scope<R, M>,
+    ) -> Result<Cow<'source, str>, FluentError>
     where
         M: MemoizerKind,
     {
         if let Some(formatter) = &scope.bundle.formatter {
-            if let Some(val) = formatter(self, &scope.bundle.intls.language(), &scope.bundle.intls) {
-                return val.into();
+            if let Some(val) = formatter(self, &scope.bundle.intls.language(), &scope.bundle.intls)
+            {
+                return Ok(val.into());
             }
         }
-        match self {
+        let locale = scope.bundle.locales.first().unwrap();
+        Ok(match self {
             FluentValue::String(s) => s.clone(),
-            FluentValue::Number(n) => n.as_string(scope.bundle.locales.first().unwrap()),
-            FluentValue::DateTime(d) => d.as_string(scope.bundle.locales.first().unwrap()),
+            FluentValue::Number(n) => n.as_string(locale)?,
+            FluentValue::DateTime(d) => d.as_string(locale)?,
+            FluentValue::Date(d) => d.as_string(locale)?,
+            FluentValue::Time(t) => t.as_string(locale)?,
+            FluentValue::RelativeTime(r) => r.as_string(locale)?,
+            FluentValue::Duration(d) => d.as_string(locale)?,
+            FluentValue::List(l, o) => {
+                let items = l
+                    .iter()
+                    .map(|value| value.as_string(scope))
+                    .collect::<Result<Vec<_>, _>>()?;
+                o.format(locale, &items)?.into()
+            }
             FluentValue::Custom(s) => scope.bundle.intls.stringify_value(&**s),
             FluentValue::Error => "".into(),
             FluentValue::None => "".into(),
-        }
+        })
+    }
+
+    /// Converts the [`FluentValue`] to a string for the locale, without the custom
+    /// [`formatter`](crate::bundle::FluentBundle::set_formatter) of a bundle.
+    ///
+    /// Custom types are converted with a new [`IntlLangMemoizer`](intl_memoizer_for_carbide::IntlLangMemoizer)
+    /// for the locale.
+    pub fn as_string_for_locale(&self, locale: &Locale) -> Result<Cow<'source, str>, FluentError> {
+        Ok(match self {
+            FluentValue::String(s) => s.clone(),
+            FluentValue::Number(n) => n.as_string(locale)?,
+            FluentValue::DateTime(d) => d.as_string(locale)?,
+            FluentValue::Date(d) => d.as_string(locale)?,
+            FluentValue::Time(t) => t.as_string(locale)?,
+            FluentValue::RelativeTime(r) => r.as_string(locale)?,
+            FluentValue::Duration(d) => d.as_string(locale)?,
+            FluentValue::List(l, o) => {
+                let items = l
+                    .iter()
+                    .map(|value| value.as_string_for_locale(locale))
+                    .collect::<Result<Vec<_>, _>>()?;
+                o.format(locale, &items)?.into()
+            }
+            FluentValue::Custom(s) => s.as_string(
+                &intl_memoizer_for_carbide::IntlLangMemoizer::new(locale.clone()),
+            ),
+            FluentValue::Error => "".into(),
+            FluentValue::None => "".into(),
+        })
     }
 
     /// Converts the [`FluentValue`] to a string.
     ///
     /// Takes self by-value to be able to skip expensive clones.
     /// Prefer this method over [`FluentValue::as_string()`] when possible.
-    pub fn into_string<R: Borrow<FluentResource>, M>(self, scope: &Scope<R, M>) -> Cow<'source, str>
+    pub fn into_string<R: Borrow<FluentResource>, M>(
+        self,
+        scope: &Scope<R, M>,
+    ) -> Result<Cow<'source, str>, FluentError>
     where
         M: MemoizerKind,
     {
-        if let Some(formatter) = &scope.bundle.formatter {
-            if let Some(val) = formatter(&self, &scope.bundle.intls.language(), &scope.bundle.intls) {
-                return val.into();
-            }
+        match self {
+            FluentValue::String(s) if scope.bundle.formatter.is_none() => Ok(s),
+            value => value.as_string(scope),
         }
+    }
+
+    /// Returns the number if the value is a [`FluentValue::Number`].
+    pub fn as_number(&self) -> Option<&FluentNumber> {
         match self {
-            FluentValue::String(s) => s,
-            FluentValue::Number(n) => n.as_string(scope.bundle.locales.first().unwrap()),
-            FluentValue::DateTime(d) => d.as_string(scope.bundle.locales.first().unwrap()),
-            FluentValue::Custom(s) => scope.bundle.intls.stringify_value(s.as_ref()),
-            FluentValue::Error => "".into(),
-            FluentValue::None => "".into(),
+            FluentValue::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns the string if the value is a [`FluentValue::String`].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FluentValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the datetime if the value is a [`FluentValue::DateTime`].
+    pub fn as_datetime(&self) -> Option<&FluentDateTime> {
+        match self {
+            FluentValue::DateTime(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn as_number_mut(&mut self) -> Option<&mut FluentNumber> {
+        match self {
+            FluentValue::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    pub fn as_str_mut(&mut self) -> Option<&mut Cow<'source, str>> {
+        match self {
+            FluentValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_datetime_mut(&mut self) -> Option<&mut FluentDateTime> {
+        match self {
+            FluentValue::DateTime(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn into_number(self) -> Option<FluentNumber> {
+        match self {
+            FluentValue::Number(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns the string if the value is a [`FluentValue::String`].
+    ///
+    /// Unlike [`FluentValue::into_string()`], other values are not formatted.
+    pub fn into_str(self) -> Option<Cow<'source, str>> {
+        match self {
+            FluentValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn into_datetime(self) -> Option<FluentDateTime> {
+        match self {
+            FluentValue::DateTime(d) => Some(d),
+            _ => None,
         }
     }
 
@@ -285,7 +530,14 @@ impl<'source> FluentValue<'source> {
             FluentValue::Custom(s) => FluentValue::Custom(s.duplicate()),
             FluentValue::Error => FluentValue::Error,
             FluentValue::None => FluentValue::None,
-            FluentValue::DateTime(d) => FluentValue::DateTime(d.clone())
+            FluentValue::DateTime(d) => FluentValue::DateTime(d.clone()),
+            FluentValue::Date(d) => FluentValue::Date(*d),
+            FluentValue::Time(t) => FluentValue::Time(*t),
+            FluentValue::RelativeTime(r) => FluentValue::RelativeTime(r.clone()),
+            FluentValue::Duration(d) => FluentValue::Duration(d.clone()),
+            FluentValue::List(l, o) => {
+                FluentValue::List(l.iter().map(FluentValue::into_owned).collect(), *o)
+            }
         }
     }
 }
@@ -308,6 +560,19 @@ impl<'source> From<&'source str> for FluentValue<'source> {
     }
 }
 
+/// Booleans are converted to the strings `true` and `false`, so they can be used as selectors.
+impl From<bool> for FluentValue<'_> {
+    fn from(b: bool) -> Self {
+        FluentValue::String(Cow::Borrowed(if b { "true" } else { "false" }))
+    }
+}
+
+impl From<&bool> for FluentValue<'_> {
+    fn from(b: &bool) -> Self {
+        (*b).into()
+    }
+}
+
 impl<'source> From<Cow<'source, str>> for FluentValue<'source> {
     fn from(s: Cow<'source, str>) -> Self {
         FluentValue::String(s)
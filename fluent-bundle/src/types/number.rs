@@ -1,22 +1,41 @@
+use fixed_decimal::{CompactDecimal, FixedDecimal, RoundingIncrement, SignDisplay};
+use icu::decimal::options::{FixedDecimalFormatterOptions, GroupingStrategy};
+use icu::decimal::provider::{Baked as DecimalBaked, DecimalSymbolsV1Marker};
+use icu::decimal::FixedDecimalFormatter;
+use icu::locid::Locale;
+use icu_experimental::compactdecimal::CompactDecimalFormatter;
+use icu_experimental::dimension::currency::formatter::{CurrencyCode, CurrencyFormatter};
+use icu_experimental::dimension::currency::options::{CurrencyFormatterOptions, Width};
+use icu_experimental::dimension::provider::currency::CurrencyEssentialsV1Marker;
+use icu_experimental::dimension::provider::percent::PercentEssentialsV1Marker;
+use icu_experimental::provider::Baked;
+use icu_provider::{DataPayload, DataProvider, DataRequest};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::convert::TryInto;
-use std::default::{Default};
+use std::default::Default;
 use std::str::FromStr;
-use fixed_decimal::FixedDecimal;
-use icu::decimal::FixedDecimalFormatter;
-use icu::decimal::options::{FixedDecimalFormatterOptions, GroupingStrategy};
-use icu::locid::Locale;
+use tinystr::TinyAsciiStr;
+use writeable::Writeable;
 
 use crate::args::FluentArgs;
+use crate::errors::FluentError;
+use crate::parts::{split_runs, FluentPart, NumberPartType};
+use crate::types::formatter_cache::{locale_cache, try_new_with_fallback, FormatterCache};
 use crate::types::FluentValue;
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum FluentNumberStyle {
     Decimal,
     Currency,
     Percent,
+    Unit,
 }
 
 impl std::default::Default for FluentNumberStyle {
@@ -31,17 +50,20 @@ impl From<&str> for FluentNumberStyle {
             "decimal" => Self::Decimal,
             "currency" => Self::Currency,
             "percent" => Self::Percent,
+            "unit" => Self::Unit,
             _ => Self::default(),
         }
     }
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum FluentNumberNotation {
     Standard,
     Scientific,
     Engineering,
-    // Compact
+    Compact,
 }
 
 impl std::default::Default for FluentNumberNotation {
@@ -56,6 +78,32 @@ impl From<&str> for FluentNumberNotation {
             "standard" => Self::Standard,
             "scientific" => Self::Scientific,
             "engineering" => Self::Engineering,
+            "compact" => Self::Compact,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat#compactdisplay
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FluentNumberCompactDisplay {
+    Short,
+    Long,
+}
+
+impl std::default::Default for FluentNumberCompactDisplay {
+    fn default() -> Self {
+        Self::Short
+    }
+}
+
+impl From<&str> for FluentNumberCompactDisplay {
+    fn from(input: &str) -> Self {
+        match input {
+            "short" => Self::Short,
+            "long" => Self::Long,
             _ => Self::default(),
         }
     }
@@ -63,6 +111,8 @@ impl From<&str> for FluentNumberNotation {
 
 /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat#usegrouping
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum FluentNumberGrouping {
     Always,
     Auto,
@@ -90,6 +140,8 @@ impl From<&str> for FluentNumberGrouping {
 
 /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat#usegrouping
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum FluentNumberRoundingMode {
     Ceil,
     Floor,
@@ -125,9 +177,80 @@ impl From<&str> for FluentNumberRoundingMode {
     }
 }
 
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat#signdisplay
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FluentNumberSignDisplay {
+    Auto,
+    Never,
+    Always,
+    ExceptZero,
+    Negative,
+}
+
+impl std::default::Default for FluentNumberSignDisplay {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl From<&str> for FluentNumberSignDisplay {
+    fn from(input: &str) -> Self {
+        match input {
+            "auto" => Self::Auto,
+            "never" => Self::Never,
+            "always" => Self::Always,
+            "exceptZero" => Self::ExceptZero,
+            "negative" => Self::Negative,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl From<FluentNumberSignDisplay> for SignDisplay {
+    fn from(input: FluentNumberSignDisplay) -> Self {
+        match input {
+            FluentNumberSignDisplay::Auto => SignDisplay::Auto,
+            FluentNumberSignDisplay::Never => SignDisplay::Never,
+            FluentNumberSignDisplay::Always => SignDisplay::Always,
+            FluentNumberSignDisplay::ExceptZero => SignDisplay::ExceptZero,
+            FluentNumberSignDisplay::Negative => SignDisplay::Negative,
+        }
+    }
+}
+
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat#trailingzerodisplay
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FluentNumberTrailingZeroDisplay {
+    Auto,
+    StripIfInteger,
+}
+
+impl std::default::Default for FluentNumberTrailingZeroDisplay {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl From<&str> for FluentNumberTrailingZeroDisplay {
+    fn from(input: &str) -> Self {
+        match input {
+            "auto" => Self::Auto,
+            "stripIfInteger" => Self::StripIfInteger,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum FluentNumberCurrencyDisplayStyle {
     Symbol,
+    NarrowSymbol,
     Code,
     Name,
 }
@@ -142,6 +265,7 @@ impl From<&str> for FluentNumberCurrencyDisplayStyle {
     fn from(input: &str) -> Self {
         match input {
             "symbol" => Self::Symbol,
+            "narrowSymbol" => Self::NarrowSymbol,
             "code" => Self::Code,
             "name" => Self::Name,
             _ => Self::default(),
@@ -149,15 +273,90 @@ impl From<&str> for FluentNumberCurrencyDisplayStyle {
     }
 }
 
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat#unitdisplay
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FluentUnitDisplay {
+    Long,
+    Short,
+    Narrow,
+}
+
+impl std::default::Default for FluentUnitDisplay {
+    fn default() -> Self {
+        Self::Short
+    }
+}
+
+impl From<&str> for FluentUnitDisplay {
+    fn from(input: &str) -> Self {
+        match input {
+            "long" => Self::Long,
+            "short" => Self::Short,
+            "narrow" => Self::Narrow,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// The plural rules used to select a variant for the number, see
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/PluralRules#type
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FluentNumberType {
+    /// Plural categories of quantities, e.g. "1 file", "2 files"
+    Cardinal,
+    /// Plural categories of ranks, e.g. "1st", "2nd", "3rd"
+    Ordinal,
+}
+
+impl std::default::Default for FluentNumberType {
+    fn default() -> Self {
+        Self::Cardinal
+    }
+}
+
+impl From<&str> for FluentNumberType {
+    fn from(input: &str) -> Self {
+        match input {
+            "cardinal" => Self::Cardinal,
+            "ordinal" => Self::Ordinal,
+            _ => Self::default(),
+        }
+    }
+}
+
 /// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat#locale_options
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, rename_all = "camelCase"))]
 pub struct FluentNumberOptions {
     pub style: FluentNumberStyle,
     pub notation: FluentNumberNotation,
+    pub compact_display: FluentNumberCompactDisplay,
+
+    /// The ISO 4217 currency code used with the currency style, e.g. `USD`.
     pub currency: Option<String>,
+
+    /// How the currency is displayed. ICU4X has no currency names yet,
+    /// so `name` fails with a [`FluentError::NumberFormat`].
     pub currency_display: FluentNumberCurrencyDisplayStyle,
+
+    /// The CLDR unit identifier used with the unit style, e.g. `kilometer` or
+    /// `kilometer-per-hour`.
+    pub unit: Option<String>,
+
+    /// How the unit is displayed. ICU4X has no unit names yet,
+    /// so `long` is displayed the same way as `short`.
+    pub unit_display: FluentUnitDisplay,
+
     pub use_grouping: FluentNumberGrouping,
 
+    /// When to display the sign of the number.
+    pub sign_display: FluentNumberSignDisplay,
+
     /// The minimum number of integer digits to use.
     /// A value with a smaller number of integer digits
     /// than this number will be left-padded with zeros
@@ -177,6 +376,19 @@ pub struct FluentNumberOptions {
     pub maximum_significant_digits: Option<usize>,
 
     pub rounding_mode: FluentNumberRoundingMode,
+
+    /// Rounds to multiples of this increment at the last fraction digit,
+    /// e.g. `5` with two fraction digits rounds to the nearest `0.05`.
+    /// Must be one of 1, 2, 5, 10, 20, 25, 50, 100, 200, 250, 500, 1000, 2000, 2500 and 5000,
+    /// other values are treated as `1`.
+    pub rounding_increment: Option<u16>,
+
+    /// Whether to drop the fraction digits of numbers that are integers.
+    pub trailing_zero_display: FluentNumberTrailingZeroDisplay,
+
+    /// The plural rules used when selecting a variant, set with `type: "ordinal"`
+    /// or the `ORDINAL` function. It doesn't affect formatting.
+    pub plural_type: FluentNumberType,
 }
 
 impl FluentNumberOptions {
@@ -189,18 +401,39 @@ impl FluentNumberOptions {
                 ("notation", FluentValue::String(n)) => {
                     self.notation = n.as_ref().into();
                 }
+                ("compactDisplay", FluentValue::String(n)) => {
+                    self.compact_display = n.as_ref().into();
+                }
                 ("currency", FluentValue::String(n)) => {
                     self.currency = Some(n.to_string());
                 }
                 ("currencyDisplay", FluentValue::String(n)) => {
                     self.currency_display = n.as_ref().into();
                 }
+                ("unit", FluentValue::String(n)) => {
+                    self.unit = Some(n.to_string());
+                }
+                ("unitDisplay", FluentValue::String(n)) => {
+                    self.unit_display = n.as_ref().into();
+                }
                 ("useGrouping", FluentValue::String(n)) => {
                     self.use_grouping = n.as_ref().into();
                 }
+                ("signDisplay", FluentValue::String(n)) => {
+                    self.sign_display = n.as_ref().into();
+                }
                 ("roundingMode", FluentValue::String(n)) => {
                     self.rounding_mode = n.as_ref().into();
                 }
+                ("trailingZeroDisplay", FluentValue::String(n)) => {
+                    self.trailing_zero_display = n.as_ref().into();
+                }
+                ("type", FluentValue::String(n)) => {
+                    self.plural_type = n.as_ref().into();
+                }
+                ("roundingIncrement", FluentValue::Number(n)) => {
+                    self.rounding_increment = Some(n.into());
+                }
                 ("minimumIntegerDigits", FluentValue::Number(n)) => {
                     self.minimum_integer_digits = Some(n.into());
                 }
@@ -220,88 +453,675 @@ impl FluentNumberOptions {
             }
         }
     }
+
+    /// Returns the name of an option that is required by the other options but not set,
+    /// which is `currency` for the currency style and `unit` for the unit style.
+    ///
+    /// A `currency` that is not a three letter ISO 4217 code, e.g. `EURO`, counts as not set.
+    pub fn missing_option(&self) -> Option<&'static str> {
+        match (self.style, &self.currency, &self.unit) {
+            (FluentNumberStyle::Currency, None, _) => Some("currency"),
+            (FluentNumberStyle::Currency, Some(currency), _) if !is_currency_code(currency) => {
+                Some("currency")
+            }
+            (FluentNumberStyle::Unit, _, None) => Some("unit"),
+            _ => None,
+        }
+    }
+
+    /// Returns the names of the options that are set but have no effect, like `currency`
+    /// without the currency style.
+    pub fn unused_options(&self) -> Vec<&'static str> {
+        let mut unused = vec![];
+        if self.currency.is_some() && self.style != FluentNumberStyle::Currency {
+            unused.push("currency");
+        }
+        if self.unit.is_some() && self.style != FluentNumberStyle::Unit {
+            unused.push("unit");
+        }
+        unused
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FluentNumber {
     pub value: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub options: FluentNumberOptions,
+
+    /// The exact decimal value, if the number was created from a decimal type.
+    /// When set, it is formatted instead of `value`, so no digits are lost to `f64`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub exact: Option<FixedDecimal>,
+}
+
+/// Returns whether the code has the shape of an ISO 4217 currency code, three ASCII letters.
+fn is_currency_code(code: &str) -> bool {
+    code.len() == 3 && code.bytes().all(|byte| byte.is_ascii_alphabetic())
 }
 
+/// Currency patterns are cached as the currency formatted around a zero, e.g. `$0` or `0\u{a0}kr`,
+/// keyed by the currency code and the display style.
+type CurrencyPatterns = HashMap<(String, FluentNumberCurrencyDisplayStyle), Option<String>>;
+
 thread_local! {
-    static FORMATTERS: RefCell<HashMap<Locale, HashMap<FluentNumberGrouping, FixedDecimalFormatter>>> = RefCell::new(HashMap::new());
+    static FORMATTERS: RefCell<FormatterCache<FluentNumberGrouping, FixedDecimalFormatter>> = RefCell::new(HashMap::new());
+    static COMPACT_FORMATTERS: RefCell<FormatterCache<(FluentNumberCompactDisplay, FluentNumberGrouping), CompactDecimalFormatter>> = RefCell::new(HashMap::new());
+    static CURRENCY_PATTERNS: RefCell<HashMap<Locale, CurrencyPatterns>> = RefCell::new(HashMap::new());
+    static PERCENT_PATTERNS: RefCell<HashMap<Locale, Option<String>>> = RefCell::new(HashMap::new());
 }
 
 impl FluentNumber {
     pub const fn new(value: f64, options: FluentNumberOptions) -> Self {
-        Self { value, options }
+        Self {
+            value,
+            options,
+            exact: None,
+        }
+    }
+
+    /// Returns the number with its options replaced by `options`.
+    ///
+    /// Unlike [`FluentNumber::new`] this is not a `const fn`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fluent_bundle::types::{FluentNumber, FluentNumberOptions};
+    ///
+    /// let number = FluentNumber::from(5).with_options(FluentNumberOptions {
+    ///     minimum_fraction_digits: Some(2),
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(number.options.minimum_fraction_digits, Some(2));
+    /// ```
+    pub fn with_options(mut self, options: FluentNumberOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the number with its options transformed by `f`, e.g. to change a
+    /// single option in a custom function. Not a `const fn`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fluent_bundle::types::{FluentNumber, FluentNumberOptions, FluentNumberStyle};
+    ///
+    /// let number = FluentNumber::from(0.25).map_options(|options| FluentNumberOptions {
+    ///     style: FluentNumberStyle::Percent,
+    ///     ..options
+    /// });
+    /// assert_eq!(number.options.style, FluentNumberStyle::Percent);
+    /// ```
+    pub fn map_options<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(FluentNumberOptions) -> FluentNumberOptions,
+    {
+        self.options = f(self.options);
+        self
+    }
+
+    /// Returns the number with its value replaced by `value`, keeping the options.
+    /// Not a `const fn`.
+    ///
+    /// An exact decimal value, from a decimal type, is dropped, as it no longer
+    /// matches the value.
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.value = value;
+        self.exact = None;
+        self
+    }
+
+    /// Compares the values of two numbers with [`f64::total_cmp`], which orders every
+    /// value, including `-0.0` before `0.0` and NaN after positive infinity.
+    ///
+    /// Use it to sort numbers, where the [`PartialOrd`] implementation gives up on NaN.
+    pub fn total_cmp(&self, other: &FluentNumber) -> Ordering {
+        self.value.total_cmp(&other.value)
     }
 
-    pub fn as_string(&self, locale: &Locale) -> Cow<'static, str> {
+    /// Returns false for NaN and the infinities, unless the number has an exact decimal value.
+    pub fn is_finite(&self) -> bool {
+        self.exact.is_some() || self.value.is_finite()
+    }
+
+    /// Returns true if the value is NaN and the number has no exact decimal value.
+    pub fn is_nan(&self) -> bool {
+        self.exact.is_none() && self.value.is_nan()
+    }
+
+    /// Returns true if the value is positive or negative infinity and the number has no
+    /// exact decimal value.
+    pub fn is_infinite(&self) -> bool {
+        self.exact.is_none() && self.value.is_infinite()
+    }
+
+    /// Formats the number for the given locale.
+    ///
+    /// Falls back to the formatting data of the root locale if data for the locale is missing,
+    /// and returns an error if no ICU formatter can be created at all.
+    pub fn as_string(&self, locale: &Locale) -> Result<Cow<'static, str>, FluentError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("format_number", locale = %locale).entered();
+
+        if !self.is_finite() {
+            return self.as_string_non_finite(locale);
+        }
+
         match self.options.notation {
             FluentNumberNotation::Standard => self.as_string_standard(locale),
             FluentNumberNotation::Scientific => self.as_string_scientific(locale, 1),
             FluentNumberNotation::Engineering => self.as_string_scientific(locale, 3),
+            FluentNumberNotation::Compact => self.as_string_compact(locale),
         }
     }
 
-    fn with_formatter<R, F: Fn(&FixedDecimalFormatter)->R>(&self, locale: &Locale, f: F)->R {
-        let grouping = match self.options.use_grouping {
+    /// Formats the number like [`as_string`](FluentNumber::as_string), split into parts
+    /// like the integer digits, the decimal separator and the currency symbol.
+    ///
+    /// The formatted string is split using the decimal symbols of the locale, the text
+    /// around the digits is a currency symbol, a percent sign or a compact unit depending
+    /// on the style and the notation of the number.
+    pub fn to_parts(&self, locale: &Locale) -> Result<Vec<FluentPart<'static>>, FluentError> {
+        let formatted = self.as_string(locale)?;
+
+        let payload: Option<DataPayload<DecimalSymbolsV1Marker>> = DecimalBaked
+            .load(DataRequest {
+                locale: &locale.into(),
+                metadata: Default::default(),
+            })
+            .and_then(|response| response.take_payload())
+            .ok();
+        let (decimal_separator, grouping_separator) =
+            payload.as_ref().map_or((".", ","), |payload| {
+                (
+                    &*payload.get().decimal_separator,
+                    &*payload.get().grouping_separator,
+                )
+            });
+
+        let scientific = matches!(
+            self.options.notation,
+            FluentNumberNotation::Scientific | FluentNumberNotation::Engineering
+        );
+        let affix_type = match (self.options.style, self.options.notation) {
+            (_, FluentNumberNotation::Compact) => NumberPartType::Compact,
+            (FluentNumberStyle::Currency, _) => NumberPartType::Currency,
+            (FluentNumberStyle::Percent, _) => NumberPartType::PercentSign,
+            (FluentNumberStyle::Unit, _) => NumberPartType::Unit,
+            (FluentNumberStyle::Decimal, _) => NumberPartType::Literal,
+        };
+
+        let mut seen_digit = false;
+        let mut seen_decimal = false;
+        let mut in_exponent = false;
+
+        let runs = split_runs(&formatted, |rest| {
+            let c = rest.chars().next().unwrap_or_default();
+            let len = c.len_utf8();
+            let followed_by_digit = |separator: &str| {
+                !separator.is_empty()
+                    && rest
+                        .strip_prefix(separator)
+                        .and_then(|rest| rest.chars().next())
+                        .is_some_and(char::is_numeric)
+            };
+
+            if c.is_numeric() {
+                seen_digit = true;
+                let part_type = if in_exponent {
+                    NumberPartType::Exponent
+                } else if seen_decimal {
+                    NumberPartType::Fraction
+                } else {
+                    NumberPartType::Integer
+                };
+                (part_type, len)
+            } else if seen_digit
+                && !seen_decimal
+                && !in_exponent
+                && followed_by_digit(decimal_separator)
+            {
+                seen_decimal = true;
+                (NumberPartType::Decimal, decimal_separator.len())
+            } else if seen_digit
+                && !seen_decimal
+                && !in_exponent
+                && followed_by_digit(grouping_separator)
+            {
+                (NumberPartType::Group, grouping_separator.len())
+            } else if scientific && seen_digit && c == 'E' {
+                in_exponent = true;
+                (NumberPartType::ExponentSeparator, len)
+            } else if matches!(c, '-' | '\u{2212}') {
+                (NumberPartType::MinusSign, len)
+            } else if c == '+' {
+                (NumberPartType::PlusSign, len)
+            } else if c.is_whitespace()
+                || c.is_control()
+                || matches!(c, '\u{200E}' | '\u{200F}' | '\u{061C}')
+            {
+                (NumberPartType::Literal, len)
+            } else {
+                (affix_type, len)
+            }
+        });
+
+        Ok(runs
+            .into_iter()
+            .map(|(part_type, value)| FluentPart::Number {
+                value: value.to_string().into(),
+                part_type,
+            })
+            .collect())
+    }
+
+    /// Formats the value with exactly `decimal_places` fraction digits, using
+    /// Rust's own float formatting instead of ICU.
+    ///
+    /// The result is locale-insensitive (`.` as decimal separator, no grouping),
+    /// which makes it suitable for machine-readable output or for environments
+    /// without locale data. The formatting options are ignored.
+    pub fn as_fixed_point_string(&self, decimal_places: usize) -> String {
+        format!("{:.prec$}", self.value, prec = decimal_places)
+    }
+
+    /// Formats a relative change, where `0.05` stands for an increase of five percent,
+    /// as a percentage that always carries a sign unless it is zero.
+    ///
+    /// For `en` this gives `"+5%"` for `0.05`, `"-12%"` for `-0.12` and `"0%"` for `0.0`.
+    /// The signs and the placement of the percent sign are taken from the locale data,
+    /// with the data of the root locale used for locales that have none.
+    pub fn format_percent_change(change: f64, locale: &Locale) -> String {
+        let number = FluentNumber::new(
+            change,
+            FluentNumberOptions {
+                style: FluentNumberStyle::Percent,
+                sign_display: FluentNumberSignDisplay::ExceptZero,
+                ..Default::default()
+            },
+        );
+
+        // The root locale always has percent data, this only guards against missing ICU data.
+        number
+            .as_string(locale)
+            .map(Cow::into_owned)
+            .unwrap_or_else(|_| match (change * 100.0).round() {
+                percent if percent == 0.0 => "0%".to_string(),
+                percent => format!("{:+}%", percent),
+            })
+    }
+
+    fn grouping_strategy(&self) -> GroupingStrategy {
+        match self.options.use_grouping {
             FluentNumberGrouping::Always => GroupingStrategy::Always,
             FluentNumberGrouping::Auto => GroupingStrategy::Auto,
             FluentNumberGrouping::Min2 => GroupingStrategy::Min2,
             FluentNumberGrouping::Never => GroupingStrategy::Never,
-        };
+        }
+    }
 
+    fn with_formatter<R, F: Fn(&FixedDecimalFormatter) -> R>(
+        &self,
+        locale: &Locale,
+        f: F,
+    ) -> Result<R, FluentError> {
         FORMATTERS.with(|cell| {
-            if let Some(groupings_map) = cell.borrow_mut().get_mut(locale) {
-                if let Some(formatter) = groupings_map.get(&self.options.use_grouping) {
-                    return f(formatter);
-                }
+            let mut cell = cell.borrow_mut();
+            let formatters = locale_cache(&mut cell, locale);
 
-                let new_formatter = FixedDecimalFormatter::try_new(
-                    &locale.into(),
-                    FixedDecimalFormatterOptions::from(grouping),
-                )
-                    .expect("locale should be present");
+            if let Some(formatter) = formatters.get(&self.options.use_grouping) {
+                return Ok(f(formatter));
+            }
 
-                let res = f(&new_formatter);
+            let options = FixedDecimalFormatterOptions::from(self.grouping_strategy());
+            let new_formatter =
+                try_new_with_fallback(locale, FluentError::NumberFormat, |locale| {
+                    FixedDecimalFormatter::try_new(locale, options)
+                })?;
 
-                groupings_map.insert(self.options.use_grouping, new_formatter);
+            let res = f(&new_formatter);
 
-                return res;
-            }
+            formatters.put(self.options.use_grouping, new_formatter);
+
+            Ok(res)
+        })
+    }
 
-            let mut groupings_map = HashMap::new();
+    fn with_compact_formatter<R, F: Fn(&CompactDecimalFormatter) -> R>(
+        &self,
+        locale: &Locale,
+        f: F,
+    ) -> Result<R, FluentError> {
+        let key = (self.options.compact_display, self.options.use_grouping);
 
-            let new_formatter = FixedDecimalFormatter::try_new(
-                &locale.into(),
-                FixedDecimalFormatterOptions::from(grouping),
-            )
-                .expect("locale should be present");
+        COMPACT_FORMATTERS.with(|cell| {
+            let mut cell = cell.borrow_mut();
+            let formatters = locale_cache(&mut cell, locale);
+
+            if let Some(formatter) = formatters.get(&key) {
+                return Ok(f(formatter));
+            }
+
+            let grouping = self.grouping_strategy();
+            let new_formatter =
+                try_new_with_fallback(locale, FluentError::NumberFormat, |locale| {
+                    match self.options.compact_display {
+                        FluentNumberCompactDisplay::Short => {
+                            CompactDecimalFormatter::try_new_short(locale, grouping.into())
+                        }
+                        FluentNumberCompactDisplay::Long => {
+                            CompactDecimalFormatter::try_new_long(locale, grouping.into())
+                        }
+                    }
+                })?;
 
             let res = f(&new_formatter);
 
-            groupings_map.insert(self.options.use_grouping, new_formatter);
+            formatters.put(key, new_formatter);
+
+            Ok(res)
+        })
+    }
+
+    /// Returns the pattern for the configured currency, or `None` if no valid currency code is set.
+    ///
+    /// The currency formatter does not group digits, so the pattern is produced by formatting a zero
+    /// and the digits are filled in by the regular decimal formatter afterwards.
+    fn currency_pattern(&self, locale: &Locale) -> Result<Option<String>, FluentError> {
+        let currency = match &self.options.currency {
+            Some(currency) => currency.to_ascii_uppercase(),
+            None => return Ok(None),
+        };
+        let key = (currency.clone(), self.options.currency_display);
+
+        CURRENCY_PATTERNS.with(|cell| {
+            let mut cell = cell.borrow_mut();
+            let patterns = cell.entry(locale.clone()).or_default();
+
+            if let Some(pattern) = patterns.get(&key) {
+                return Ok(pattern.clone());
+            }
+
+            let code = Some(&currency)
+                .filter(|currency| is_currency_code(currency))
+                .and_then(|currency| TinyAsciiStr::<3>::from_str(currency).ok());
+
+            let pattern = code
+                .map(|code| -> Result<String, FluentError> {
+                    let zero = FixedDecimal::from(0);
+
+                    let pattern = match self.options.currency_display {
+                        FluentNumberCurrencyDisplayStyle::Symbol
+                        | FluentNumberCurrencyDisplayStyle::NarrowSymbol => {
+                            let width = match self.options.currency_display {
+                                FluentNumberCurrencyDisplayStyle::NarrowSymbol => Width::Narrow,
+                                _ => Width::Short,
+                            };
+                            let formatter = try_new_with_fallback(
+                                locale,
+                                FluentError::NumberFormat,
+                                |locale| {
+                                    CurrencyFormatter::try_new(
+                                        locale,
+                                        CurrencyFormatterOptions::from(width),
+                                    )
+                                },
+                            )?;
+                            formatter
+                                .format_fixed_decimal(&zero, CurrencyCode(code))
+                                .write_to_string()
+                                .into_owned()
+                        }
+                        // The code is placed like an alphabetic currency sign, e.g. `USD 0`.
+                        FluentNumberCurrencyDisplayStyle::Code => {
+                            let payload: DataPayload<CurrencyEssentialsV1Marker> =
+                                try_new_with_fallback(
+                                    locale,
+                                    FluentError::NumberFormat,
+                                    |locale| {
+                                        Baked
+                                            .load(DataRequest {
+                                                locale,
+                                                metadata: Default::default(),
+                                            })
+                                            .and_then(|response| response.take_payload())
+                                    },
+                                )?;
+                            let essentials = payload.get();
+                            let pattern = essentials
+                                .standard_alpha_next_to_number_pattern
+                                .as_ref()
+                                .or(essentials.standard_pattern.as_ref())
+                                .ok_or_else(|| {
+                                    FluentError::NumberFormat(
+                                        "The locale has no currency pattern".to_string(),
+                                    )
+                                })?;
+                            let formatted = pattern
+                                .interpolate((&zero, code.as_str()))
+                                .write_to_string()
+                                .into_owned();
+                            formatted
+                        }
+                        // ICU4X has no data for currency names yet.
+                        FluentNumberCurrencyDisplayStyle::Name => {
+                            return Err(FluentError::NumberFormat(format!(
+                                "The name of the currency {} is not available",
+                                code
+                            )))
+                        }
+                    };
+
+                    Ok(pattern)
+                })
+                .transpose()?;
 
-            cell.borrow_mut().insert(locale.clone(), groupings_map);
+            patterns.insert(key, pattern.clone());
 
-            res
+            Ok(pattern)
         })
     }
 
-    fn as_string_standard(&self, locale: &Locale) -> Cow<'static, str> {
-        self.with_formatter(locale, |formatter| {
+    /// Returns the percent pattern of the locale, with the number replaced by a zero, e.g. `0%` or `%0`.
+    fn percent_pattern(&self, locale: &Locale) -> Option<String> {
+        PERCENT_PATTERNS.with(|cell| {
+            cell.borrow_mut()
+                .entry(locale.clone())
+                .or_insert_with(|| {
+                    let payload: DataPayload<PercentEssentialsV1Marker> = Baked
+                        .load(DataRequest {
+                            locale: &locale.into(),
+                            metadata: Default::default(),
+                        })
+                        .and_then(|response| response.take_payload())
+                        .ok()?;
+                    let essentials = payload.get();
+
+                    // Only the positive subpattern is used, the sign is placed in front like for decimals.
+                    let pattern = essentials.standard.split(';').next()?;
+                    let number_start = pattern.find(|c| "#0,.".contains(c))?;
+                    let number_end = pattern.rfind(|c| "#0,.".contains(c))? + 1;
+
+                    Some(
+                        format!("{}0{}", &pattern[..number_start], &pattern[number_end..],)
+                            .replace('%', &essentials.percent_sign_symbol),
+                    )
+                })
+                .clone()
+        })
+    }
+
+    /// Returns the pattern for the configured unit, with the number replaced by a zero, e.g.
+    /// `0 km/h`, or `None` if the unit is not set or not one of the units Fluent supports.
+    ///
+    /// ICU4X has no unit formatter yet, so the units are displayed with their CLDR symbols.
+    /// The `narrow` display leaves out the space between the number and the symbol.
+    fn unit_pattern(&self) -> Option<String> {
+        let unit = self.options.unit.as_deref()?;
+        let symbol = match unit.split_once("-per-") {
+            Some((numerator, denominator)) => {
+                format!("{}/{}", unit_symbol(numerator)?, unit_symbol(denominator)?)
+            }
+            None => unit_symbol(unit)?.to_string(),
+        };
+
+        let attached = matches!(unit, "celsius" | "fahrenheit" | "degree" | "percent");
+        if attached || self.options.unit_display == FluentUnitDisplay::Narrow {
+            Some(format!("0{}", symbol))
+        } else {
+            Some(format!("0 {}", symbol))
+        }
+    }
+
+    fn as_string_standard(&self, locale: &Locale) -> Result<Cow<'static, str>, FluentError> {
+        let formatted = self.with_formatter(locale, |formatter| {
             formatter.format(&self.as_decimal()).to_string()
-        }).into()
+        })?;
+
+        self.apply_style(locale, formatted)
     }
 
-    fn as_string_scientific(&self, locale: &Locale, multiple_of: i16) -> Cow<'static, str> {
-        let mut decimal = FixedDecimal::from_str(&self.value.to_string())
-            .expect("That the f64 value when formatted as a string is convertable to a fixed decimal");
+    /// Places the formatted number in the pattern of the style, e.g. `$1.00` or `1 km`.
+    fn apply_style(
+        &self,
+        locale: &Locale,
+        formatted: String,
+    ) -> Result<Cow<'static, str>, FluentError> {
+        let pattern = match self.options.style {
+            FluentNumberStyle::Decimal => None,
+            FluentNumberStyle::Currency => self.currency_pattern(locale)?,
+            FluentNumberStyle::Percent => self.percent_pattern(locale),
+            FluentNumberStyle::Unit => self.unit_pattern(),
+        };
 
-        let magnitude = decimal.nonzero_magnitude_start() / multiple_of * multiple_of;
+        match pattern {
+            Some(pattern) => {
+                // The sign goes in front of the pattern, e.g. `-$1.00` rather than `$-1.00`.
+                let digits_start = formatted.find(char::is_numeric).unwrap_or(0);
+                let (sign, digits) = formatted.split_at(digits_start);
 
+                Ok(format!("{}{}", sign, pattern.replacen('0', digits, 1)).into())
+            }
+            None => Ok(formatted.into()),
+        }
+    }
+
+    /// Formats NaN and the infinities, which have no decimal digits, with the sign symbols
+    /// of the locale and the CLDR symbols `NaN` and `∞`, which ICU4X has no data for.
+    fn as_string_non_finite(&self, locale: &Locale) -> Result<Cow<'static, str>, FluentError> {
+        let symbol = if self.value.is_nan() { "NaN" } else { "∞" };
+
+        let negative = self.value.is_sign_negative() && !self.value.is_nan();
+        let show_sign = match self.options.sign_display {
+            FluentNumberSignDisplay::Never => false,
+            FluentNumberSignDisplay::Auto | FluentNumberSignDisplay::Negative => negative,
+            FluentNumberSignDisplay::Always => !self.value.is_nan(),
+            FluentNumberSignDisplay::ExceptZero => !self.value.is_nan(),
+        };
+
+        let payload: Option<DataPayload<DecimalSymbolsV1Marker>> = DecimalBaked
+            .load(DataRequest {
+                locale: &locale.into(),
+                metadata: Default::default(),
+            })
+            .and_then(|response| response.take_payload())
+            .ok();
+        let (prefix, suffix) = match (show_sign, negative, payload.as_ref()) {
+            (false, _, _) => (String::new(), String::new()),
+            (true, true, Some(payload)) => {
+                let affixes = &payload.get().minus_sign_affixes;
+                (affixes.prefix.to_string(), affixes.suffix.to_string())
+            }
+            (true, false, Some(payload)) => {
+                let affixes = &payload.get().plus_sign_affixes;
+                (affixes.prefix.to_string(), affixes.suffix.to_string())
+            }
+            (true, true, None) => ("-".to_string(), String::new()),
+            (true, false, None) => ("+".to_string(), String::new()),
+        };
+
+        let pattern = match self.options.style {
+            FluentNumberStyle::Decimal => None,
+            FluentNumberStyle::Currency => self.currency_pattern(locale)?,
+            FluentNumberStyle::Percent => self.percent_pattern(locale),
+            FluentNumberStyle::Unit => self.unit_pattern(),
+        };
+
+        // Like for finite numbers, the sign goes in front of the pattern.
+        let number = format!("{}{}", symbol, suffix);
+        Ok(match pattern {
+            Some(pattern) => format!("{}{}", prefix, pattern.replacen('0', &number, 1)),
+            None => format!("{}{}", prefix, number),
+        }
+        .into())
+    }
+
+    fn as_string_compact(&self, locale: &Locale) -> Result<Cow<'static, str>, FluentError> {
+        let mut decimal = self.to_fixed_decimal();
+
+        if self.options.style == FluentNumberStyle::Percent {
+            decimal.multiply_pow10(2);
+            decimal.trim_start();
+        }
+        decimal.apply_sign_display(self.options.sign_display.into());
+
+        let formatted = self.with_compact_formatter(locale, |formatter| {
+            // Without explicit fraction digits the formatter picks the usual
+            // compact precision: one fraction digit below 10, none above.
+            if self.options.minimum_fraction_digits.is_none()
+                && self.options.maximum_fraction_digits.is_none()
+            {
+                return formatter.format_fixed_decimal(decimal.clone()).to_string();
+            }
+
+            let minimum_fraction_digits = self.options.minimum_fraction_digits.unwrap_or(0);
+            let maximum_fraction_digits =
+                self.options
+                    .maximum_fraction_digits
+                    .unwrap_or(minimum_fraction_digits) as i16;
+
+            let mut exponent =
+                formatter.compact_exponent_for_magnitude(decimal.nonzero_magnitude_start());
+            let mut significand = decimal.clone().multiplied_pow10(-i16::from(exponent));
+            self.round(&mut significand, -maximum_fraction_digits);
+
+            // Rounding can carry the value into the next magnitude, e.g. 999.99K into 1000K.
+            let rounded_exponent = formatter.compact_exponent_for_magnitude(
+                significand.nonzero_magnitude_start() + i16::from(exponent),
+            );
+            if rounded_exponent != exponent {
+                significand.multiply_pow10(i16::from(exponent) - i16::from(rounded_exponent));
+                exponent = rounded_exponent;
+            }
+
+            significand.trim_start();
+            significand.trim_end();
+            significand.pad_end(-(minimum_fraction_digits as i16));
+            // Rounding resets the sign display, e.g. for a value rounded to zero.
+            significand.apply_sign_display(self.options.sign_display.into());
+
+            let compact = CompactDecimal::from_significand_and_exponent(significand, exponent);
+
+            formatter
+                .format_compact_decimal(&compact)
+                .map(|formatted| formatted.to_string())
+                .unwrap_or_else(|_| formatter.format_fixed_decimal(decimal.clone()).to_string())
+        })?;
+
+        self.apply_style(locale, formatted)
+    }
+
+    fn as_string_scientific(
+        &self,
+        locale: &Locale,
+        multiple_of: i16,
+    ) -> Result<Cow<'static, str>, FluentError> {
+        let mut decimal = self.to_fixed_decimal();
+
+        let magnitude = decimal.nonzero_magnitude_start() / multiple_of * multiple_of;
 
         decimal.multiply_pow10(-magnitude);
         decimal.trim_start();
@@ -309,65 +1129,227 @@ impl FluentNumber {
 
         let minimum_integer_digits = self.options.minimum_integer_digits.unwrap_or(2);
         let minimum_fraction_digits = self.options.minimum_fraction_digits.unwrap_or(3);
-        let maximum_fraction_digits = self.options.maximum_fraction_digits.unwrap_or(minimum_fraction_digits.max(3)) as i16;
+        let maximum_fraction_digits = self
+            .options
+            .maximum_fraction_digits
+            .unwrap_or(minimum_fraction_digits.max(3)) as i16;
 
-        match self.options.rounding_mode {
-            FluentNumberRoundingMode::Ceil => decimal.ceil(-maximum_fraction_digits),
-            FluentNumberRoundingMode::Floor => decimal.floor(-maximum_fraction_digits),
-            FluentNumberRoundingMode::Expand => decimal.expand(-maximum_fraction_digits),
-            FluentNumberRoundingMode::Trunc => decimal.trunc(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfCeil => decimal.half_ceil(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfFloor => decimal.half_floor(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfExpand => decimal.half_expand(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfTrunc => decimal.half_trunc(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfEven => decimal.half_even(-maximum_fraction_digits),
-        };
+        self.round(&mut decimal, -maximum_fraction_digits);
 
         decimal.trim_end();
         decimal.pad_end(-(minimum_fraction_digits as i16));
+        decimal.apply_sign_display(self.options.sign_display.into());
 
-        let mut magnitude_decimal = FixedDecimal::from(magnitude.abs());
+        // The exponent always shows its sign, formatted with the sign symbols and digits of the locale.
+        let mut magnitude_decimal = FixedDecimal::from(magnitude);
         magnitude_decimal.pad_start(minimum_integer_digits as i16);
+        magnitude_decimal.apply_sign_display(SignDisplay::Always);
 
         self.with_formatter(locale, |formatter| {
             let mut string = formatter.format(&decimal).to_string();
-            string.push_str("E");
-            if magnitude.is_negative() {
-                string.push_str("-"); // TODO: Should be accessing formatter data but I see no method for it.
-            } else {
-                string.push_str("+"); // TODO: Should be accessing formatter data but I see no method for it.
-            }
+            // The ICU4X decimal symbols have no exponent separator, so the one of the root locale is used.
+            string.push('E');
             string.push_str(&formatter.format(&magnitude_decimal).to_string());
 
             string
-        }).into()
+        })
+        .map(Into::into)
+    }
+
+    /// Returns the unformatted value as a decimal, preferring the exact value if there is one.
+    fn to_fixed_decimal(&self) -> FixedDecimal {
+        match &self.exact {
+            Some(exact) => exact.clone(),
+            None => FixedDecimal::from_str(&self.value.to_string()).expect(
+                "That the f64 value when formatted as a string is convertable to a fixed decimal",
+            ),
+        }
+    }
+
+    /// Rounds `decimal` at `position` using the configured rounding mode.
+    fn round(&self, decimal: &mut FixedDecimal, position: i16) {
+        self.round_to_increment(decimal, position, RoundingIncrement::MultiplesOf1);
+    }
+
+    /// Rounds `decimal` to `increment` at `position` using the configured rounding mode.
+    fn round_to_increment(
+        &self,
+        decimal: &mut FixedDecimal,
+        position: i16,
+        increment: RoundingIncrement,
+    ) {
+        match self.options.rounding_mode {
+            FluentNumberRoundingMode::Ceil => decimal.ceil_to_increment(position, increment),
+            FluentNumberRoundingMode::Floor => decimal.floor_to_increment(position, increment),
+            FluentNumberRoundingMode::Expand => decimal.expand_to_increment(position, increment),
+            FluentNumberRoundingMode::Trunc => decimal.trunc_to_increment(position, increment),
+            FluentNumberRoundingMode::HalfCeil => {
+                decimal.half_ceil_to_increment(position, increment)
+            }
+            FluentNumberRoundingMode::HalfFloor => {
+                decimal.half_floor_to_increment(position, increment)
+            }
+            FluentNumberRoundingMode::HalfExpand => {
+                decimal.half_expand_to_increment(position, increment)
+            }
+            FluentNumberRoundingMode::HalfTrunc => {
+                decimal.half_trunc_to_increment(position, increment)
+            }
+            FluentNumberRoundingMode::HalfEven => {
+                decimal.half_even_to_increment(position, increment)
+            }
+        };
+    }
+
+    /// Splits the rounding increment into a shift of the rounding position and one of
+    /// the increments ICU can round to, e.g. `250` into one position and multiples of 25.
+    fn rounding_increment(&self) -> (i16, RoundingIncrement) {
+        match self.options.rounding_increment.unwrap_or(1) {
+            2 => (0, RoundingIncrement::MultiplesOf2),
+            5 => (0, RoundingIncrement::MultiplesOf5),
+            10 => (1, RoundingIncrement::MultiplesOf1),
+            20 => (1, RoundingIncrement::MultiplesOf2),
+            25 => (0, RoundingIncrement::MultiplesOf25),
+            50 => (1, RoundingIncrement::MultiplesOf5),
+            100 => (2, RoundingIncrement::MultiplesOf1),
+            200 => (2, RoundingIncrement::MultiplesOf2),
+            250 => (1, RoundingIncrement::MultiplesOf25),
+            500 => (2, RoundingIncrement::MultiplesOf5),
+            1000 => (3, RoundingIncrement::MultiplesOf1),
+            2000 => (3, RoundingIncrement::MultiplesOf2),
+            2500 => (2, RoundingIncrement::MultiplesOf25),
+            5000 => (3, RoundingIncrement::MultiplesOf5),
+            _ => (0, RoundingIncrement::MultiplesOf1),
+        }
     }
 
     fn as_decimal(&self) -> FixedDecimal {
+        // Currencies default to two fraction digits, percentages to none
+        // and plain numbers to between zero and three.
+        let (default_minimum_fraction_digits, default_maximum_fraction_digits) =
+            match self.options.style {
+                FluentNumberStyle::Decimal | FluentNumberStyle::Unit => (0, 3),
+                FluentNumberStyle::Currency => (2, 2),
+                FluentNumberStyle::Percent => (0, 0),
+            };
+
         let minimum_integer_digits = self.options.minimum_integer_digits.unwrap_or(1);
-        let minimum_fraction_digits = self.options.minimum_fraction_digits.unwrap_or(0);
-        let maximum_fraction_digits = self.options.maximum_fraction_digits.unwrap_or(minimum_fraction_digits.max(3)) as i16;
-
-        let f3 = FixedDecimal::from_str(&self.value.to_string())
-            .expect("That the f64 value when formatted as a string is convertable to a fixed decimal")
-            .padded_start(minimum_integer_digits as i16);
-
-        let f4 = match self.options.rounding_mode {
-            FluentNumberRoundingMode::Ceil => f3.ceiled(-maximum_fraction_digits),
-            FluentNumberRoundingMode::Floor => f3.floored(-maximum_fraction_digits),
-            FluentNumberRoundingMode::Expand => f3.expanded(-maximum_fraction_digits),
-            FluentNumberRoundingMode::Trunc => f3.trunced(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfCeil => f3.half_ceiled(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfFloor => f3.half_floored(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfExpand => f3.half_expanded(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfTrunc => f3.half_trunced(-maximum_fraction_digits),
-            FluentNumberRoundingMode::HalfEven => f3.half_evened(-maximum_fraction_digits),
+        let minimum_fraction_digits = self
+            .options
+            .minimum_fraction_digits
+            .unwrap_or(default_minimum_fraction_digits);
+        let maximum_fraction_digits = self
+            .options
+            .maximum_fraction_digits
+            .unwrap_or(minimum_fraction_digits.max(default_maximum_fraction_digits))
+            as i16;
+
+        let mut f3 = self.to_fixed_decimal();
+
+        if self.options.style == FluentNumberStyle::Percent {
+            f3.multiply_pow10(2);
+            f3.trim_start();
+        }
+
+        let mut f5 = if self.options.minimum_significant_digits.is_some()
+            || self.options.maximum_significant_digits.is_some()
+        {
+            // Significant digits take priority over fraction digits when both are set.
+            let minimum_significant_digits =
+                self.options.minimum_significant_digits.unwrap_or(1) as i16;
+            let maximum_significant_digits =
+                self.options
+                    .maximum_significant_digits
+                    .unwrap_or(21)
+                    .max(minimum_significant_digits as usize) as i16;
+
+            let magnitude = f3.nonzero_magnitude_start();
+            self.round(&mut f3, magnitude - maximum_significant_digits + 1);
+            f3.trim_end();
+
+            // The magnitude is taken after rounding, as rounding can carry into the next digit.
+            let magnitude = f3.nonzero_magnitude_start();
+            f3.pad_end(magnitude - minimum_significant_digits + 1);
+
+            f3.padded_start(minimum_integer_digits as i16)
+        } else {
+            let mut f4 = f3.padded_start(minimum_integer_digits as i16);
+
+            let (shift, increment) = self.rounding_increment();
+            self.round_to_increment(&mut f4, -maximum_fraction_digits + shift, increment);
+
+            f4.trimmed_end()
+                .padded_end(-(minimum_fraction_digits as i16))
         };
 
-        f4.trimmed_end().padded_end(-(minimum_fraction_digits as i16))
+        // The minimum fraction digits only apply to numbers that still have a fraction after rounding.
+        if self.options.trailing_zero_display == FluentNumberTrailingZeroDisplay::StripIfInteger
+            && f5.nonzero_magnitude_end() >= 0
+        {
+            f5.trim_end();
+        }
+
+        f5.apply_sign_display(self.options.sign_display.into());
+
+        f5
     }
 }
 
+/// Returns the symbol of one of the simple units sanctioned by ECMA-402 for `Intl.NumberFormat`.
+fn unit_symbol(unit: &str) -> Option<&'static str> {
+    Some(match unit {
+        "acre" => "ac",
+        "bit" => "bit",
+        "byte" => "byte",
+        "celsius" => "°C",
+        "centimeter" => "cm",
+        "day" => "d",
+        "degree" => "°",
+        "fahrenheit" => "°F",
+        "fluid-ounce" => "fl oz",
+        "foot" => "ft",
+        "gallon" => "gal",
+        "gigabit" => "Gb",
+        "gigabyte" => "GB",
+        "gram" => "g",
+        "hectare" => "ha",
+        "hour" => "h",
+        "inch" => "in",
+        "kilobit" => "kb",
+        "kilobyte" => "kB",
+        "kilogram" => "kg",
+        "kilometer" => "km",
+        "liter" => "L",
+        "megabit" => "Mb",
+        "megabyte" => "MB",
+        "meter" => "m",
+        "microsecond" => "μs",
+        "mile" => "mi",
+        "mile-scandinavian" => "smi",
+        "milliliter" => "mL",
+        "millimeter" => "mm",
+        "millisecond" => "ms",
+        "minute" => "min",
+        "month" => "mo",
+        "nanosecond" => "ns",
+        "ounce" => "oz",
+        "percent" => "%",
+        "petabyte" => "PB",
+        "pound" => "lb",
+        "second" => "s",
+        "stone" => "st",
+        "terabit" => "Tb",
+        "terabyte" => "TB",
+        "week" => "wk",
+        "yard" => "yd",
+        "year" => "yr",
+        _ => return None,
+    })
+}
+
+/// Parses a number, keeping the digits after the decimal point as the minimum
+/// fraction digits, so `"1.50"` formats as `1.50`. A negative zero keeps its
+/// sign, `"-0.0"` formats as `-0.0`.
 impl FromStr for FluentNumber {
     type Err = std::num::ParseFloatError;
 
@@ -383,6 +1365,30 @@ impl FromStr for FluentNumber {
     }
 }
 
+/// Numbers are ordered by their `value`. Numbers with the same value but different
+/// formatting options are not `==`, so they are not ordered either.
+///
+/// A NaN is not ordered, `partial_cmp` returns `None` when either value is NaN,
+/// see [`FluentNumber::total_cmp`] for a total order.
+impl PartialOrd for FluentNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.value.partial_cmp(&other.value)? {
+            Ordering::Equal if self != other => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// Formats the number with the root locale, for debugging and logging.
+impl std::fmt::Display for FluentNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.as_string(&Locale::UND) {
+            Ok(s) => f.write_str(&s),
+            Err(_) => f.write_str("<error>"),
+        }
+    }
+}
+
 impl<'l> From<FluentNumber> for FluentValue<'l> {
     fn from(input: FluentNumber) -> Self {
         FluentValue::Number(input)
@@ -393,18 +1399,12 @@ macro_rules! from_num {
     ($num:ty) => {
         impl From<$num> for FluentNumber {
             fn from(n: $num) -> Self {
-                Self {
-                    value: n as f64,
-                    options: FluentNumberOptions::default(),
-                }
+                Self::new(n as f64, FluentNumberOptions::default())
             }
         }
         impl From<&$num> for FluentNumber {
             fn from(n: &$num) -> Self {
-                Self {
-                    value: *n as f64,
-                    options: FluentNumberOptions::default(),
-                }
+                Self::new(*n as f64, FluentNumberOptions::default())
             }
         }
         impl From<FluentNumber> for $num {
@@ -433,8 +1433,100 @@ macro_rules! from_num {
     };
 }
 
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for FluentNumber {
+    fn from(input: rust_decimal::Decimal) -> Self {
+        // Going through the string representation keeps every digit of the decimal.
+        let string = input.to_string();
+        let value = f64::from_str(&string)
+            .expect("That the decimal value when formatted as a string is convertable to a f64");
+        let exact = FixedDecimal::from_str(&string).expect(
+            "That the decimal value when formatted as a string is convertable to a fixed decimal",
+        );
+
+        let options = FluentNumberOptions {
+            minimum_fraction_digits: Some(input.scale() as usize),
+            ..Default::default()
+        };
+
+        Self {
+            value,
+            options,
+            exact: Some(exact),
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for FluentValue<'_> {
+    fn from(input: rust_decimal::Decimal) -> Self {
+        FluentValue::Number(input.into())
+    }
+}
+
+/// Converts the value of the number, returning `None` if it is NaN or out of range of the type.
+#[cfg(feature = "num-traits")]
+impl num_traits::ToPrimitive for FluentNumber {
+    fn to_i64(&self) -> Option<i64> {
+        num_traits::ToPrimitive::to_i64(&self.value)
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        num_traits::ToPrimitive::to_i128(&self.value)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        num_traits::ToPrimitive::to_u64(&self.value)
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        num_traits::ToPrimitive::to_u128(&self.value)
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        num_traits::ToPrimitive::to_f32(&self.value)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.value)
+    }
+}
+
+/// Creates a number with the default options, like the `From` conversions.
+#[cfg(feature = "num-traits")]
+impl num_traits::FromPrimitive for FluentNumber {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(n.into())
+    }
+}
+
+/// NaN and the infinities have no plural operands and are converted to the operands
+/// of `0`, use [`plural_category_safe`](crate::types::plural_category_safe) to detect them.
 impl From<&FluentNumber> for icu::plurals::PluralOperands {
     fn from(input: &FluentNumber) -> Self {
+        if !input.is_finite() {
+            return icu::plurals::PluralOperands::from(&FixedDecimal::from(0));
+        }
         icu::plurals::PluralOperands::from(&input.as_decimal()) // TODO this does not allow to handle trailing zeros
     }
 }
@@ -452,6 +1544,6 @@ mod tests {
         let x = 1i16;
         let y = &x;
         let z: FluentValue = y.into();
-        assert_eq!(z, FluentValue::try_number("1"));
+        assert_eq!(z, FluentValue::number_unchecked("1"));
     }
 }
@@ -1,20 +1,45 @@
+use crate::types::FluentNumber;
+use icu::locid::Locale;
+use icu::plurals::{PluralCategory, PluralOperands, PluralRuleType, PluralRules};
 use std::cell::RefCell;
 use std::collections::HashMap;
-use icu::locid::Locale;
-use icu::plurals::{PluralCategory, PluralOperands, PluralRules, PluralRuleType};
 
 thread_local! {
     // Ordinal, Cardinal
     static PLURALS: RefCell<HashMap<Locale, (PluralRules, PluralRules)>> = RefCell::new(HashMap::new());
 }
 
-pub fn plural_category<I: Into<PluralOperands>>(locale: &Locale, plural_rule_type: PluralRuleType, input: I) -> PluralCategory {
-    PLURALS.with(|cell| {
+/// Returns the plural category of the number in the locale, e.g. `One` for `1` in English
+/// cardinal rules, or `Two` for `2` in English ordinal rules.
+///
+/// NaN and the infinities have no plural operands, their category is always `Other`,
+/// like in `Intl.PluralRules`. Use [`plural_category_safe`] to tell them apart.
+pub fn plural_category(
+    locale: &Locale,
+    plural_rule_type: PluralRuleType,
+    input: &FluentNumber,
+) -> PluralCategory {
+    plural_category_safe(locale, plural_rule_type, input).unwrap_or(PluralCategory::Other)
+}
+
+/// Returns the plural category of the number in the locale, or `None` if the
+/// number is NaN or infinite.
+pub fn plural_category_safe(
+    locale: &Locale,
+    plural_rule_type: PluralRuleType,
+    input: &FluentNumber,
+) -> Option<PluralCategory> {
+    if !input.is_finite() {
+        return None;
+    }
+    let input = PluralOperands::from(input);
+
+    Some(PLURALS.with(|cell| {
         if let Some((ordinal, cardinal)) = cell.borrow().get(locale) {
             return match plural_rule_type {
                 PluralRuleType::Cardinal => cardinal.category_for(input),
                 PluralRuleType::Ordinal => ordinal.category_for(input),
-                _ => panic!("New plural rule type that should be implemented")
+                _ => panic!("New plural rule type that should be implemented"),
             };
         }
 
@@ -24,11 +49,69 @@ pub fn plural_category<I: Into<PluralOperands>>(locale: &Locale, plural_rule_typ
         let res = match plural_rule_type {
             PluralRuleType::Cardinal => cardinal.category_for(input),
             PluralRuleType::Ordinal => ordinal.category_for(input),
-            _ => panic!("New plural rule type that should be implemented")
+            _ => panic!("New plural rule type that should be implemented"),
         };
 
-        cell.borrow_mut().insert(locale.clone(), (ordinal, cardinal));
+        cell.borrow_mut()
+            .insert(locale.clone(), (ordinal, cardinal));
 
         res
-    })
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu::locid::locale;
+
+    #[test]
+    fn finite_numbers() {
+        let en = locale!("en");
+        assert_eq!(
+            plural_category(&en, PluralRuleType::Cardinal, &1.into()),
+            PluralCategory::One
+        );
+        assert_eq!(
+            plural_category(&en, PluralRuleType::Cardinal, &5.into()),
+            PluralCategory::Other
+        );
+        assert_eq!(
+            plural_category(&en, PluralRuleType::Ordinal, &2.into()),
+            PluralCategory::Two
+        );
+        assert_eq!(
+            plural_category_safe(&en, PluralRuleType::Ordinal, &3.into()),
+            Some(PluralCategory::Few)
+        );
+    }
+
+    #[test]
+    fn non_finite_numbers() {
+        let values = [
+            f64::NAN,
+            -f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f32::NAN as f64,
+            f32::INFINITY as f64,
+            f32::NEG_INFINITY as f64,
+            // A NaN with a payload.
+            f64::from_bits(0x7ff8_0000_0000_0001),
+        ];
+
+        for locale in [locale!("en"), locale!("pl"), locale!("ar")] {
+            for value in values {
+                let number = FluentNumber::from(value);
+                for rule_type in [PluralRuleType::Cardinal, PluralRuleType::Ordinal] {
+                    assert_eq!(
+                        plural_category(&locale, rule_type, &number),
+                        PluralCategory::Other
+                    );
+                    assert_eq!(plural_category_safe(&locale, rule_type, &number), None);
+                }
+                // Converting to operands doesn't panic either.
+                let _ = PluralOperands::from(&number);
+            }
+        }
+    }
 }
@@ -0,0 +1,288 @@
+use crate::args::FluentArgs;
+use crate::errors::FluentError;
+use crate::types::formatter_cache::{locale_cache, try_new_with_fallback, FormatterCache};
+use crate::types::FluentValue;
+use fixed_decimal::FixedDecimal;
+use icu::locid::Locale;
+use icu_experimental::relativetime::options::Numeric;
+use icu_experimental::relativetime::{RelativeTimeFormatter, RelativeTimeFormatterOptions};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format#unit
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FluentRelativeTimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl std::default::Default for FluentRelativeTimeUnit {
+    fn default() -> Self {
+        Self::Second
+    }
+}
+
+impl From<&str> for FluentRelativeTimeUnit {
+    fn from(input: &str) -> Self {
+        match input {
+            "second" | "seconds" => Self::Second,
+            "minute" | "minutes" => Self::Minute,
+            "hour" | "hours" => Self::Hour,
+            "day" | "days" => Self::Day,
+            "week" | "weeks" => Self::Week,
+            "month" | "months" => Self::Month,
+            "quarter" | "quarters" => Self::Quarter,
+            "year" | "years" => Self::Year,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat#style
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FluentRelativeTimeStyle {
+    Long,
+    Short,
+    Narrow,
+}
+
+impl std::default::Default for FluentRelativeTimeStyle {
+    fn default() -> Self {
+        Self::Long
+    }
+}
+
+impl From<&str> for FluentRelativeTimeStyle {
+    fn from(input: &str) -> Self {
+        match input {
+            "long" => Self::Long,
+            "short" => Self::Short,
+            "narrow" => Self::Narrow,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat#numeric
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FluentRelativeTimeNumeric {
+    /// Always displays the number, e.g. "1 day ago".
+    Always,
+    /// Uses a phrase where the locale has one, e.g. "yesterday", and the number otherwise.
+    Auto,
+}
+
+impl std::default::Default for FluentRelativeTimeNumeric {
+    fn default() -> Self {
+        Self::Always
+    }
+}
+
+impl From<&str> for FluentRelativeTimeNumeric {
+    fn from(input: &str) -> Self {
+        match input {
+            "always" => Self::Always,
+            "auto" => Self::Auto,
+            _ => Self::default(),
+        }
+    }
+}
+
+/// A relative point in time, such as "3 days ago" or "in 2 hours".
+///
+/// Positive values are in the future and negative values in the past.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FluentRelativeTime {
+    pub value: f64,
+    pub unit: FluentRelativeTimeUnit,
+    pub style: FluentRelativeTimeStyle,
+    pub numeric: FluentRelativeTimeNumeric,
+}
+
+type FormatterKey = (
+    FluentRelativeTimeUnit,
+    FluentRelativeTimeStyle,
+    FluentRelativeTimeNumeric,
+);
+
+thread_local! {
+    static FORMATTERS: RefCell<FormatterCache<FormatterKey, RelativeTimeFormatter>> = RefCell::new(HashMap::new());
+}
+
+impl FluentRelativeTime {
+    pub const fn new(
+        value: f64,
+        unit: FluentRelativeTimeUnit,
+        style: FluentRelativeTimeStyle,
+    ) -> Self {
+        Self {
+            value,
+            unit,
+            style,
+            numeric: FluentRelativeTimeNumeric::Always,
+        }
+    }
+
+    /// Returns the relative time with the given [`FluentRelativeTimeNumeric`] option.
+    pub const fn with_numeric(self, numeric: FluentRelativeTimeNumeric) -> Self {
+        Self { numeric, ..self }
+    }
+
+    pub fn merge(&mut self, opts: &FluentArgs) {
+        for (key, value) in opts.iter() {
+            match (key, value) {
+                ("unit", FluentValue::String(n)) => {
+                    self.unit = n.as_ref().into();
+                }
+                ("style", FluentValue::String(n)) => {
+                    self.style = n.as_ref().into();
+                }
+                ("numeric", FluentValue::String(n)) => {
+                    self.numeric = n.as_ref().into();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Formats the relative time, e.g. `in 3 days`.
+    ///
+    /// Fails for infinite and NaN values, which are not a point in time.
+    pub fn as_string(&self, locale: &Locale) -> Result<Cow<'static, str>, FluentError> {
+        if !self.value.is_finite() {
+            return Err(FluentError::RelativeTimeFormat(format!(
+                "{} is not a finite value",
+                self.value
+            )));
+        }
+
+        let decimal = FixedDecimal::from_str(&self.value.to_string())
+            .map_err(|error| FluentError::RelativeTimeFormat(error.to_string()))?;
+
+        self.with_formatter(locale, |formatter| {
+            formatter.format(decimal.clone()).to_string().into()
+        })
+    }
+
+    fn with_formatter<R, F: Fn(&RelativeTimeFormatter) -> R>(
+        &self,
+        locale: &Locale,
+        f: F,
+    ) -> Result<R, FluentError> {
+        let key = (self.unit, self.style, self.numeric);
+
+        FORMATTERS.with(|cell| {
+            let mut cell = cell.borrow_mut();
+            let formatters = locale_cache(&mut cell, locale);
+
+            if let Some(formatter) = formatters.get(&key) {
+                return Ok(f(formatter));
+            }
+
+            let options = RelativeTimeFormatterOptions {
+                numeric: match self.numeric {
+                    FluentRelativeTimeNumeric::Always => Numeric::Always,
+                    FluentRelativeTimeNumeric::Auto => Numeric::Auto,
+                },
+            };
+
+            let new_formatter =
+                try_new_with_fallback(locale, FluentError::RelativeTimeFormat, |locale| {
+                    match (self.unit, self.style) {
+                        (FluentRelativeTimeUnit::Second, FluentRelativeTimeStyle::Long) => {
+                            RelativeTimeFormatter::try_new_long_second(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Minute, FluentRelativeTimeStyle::Long) => {
+                            RelativeTimeFormatter::try_new_long_minute(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Hour, FluentRelativeTimeStyle::Long) => {
+                            RelativeTimeFormatter::try_new_long_hour(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Day, FluentRelativeTimeStyle::Long) => {
+                            RelativeTimeFormatter::try_new_long_day(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Week, FluentRelativeTimeStyle::Long) => {
+                            RelativeTimeFormatter::try_new_long_week(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Month, FluentRelativeTimeStyle::Long) => {
+                            RelativeTimeFormatter::try_new_long_month(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Quarter, FluentRelativeTimeStyle::Long) => {
+                            RelativeTimeFormatter::try_new_long_quarter(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Year, FluentRelativeTimeStyle::Long) => {
+                            RelativeTimeFormatter::try_new_long_year(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Second, FluentRelativeTimeStyle::Short) => {
+                            RelativeTimeFormatter::try_new_short_second(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Minute, FluentRelativeTimeStyle::Short) => {
+                            RelativeTimeFormatter::try_new_short_minute(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Hour, FluentRelativeTimeStyle::Short) => {
+                            RelativeTimeFormatter::try_new_short_hour(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Day, FluentRelativeTimeStyle::Short) => {
+                            RelativeTimeFormatter::try_new_short_day(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Week, FluentRelativeTimeStyle::Short) => {
+                            RelativeTimeFormatter::try_new_short_week(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Month, FluentRelativeTimeStyle::Short) => {
+                            RelativeTimeFormatter::try_new_short_month(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Quarter, FluentRelativeTimeStyle::Short) => {
+                            RelativeTimeFormatter::try_new_short_quarter(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Year, FluentRelativeTimeStyle::Short) => {
+                            RelativeTimeFormatter::try_new_short_year(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Second, FluentRelativeTimeStyle::Narrow) => {
+                            RelativeTimeFormatter::try_new_narrow_second(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Minute, FluentRelativeTimeStyle::Narrow) => {
+                            RelativeTimeFormatter::try_new_narrow_minute(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Hour, FluentRelativeTimeStyle::Narrow) => {
+                            RelativeTimeFormatter::try_new_narrow_hour(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Day, FluentRelativeTimeStyle::Narrow) => {
+                            RelativeTimeFormatter::try_new_narrow_day(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Week, FluentRelativeTimeStyle::Narrow) => {
+                            RelativeTimeFormatter::try_new_narrow_week(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Month, FluentRelativeTimeStyle::Narrow) => {
+                            RelativeTimeFormatter::try_new_narrow_month(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Quarter, FluentRelativeTimeStyle::Narrow) => {
+                            RelativeTimeFormatter::try_new_narrow_quarter(locale, options)
+                        }
+                        (FluentRelativeTimeUnit::Year, FluentRelativeTimeStyle::Narrow) => {
+                            RelativeTimeFormatter::try_new_narrow_year(locale, options)
+                        }
+                    }
+                })?;
+
+            let res = f(&new_formatter);
+
+            formatters.put(key, new_formatter);
+
+            Ok(res)
+        })
+    }
+}
+
+impl<'l> From<FluentRelativeTime> for FluentValue<'l> {
+    fn from(input: FluentRelativeTime) -> Self {
+        FluentValue::RelativeTime(input)
+    }
+}
@@ -1,6 +1,8 @@
-use fluent_bundle_for_carbide::{FluentArgs, FluentBundle, FluentResource};
-use std::borrow::Cow;
+use fluent_bundle_for_carbide::{
+    FluentArgs, FluentBundle, FluentBundleBuilder, FluentError, FluentResource, FluentValue,
+};
 use icu::locid::locale;
+use std::borrow::Cow;
 
 #[test]
 fn add_resource_override() {
@@ -35,6 +37,63 @@ fn add_resource_override() {
     assert!(errors.is_empty());
 }
 
+#[test]
+fn add_resource_override_terms_and_functions() {
+    let res =
+        FluentResource::try_new("-brand = Firefox\nabout = About { -brand }".to_string()).unwrap();
+    let res2 =
+        FluentResource::try_new("-brand = Thunderbird\nNUMBER = Number".to_string()).unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(&res).expect("Failed to add a resource");
+    bundle.add_resource_overriding(&res2);
+
+    let mut errors = vec![];
+    let value = bundle
+        .get_message("about")
+        .expect("Failed to retrieve a message")
+        .value()
+        .expect("Failed to retrieve a value of a message");
+    assert_eq!(
+        bundle.format_pattern(value, None, &mut errors),
+        "About Thunderbird"
+    );
+    assert!(errors.is_empty());
+
+    // Entries with the name of a function do not replace the function.
+    assert!(!bundle.has_message("NUMBER"));
+    assert!(bundle.function_names().any(|name| name == "NUMBER"));
+}
+
+#[test]
+fn add_cloned_resource() {
+    let res = FluentResource::try_new("key = Value".to_string()).unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle
+        .add_resource(res.clone())
+        .expect("Failed to add a resource");
+    drop(bundle);
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle
+        .add_resource(res.clone())
+        .expect("Failed to add a resource");
+
+    let mut errors = vec![];
+    let value = bundle
+        .get_message("key")
+        .expect("Failed to retrieve a message")
+        .value()
+        .expect("Failed to retrieve a value of a message");
+    assert_eq!(bundle.format_pattern(value, None, &mut errors), "Value");
+    assert!(errors.is_empty());
+
+    assert_eq!(res.source(), "key = Value");
+    assert_eq!(res.entries().count(), 1);
+}
+
 #[test]
 fn borrowed_plain_message() {
     let res = FluentResource::try_new("key = Value".to_string()).unwrap();
@@ -65,6 +124,25 @@ fn borrowed_plain_message() {
     assert!(is_borrowed(formatted_pattern));
 }
 
+#[test]
+fn borrowed_string_literal() {
+    let res = FluentResource::try_new("key = { \"Value\" }\nescaped = { \"\\u0041\" }".to_string())
+        .unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).expect("Failed to add a resource");
+
+    let mut errors = vec![];
+    let value = bundle.format_value("key", None, &mut errors).unwrap();
+    assert_eq!(value, "Value");
+    assert!(matches!(value, Cow::Borrowed(_)));
+
+    let value = bundle.format_value("escaped", None, &mut errors).unwrap();
+    assert_eq!(value, "A");
+    assert!(matches!(value, Cow::Owned(_)));
+    assert!(errors.is_empty());
+}
+
 #[test]
 fn arguments_outlive_formatted_pattern() {
     let res = FluentResource::try_new("key = { $variable }".to_string()).unwrap();
@@ -90,3 +168,855 @@ fn arguments_outlive_formatted_pattern() {
 
     assert_eq!(formatted_pattern, "Variable");
 }
+
+#[test]
+fn format_pattern_into_byte_buffer() {
+    let res = FluentResource::try_new("key = Hello { $name }!".to_string()).unwrap();
+    let en_us = locale!("en-US");
+
+    let mut bundle = FluentBundle::new(vec![en_us]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(&res).expect("Failed to add a resource");
+
+    let mut errors = vec![];
+    let mut args = FluentArgs::new();
+    args.set("name", "Jane");
+
+    let value = bundle
+        .get_message("key")
+        .expect("Failed to retrieve a message")
+        .value()
+        .expect("Failed to retrieve a value of a message");
+
+    let mut buf = Vec::with_capacity(64);
+    buf.extend_from_slice(b"HTTP body: ");
+    bundle.format_pattern_buf(&mut buf, value, Some(&args), &mut errors);
+
+    assert_eq!(buf, b"HTTP body: Hello Jane!");
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn format_pattern_to_writers() {
+    let res = FluentResource::try_new("key = Hello { $name }!".to_string()).unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(&res).expect("Failed to add a resource");
+
+    let mut errors = vec![];
+    let mut args = FluentArgs::new();
+    args.set("name", "Jane");
+
+    let value = bundle
+        .get_message("key")
+        .expect("Failed to retrieve a message")
+        .value()
+        .expect("Failed to retrieve a value of a message");
+
+    let mut s = String::from("> ");
+    bundle
+        .format_pattern_to_writer(value, Some(&args), &mut errors, &mut s)
+        .expect("Failed to write");
+    assert_eq!(s, "> Hello Jane!");
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    bundle
+        .format_pattern_to_io_writer(value, Some(&args), &mut errors, &mut cursor)
+        .expect("Failed to write");
+    assert_eq!(cursor.into_inner(), b"Hello Jane!");
+
+    let mut full = [0u8; 4];
+    let err = bundle
+        .format_pattern_to_io_writer(value, Some(&args), &mut errors, &mut &mut full[..])
+        .expect_err("The buffer is too small");
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn boolean_selector() {
+    let res = FluentResource::try_new(
+        r#"
+key = { $enabled ->
+    [true] Yes
+   *[false] No
+}
+"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).expect("Failed to add a resource");
+
+    let value = bundle
+        .get_message("key")
+        .expect("Failed to retrieve a message")
+        .value()
+        .expect("Failed to retrieve a value of a message");
+
+    let mut errors = vec![];
+    let mut args = FluentArgs::new();
+
+    args.set("enabled", true);
+    assert_eq!(
+        bundle.format_pattern(value, Some(&args), &mut errors),
+        "Yes"
+    );
+
+    args.set("enabled", false);
+    assert_eq!(bundle.format_pattern(value, Some(&args), &mut errors), "No");
+
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn new_from_locale_iterator() {
+    let bundle: FluentBundle<FluentResource> = FluentBundle::new([locale!("de-AT"), locale!("de")]);
+    assert_eq!(bundle.locales, [locale!("de-AT"), locale!("de")]);
+
+    let bundle: FluentBundle<FluentResource> =
+        FluentBundle::new(["en-US", "en"].iter().map(|locale| locale.parse().unwrap()));
+    assert_eq!(bundle.locales, [locale!("en-US"), locale!("en")]);
+
+    let bundle: fluent_bundle_for_carbide::concurrent::FluentBundle<FluentResource> =
+        fluent_bundle_for_carbide::concurrent::FluentBundle::new_concurrent(Some(locale!("pl")));
+    assert_eq!(bundle.locales, [locale!("pl")]);
+}
+
+#[test]
+fn merge_bundles() {
+    let core = FluentResource::try_new(
+        "-brand = Fluent\nhello = Hello from { -brand }\nbye = Bye\nUPPER = Not a function"
+            .to_string(),
+    )
+    .unwrap();
+    let app = FluentResource::try_new(
+        "-brand = App\nwelcome = Welcome to { -brand }\nSHOUT = Shout".to_string(),
+    )
+    .unwrap();
+
+    let mut base = FluentBundle::new(vec![locale!("en-US")]);
+    base.set_use_isolating(false);
+    base.add_resource(core).unwrap();
+    base.add_function("SHOUT", |_, _| Ok("base".into()))
+        .unwrap();
+    base.add_function("TWICE", |_, _| Ok("base".into()))
+        .unwrap();
+
+    let mut overlay = FluentBundle::new(vec![locale!("de")]);
+    overlay.add_resource(app).unwrap();
+    overlay
+        .add_function("TWICE", |_, _| Ok("overlay".into()))
+        .unwrap();
+    overlay
+        .add_function("UPPER", |_, _| Ok("overlay".into()))
+        .unwrap();
+
+    let (bundle, errors) = FluentBundle::merge(base, overlay);
+
+    let mut ids = errors
+        .iter()
+        .map(|error| match error {
+            FluentError::Overriding { id, .. } => id.as_str(),
+            error => panic!("Unexpected error {:?}", error),
+        })
+        .collect::<Vec<_>>();
+    ids.sort_unstable();
+    assert_eq!(ids, ["SHOUT", "UPPER"]);
+
+    assert_eq!(bundle.locales, [locale!("en-US")]);
+
+    let mut errors = vec![];
+    assert_eq!(
+        bundle.format_value("hello", None, &mut errors).as_deref(),
+        Some("Hello from App")
+    );
+    assert_eq!(
+        bundle.format_value("welcome", None, &mut errors).as_deref(),
+        Some("Welcome to App")
+    );
+    assert_eq!(
+        bundle.format_value("bye", None, &mut errors).as_deref(),
+        Some("Bye")
+    );
+    assert!(errors.is_empty());
+
+    // Functions of the overlay replace the ones of the base, but not messages.
+    assert!(bundle.has_function("SHOUT"));
+    assert!(!bundle.has_message("SHOUT"));
+    assert!(bundle.has_message("UPPER"));
+    let res = FluentResource::try_new("twice = { TWICE() }".to_string()).unwrap();
+    let mut bundle = bundle;
+    bundle.add_resource(res).unwrap();
+    assert_eq!(
+        bundle.format_value("twice", None, &mut errors).as_deref(),
+        Some("overlay")
+    );
+}
+
+#[test]
+fn has_message_and_term() {
+    let res = FluentResource::try_new(
+        r#"
+-brand = Fluent
+hello = Hello from { -brand }
+"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    assert!(!bundle.has_message("hello"));
+    assert!(!bundle.has_term("brand"));
+
+    bundle.add_resource(&res).expect("Failed to add a resource");
+
+    assert!(bundle.has_message("hello"));
+    assert!(!bundle.has_message("brand"));
+    assert!(!bundle.has_message("missing"));
+
+    assert!(bundle.has_term("brand"));
+    assert!(!bundle.has_term("hello"));
+    assert!(!bundle.has_term("missing"));
+}
+
+#[test]
+fn get_term() {
+    let res = FluentResource::try_new(
+        r#"
+-brand = Fluent
+    .gender = neuter
+hello = Hello from { -brand }
+"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(&res).expect("Failed to add a resource");
+
+    let term = bundle.get_term("brand").expect("The term exists");
+    assert_eq!(term.id(), "brand");
+
+    let mut errors = vec![];
+    assert_eq!(
+        bundle.format_pattern(term.value(), None, &mut errors),
+        "Fluent"
+    );
+
+    let gender = term.get_attribute("gender").expect("The attribute exists");
+    assert_eq!(
+        bundle.format_pattern(gender.value(), None, &mut errors),
+        "neuter"
+    );
+    assert!(term.get_attribute("missing").is_none());
+    assert_eq!(term.attributes().count(), 1);
+    assert!(errors.is_empty());
+
+    assert!(bundle.get_term("hello").is_none());
+    assert!(bundle.get_term("-brand").is_none());
+}
+
+#[test]
+fn entry_ids() {
+    let res = FluentResource::try_new(
+        r#"
+-brand = Fluent
+hello = Hello from { -brand }
+goodbye = Bye
+"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).expect("Failed to add a resource");
+    bundle
+        .add_function("CUSTOM", |_, _| Ok("custom".into()))
+        .expect("Failed to add a function");
+
+    let mut message_ids = bundle.message_ids().collect::<Vec<_>>();
+    message_ids.sort_unstable();
+    assert_eq!(message_ids, ["goodbye", "hello"]);
+
+    assert_eq!(bundle.term_ids().collect::<Vec<_>>(), ["brand"]);
+
+    let function_names = bundle.function_names().collect::<Vec<_>>();
+    assert!(function_names.contains(&"CUSTOM"));
+    assert!(function_names.contains(&"NUMBER"));
+    assert!(!function_names.contains(&"hello"));
+}
+
+#[test]
+fn bundle_builder() {
+    let res = FluentResource::try_new("hello = Hello, { $name }!".to_string())
+        .expect("Failed to parse an FTL string.");
+
+    let bundle = FluentBundleBuilder::new()
+        .with_locales(vec![locale!("en-US")])
+        .with_use_isolating(false)
+        .with_resource(&res)
+        .expect("Failed to add FTL resources to the bundle.")
+        .with_function("UPPER", |positional, _| match positional {
+            [FluentValue::String(s)] => Ok(s.to_uppercase().into()),
+            _ => Ok(FluentValue::Error),
+        })
+        .expect("Failed to add a function to the bundle.")
+        .build()
+        .expect("Failed to build the bundle.");
+
+    let mut args = FluentArgs::new();
+    args.set("name", "John");
+    let msg = bundle.get_message("hello").expect("Message doesn't exist.");
+    let mut errors = vec![];
+    let value = bundle.format_pattern(msg.value().unwrap(), Some(&args), &mut errors);
+    assert_eq!(value, "Hello, John!");
+    assert!(errors.is_empty());
+
+    assert!(FluentBundleBuilder::<&FluentResource, _>::new()
+        .with_function("NUMBER", |_, _| Ok(FluentValue::None))
+        .is_err());
+
+    assert_eq!(
+        FluentBundleBuilder::<FluentResource, _>::new()
+            .build()
+            .err(),
+        Some(FluentError::MissingLocale)
+    );
+
+    let concurrent = FluentBundleBuilder::new_concurrent()
+        .with_locales(vec![locale!("en-US")])
+        .with_resource(res)
+        .expect("Failed to add FTL resources to the bundle.")
+        .build()
+        .expect("Failed to build the bundle.");
+    assert!(concurrent.has_message("hello"));
+}
+
+#[test]
+fn format_value_and_attribute() {
+    let res = FluentResource::try_new(
+        "hello = Hello, { $name }!\n    .title = Greeting\nempty =\n    .title = Empty".to_string(),
+    )
+    .unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(&res).expect("Failed to add a resource");
+
+    let mut args = FluentArgs::new();
+    args.set("name", "John");
+
+    let mut errors = vec![];
+    assert_eq!(
+        bundle
+            .format_value("hello", Some(&args), &mut errors)
+            .as_deref(),
+        Some("Hello, John!")
+    );
+    assert_eq!(bundle.format_value("empty", None, &mut errors), None);
+    assert_eq!(bundle.format_value("missing", None, &mut errors), None);
+
+    assert_eq!(
+        bundle
+            .format_attribute("hello", "title", None, &mut errors)
+            .as_deref(),
+        Some("Greeting")
+    );
+    assert_eq!(
+        bundle.format_value_or_default("hello", "Hi", Some(&args), &mut errors),
+        "Hello, John!"
+    );
+    assert_eq!(
+        bundle.format_value_or_default("missing", "Hi", None, &mut errors),
+        "Hi"
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn format_missing_attribute() {
+    use fluent_bundle_for_carbide::resolver::errors::ReferenceKind;
+    use fluent_bundle_for_carbide::resolver::ResolverError;
+
+    let res = FluentResource::try_new("hello = Hello!\n    .title = Greeting".to_string()).unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).expect("Failed to add a resource");
+
+    let reference = |id: &str, attribute: Option<&str>| FluentError::ResolverError {
+        id: Some(id.to_string()),
+        error: ResolverError::Reference(ReferenceKind::Message {
+            id: id.to_string(),
+            attribute: attribute.map(str::to_string),
+        }),
+    };
+
+    let mut errors = vec![];
+    assert_eq!(
+        bundle.format_attribute("hello", "missing", None, &mut errors),
+        None
+    );
+    assert_eq!(errors, [reference("hello", Some("missing"))]);
+
+    let mut errors = vec![];
+    assert_eq!(
+        bundle.format_attribute("missing", "title", None, &mut errors),
+        None
+    );
+    assert_eq!(errors, [reference("missing", None)]);
+
+    let mut concurrent =
+        fluent_bundle_for_carbide::concurrent::FluentBundle::new_concurrent(vec![locale!("en-US")]);
+    concurrent
+        .add_resource(&res)
+        .expect("Failed to add a resource");
+    let mut errors = vec![];
+    assert_eq!(
+        concurrent
+            .format_attribute("hello", "title", None, &mut errors)
+            .as_deref(),
+        Some("Greeting")
+    );
+    assert_eq!(
+        concurrent.format_attribute("hello", "missing", None, &mut errors),
+        None
+    );
+    assert_eq!(errors, [reference("hello", Some("missing"))]);
+}
+
+#[test]
+fn error_context() {
+    use fluent_bundle_for_carbide::resolver::ResolverError;
+    use std::error::Error;
+
+    let res = FluentResource::try_new(
+        "hello = Hello, { $name }!\nwelcome = { hello }\n    .title = { missing }".to_string(),
+    )
+    .unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).unwrap();
+
+    // Errors are reported for the message that contains the failing reference.
+    let mut errors = vec![];
+    bundle.format_value("welcome", None, &mut errors);
+    assert!(matches!(
+        errors.as_slice(),
+        [FluentError::ResolverError { id: Some(id), error: ResolverError::Reference(_) }] if id == "hello"
+    ));
+    assert_eq!(
+        errors[0].to_string(),
+        "Resolver error in \"hello\": Unknown variable: $name"
+    );
+    assert!(errors[0].source().is_some());
+
+    let mut errors = vec![];
+    bundle.format_attribute("welcome", "title", None, &mut errors);
+    assert!(matches!(
+        errors.as_slice(),
+        [FluentError::ResolverError { id: Some(id), .. }] if id == "welcome"
+    ));
+
+    let source = "hello = Hello\n  broken = {";
+    let (_, errors) = FluentResource::try_new(source.to_string()).unwrap_err();
+    assert!(matches!(
+        errors[0],
+        FluentError::ParserError {
+            line: 2,
+            column: 13,
+            ..
+        }
+    ));
+    assert!(errors[0].source().is_some());
+    assert_eq!(
+        errors[0].display_with_source(source),
+        "Parser error at 2:13: Expected an inline expression\n  |\n2 |   broken = {\n  |             ^"
+    );
+}
+
+#[test]
+fn format_pattern_with_warnings() {
+    use fluent_bundle_for_carbide::resolver::errors::ReferenceKind;
+    use fluent_bundle_for_carbide::FluentWarning;
+
+    let res = FluentResource::try_new(
+        "hello = Hello, { $name }! { -brand } { $count ->\n    [one] { $unit }\n   *[other] many\n}".to_string(),
+    )
+    .unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(&res).unwrap();
+
+    let mut args = FluentArgs::new();
+    args.set("name", "John");
+    args.set("count", 5);
+    args.set("unit", "kg");
+    args.set("extra", "unused");
+
+    let pattern = bundle.get_message("hello").unwrap().value().unwrap();
+    let mut errors = vec![];
+    let mut warnings = vec![];
+    let value =
+        bundle.format_pattern_with_warnings(pattern, Some(&args), &mut errors, &mut warnings);
+    assert_eq!(value, "Hello, John! {-brand} many");
+    assert!(errors.is_empty());
+    assert_eq!(
+        warnings,
+        vec![
+            FluentWarning::MissingReference {
+                id: Some("hello".to_string()),
+                reference: ReferenceKind::Term {
+                    id: "brand".to_string(),
+                    attribute: None
+                },
+            },
+            FluentWarning::UnusedArgument("extra".to_string()),
+            FluentWarning::UnusedArgument("unit".to_string()),
+        ]
+    );
+
+    // Without a warnings collector the missing reference is an error, like before.
+    let mut errors = vec![];
+    bundle.format_pattern(pattern, Some(&args), &mut errors);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn cyclic_reference() {
+    let res = FluentResource::try_new(
+        "self = { self }\na = { b }\nb = { c }\nc = { a }\nattr = { attr.title }\n    .title = { attr }\nterm = { -t }\n-t = { -u }\n-u = { -t }"
+            .to_string(),
+    )
+    .unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(&res).unwrap();
+
+    let cases = [
+        ("self", "{self}", vec!["self", "self"]),
+        ("a", "{a}", vec!["a", "b", "c", "a"]),
+        ("attr", "{attr}", vec!["attr", "attr.title", "attr"]),
+        ("term", "{-t}", vec!["-t", "-u", "-t"]),
+    ];
+
+    for (id, expected, ids) in cases {
+        let mut errors = vec![];
+        let value = bundle.format_value(id, None, &mut errors);
+        assert_eq!(value.as_deref(), Some(expected));
+        assert_eq!(
+            errors,
+            vec![FluentError::CyclicReference {
+                ids: ids.into_iter().map(String::from).collect(),
+            }]
+        );
+    }
+}
+
+#[test]
+fn detect_circular_references() {
+    let res = FluentResource::try_new(
+        "self = { self }\na = { b }\nb = { c }\nc = { a }\nattr = { attr.title }\n    .title = { attr }\nterm = { -t }\n-t = { -u }\n-u = { -t }\nselect = { $n ->\n    [one] { select }\n   *[other] Other\n}\nok = { missing } { a } { -t }"
+            .to_string(),
+    )
+    .unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).unwrap();
+
+    let cycles = |ids: &[&[&str]]| -> Vec<FluentError> {
+        ids.iter()
+            .map(|ids| FluentError::CyclicReference {
+                ids: ids.iter().map(|id| id.to_string()).collect(),
+            })
+            .collect()
+    };
+
+    assert_eq!(
+        bundle.detect_circular_references(),
+        cycles(&[
+            &["-t", "-u", "-t"],
+            &["a", "b", "c", "a"],
+            &["attr", "attr.title", "attr"],
+            &["select", "select"],
+            &["self", "self"],
+        ])
+    );
+
+    let res =
+        FluentResource::try_new("hello = Hello { -brand }\n-brand = Fluent".to_string()).unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    assert_eq!(bundle.add_resource_checked(&res), Ok(()));
+    assert!(bundle.detect_circular_references().is_empty());
+
+    let res = FluentResource::try_new("-brand = { hello }".to_string()).unwrap();
+    assert!(matches!(
+        &bundle.add_resource_checked(&res).unwrap_err()[..],
+        [FluentError::Overriding { id, .. }] if id == "brand"
+    ));
+
+    let res = FluentResource::try_new("-brand = { hello }".to_string()).unwrap();
+    bundle.add_resource_overriding(&res);
+    assert_eq!(
+        bundle.detect_circular_references(),
+        cycles(&[&["-brand", "hello", "-brand"]])
+    );
+}
+
+#[test]
+fn format_to_parts() {
+    use fluent_bundle_for_carbide::types::FluentDateTime;
+    use fluent_bundle_for_carbide::{FluentPart, NumberPartType};
+
+    let res = FluentResource::try_new(
+        "total = { $count ->\n    [one] One item\n   *[other] { $count } items\n} for { NUMBER($price, style: \"currency\", currency: \"EUR\") }\ndate = Sent { $date }\nliteral = { -1.5 }".to_string(),
+    )
+    .unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).unwrap();
+
+    let mut args = FluentArgs::new();
+    args.set("count", 12345);
+    args.set("price", -0.5);
+    args.set(
+        "date",
+        FluentDateTime::from(
+            chrono::NaiveDate::from_ymd_opt(2024, 3, 7)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        ),
+    );
+
+    let number = |value: &str, part_type| FluentPart::Number {
+        value: value.to_string().into(),
+        part_type,
+    };
+
+    for id in ["total", "date", "literal"] {
+        let pattern = bundle.get_message(id).unwrap().value().unwrap();
+        let mut errors = vec![];
+        let parts = bundle.format_to_parts(pattern, Some(&args), &mut errors);
+        assert!(errors.is_empty());
+        assert_eq!(
+            parts.iter().map(FluentPart::value).collect::<String>(),
+            bundle.format_pattern(pattern, Some(&args), &mut errors),
+        );
+
+        match id {
+            "total" => assert_eq!(
+                parts,
+                vec![
+                    FluentPart::Literal("\u{2068}\u{2068}".into()),
+                    number("12", NumberPartType::Integer),
+                    number(",", NumberPartType::Group),
+                    number("345", NumberPartType::Integer),
+                    FluentPart::Literal("\u{2069} items\u{2069} for \u{2068}".into()),
+                    number("-", NumberPartType::MinusSign),
+                    number("€", NumberPartType::Currency),
+                    number("0", NumberPartType::Integer),
+                    number(".", NumberPartType::Decimal),
+                    number("50", NumberPartType::Fraction),
+                    FluentPart::Literal("\u{2069}".into()),
+                ]
+            ),
+            "date" => {
+                assert_eq!(parts[0], FluentPart::Literal("Sent \u{2068}".into()));
+                assert!(parts
+                    .iter()
+                    .any(|part| matches!(part, FluentPart::DateTime { .. })));
+            }
+            _ => assert_eq!(
+                parts,
+                vec![
+                    number("-", NumberPartType::MinusSign),
+                    number("1", NumberPartType::Integer),
+                    number(".", NumberPartType::Decimal),
+                    number("5", NumberPartType::Fraction),
+                ]
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn format_pattern_spans() {
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata};
+
+    /// Collects the fields of all the spans as `span:field=value`.
+    #[derive(Clone, Default)]
+    struct Collector {
+        names: Arc<Mutex<Vec<&'static str>>>,
+        fields: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct FieldVisitor<'a>(&'static str, &'a mut Vec<String>);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.1
+                .push(format!("{}:{}={:?}", self.0, field.name(), value));
+        }
+    }
+
+    impl tracing::Subscriber for Collector {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut names = self.names.lock().unwrap();
+            names.push(span.metadata().name());
+            span.record(&mut FieldVisitor(
+                span.metadata().name(),
+                &mut self.fields.lock().unwrap(),
+            ));
+            Id::from_u64(names.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let name = self.names.lock().unwrap()[span.into_u64() as usize - 1];
+            values.record(&mut FieldVisitor(name, &mut self.fields.lock().unwrap()));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    let res = FluentResource::try_new("hello = Hello { NUMBER(5) }!".to_string()).unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).unwrap();
+
+    let collector = Collector::default();
+    tracing::subscriber::with_default(collector.clone(), || {
+        let mut errors = vec![];
+        let pattern = bundle
+            .get_message("hello")
+            .and_then(|msg| msg.value())
+            .unwrap();
+        bundle.format_pattern(pattern, None, &mut errors);
+        assert!(errors.is_empty());
+    });
+
+    let fields = collector.fields.lock().unwrap();
+    assert!(fields.contains(&"format_pattern:locale=en-US".to_string()));
+    assert!(fields.contains(&"format_pattern:message.id=\"hello\"".to_string()));
+    assert!(fields.contains(&"format_number:locale=en-US".to_string()));
+}
+
+#[test]
+fn max_recursion_depth() {
+    let source = (0..70)
+        .map(|i| format!("msg-{} = {{ msg-{} }}\n", i, i + 1))
+        .chain(std::iter::once("msg-70 = End\n".to_string()))
+        .collect::<String>();
+    let res = FluentResource::try_new(source).unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).unwrap();
+
+    let pattern = bundle
+        .get_message("msg-0")
+        .and_then(|msg| msg.value())
+        .unwrap();
+    let mut errors = vec![];
+    let value = bundle.format_pattern(pattern, None, &mut errors);
+    assert_eq!(value, "{msg-65}");
+    assert_eq!(
+        errors,
+        vec![FluentError::RecursionDepthExceeded {
+            message_id: "msg-65".to_string(),
+            depth: 65,
+        }]
+    );
+
+    bundle.set_max_recursion_depth(70);
+    let pattern = bundle
+        .get_message("msg-0")
+        .and_then(|msg| msg.value())
+        .unwrap();
+    let mut errors = vec![];
+    let value = bundle.format_pattern(pattern, None, &mut errors);
+    assert_eq!(value, "End");
+    assert!(errors.is_empty());
+
+    let bundle: FluentBundle<FluentResource> = FluentBundleBuilder::new()
+        .with_locales(vec![locale!("en-US")])
+        .with_max_recursion_depth(2)
+        .with_resource(
+            FluentResource::try_new("a = { b }\nb = { c }\nc = { d }\nd = D".to_string()).unwrap(),
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+    let pattern = bundle.get_message("a").and_then(|msg| msg.value()).unwrap();
+    let mut errors = vec![];
+    assert_eq!(bundle.format_pattern(pattern, None, &mut errors), "{d}");
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn clone_with_resources() {
+    let core =
+        FluentResource::try_new("hello = Hi!\nbye = { SHOUT(\"Bye\") }".to_string()).unwrap();
+    let request =
+        FluentResource::try_new("hello = Your order was placed!\nconfirm = Confirmed".to_string())
+            .unwrap();
+
+    let mut base = FluentBundle::new(vec![locale!("en-US")]);
+    base.set_use_isolating(false);
+    base.add_resource(&core).unwrap();
+    base.add_function("SHOUT", |args, _| match &args[0] {
+        FluentValue::String(s) => Ok(FluentValue::from(format!("{}!", s))),
+        _ => Ok(FluentValue::Error),
+    })
+    .unwrap();
+
+    let bundle = base.clone_with_resources(vec![&request]).ok().unwrap();
+    assert_eq!(bundle.locales, base.locales);
+
+    let mut errors = vec![];
+    assert_eq!(
+        bundle.format_value("hello", None, &mut errors).as_deref(),
+        Some("Your order was placed!")
+    );
+    assert_eq!(
+        bundle.format_value("confirm", None, &mut errors).as_deref(),
+        Some("Confirmed")
+    );
+    assert_eq!(
+        bundle.format_value("bye", None, &mut errors).as_deref(),
+        Some("Bye!")
+    );
+    assert!(errors.is_empty());
+
+    // The base bundle is not changed.
+    assert_eq!(
+        base.format_value("hello", None, &mut errors).as_deref(),
+        Some("Hi!")
+    );
+    assert!(!base.has_message("confirm"));
+
+    // A collision with a function is reported, but the rest of the resource is added.
+    let conflict = FluentResource::try_new("SHOUT = Message\nextra = Extra".to_string()).unwrap();
+    let (bundle, errors) = base.clone_with_resources(vec![&conflict]).err().unwrap();
+    assert!(matches!(&errors[..], [FluentError::Overriding { id, .. }] if id == "SHOUT"));
+    assert!(bundle.has_function("SHOUT"));
+    assert!(!bundle.has_message("SHOUT"));
+    let mut errors = vec![];
+    assert_eq!(
+        bundle.format_value("extra", None, &mut errors).as_deref(),
+        Some("Extra")
+    );
+    assert_eq!(
+        bundle.format_value("bye", None, &mut errors).as_deref(),
+        Some("Bye!")
+    );
+}
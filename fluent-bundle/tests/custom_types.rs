@@ -23,7 +23,10 @@ fn fluent_custom_type() {
         fn duplicate(&self) -> Box<dyn FluentType + Send> {
             Box::new(DateTime { epoch: self.epoch })
         }
-        fn as_string(&self, _: &intl_memoizer_for_carbide::IntlLangMemoizer) -> std::borrow::Cow<'static, str> {
+        fn as_string(
+            &self,
+            _: &intl_memoizer_for_carbide::IntlLangMemoizer,
+        ) -> std::borrow::Cow<'static, str> {
             format!("{}", self.epoch).into()
         }
         fn as_string_threadsafe(
@@ -120,7 +123,10 @@ fn fluent_date_time_builtin() {
         fn duplicate(&self) -> Box<dyn FluentType + Send> {
             Box::new(DateTime::new(self.epoch, DateTimeOptions::default()))
         }
-        fn as_string(&self, _: &intl_memoizer_for_carbide::IntlLangMemoizer) -> std::borrow::Cow<'static, str> {
+        fn as_string(
+            &self,
+            _: &intl_memoizer_for_carbide::IntlLangMemoizer,
+        ) -> std::borrow::Cow<'static, str> {
             format!("2020-01-20 {}:00", self.epoch).into()
         }
         fn as_string_threadsafe(
@@ -146,21 +152,23 @@ key-ref = Hello { DATETIME($date, dateStyle: "full") } World
     bundle.set_use_isolating(false);
 
     bundle
-        .add_function("DATETIME", |positional, named| match positional.get(0) {
-            Some(FluentValue::Custom(custom)) => {
-                if let Some(that) = custom.as_ref().as_any().downcast_ref::<DateTime>() {
-                    let mut dt = that.clone();
-                    dt.options.merge(named);
-                    FluentValue::Custom(Box::new(dt))
-                } else {
-                    FluentValue::Error
+        .add_function_override("DATETIME", |positional, named| {
+            Ok(match positional.get(0) {
+                Some(FluentValue::Custom(custom)) => {
+                    if let Some(that) = custom.as_ref().as_any().downcast_ref::<DateTime>() {
+                        let mut dt = that.clone();
+                        dt.options.merge(named);
+                        FluentValue::Custom(Box::new(dt))
+                    } else {
+                        FluentValue::Error
+                    }
                 }
-            }
-            Some(FluentValue::Number(num)) => {
-                let num = num.value as usize;
-                FluentValue::Custom(Box::new(DateTime::new(num, named.into())))
-            }
-            _ => FluentValue::Error,
+                Some(FluentValue::Number(num)) => {
+                    let num = num.value as usize;
+                    FluentValue::Custom(Box::new(DateTime::new(num, named.into())))
+                }
+                _ => FluentValue::Error,
+            })
         })
         .unwrap();
 
@@ -182,49 +190,49 @@ key-ref = Hello { DATETIME($date, dateStyle: "full") } World
 
 #[test]
 fn fluent_custom_number_format() {
-//     fn custom_formatter<M: MemoizerKind>(num: &FluentValue, _intls: &M) -> Option<String> {
-//         match num {
-//             FluentValue::Number(_) => Some("CUSTOM".into()),
-//             _ => None,
-//         }
-//     }
-//
-//     let res = FluentResource::try_new(
-//         r#"
-// key-num-implicit = Hello { 5.000 } World
-// key-num-explicit = Hello { NUMBER(5, minimumFractionDigits: 2) } World
-//     "#
-//         .into(),
-//     )
-//     .unwrap();
-//     let mut bundle = FluentBundle::default();
-//     bundle.add_resource(res).unwrap();
-//     bundle.set_use_isolating(false);
-//
-//     bundle
-//         .add_function("NUMBER", |positional, named| match positional.get(0) {
-//             Some(FluentValue::Number(n)) => {
-//                 let mut num = n.clone();
-//                 num.options.merge(named);
-//                 FluentValue::Number(num)
-//             }
-//             _ => FluentValue::Error,
-//         })
-//         .unwrap();
-//
-//     let mut errors = vec![];
-//
-//     let msg = bundle.get_message("key-num-explicit").unwrap();
-//     let val = bundle.format_pattern(msg.value().unwrap(), None, &mut errors);
-//     assert_eq!(val, "Hello 5.00 World");
-//
-//     let msg = bundle.get_message("key-num-implicit").unwrap();
-//     let val = bundle.format_pattern(msg.value().unwrap(), None, &mut errors);
-//     assert_eq!(val, "Hello 5.000 World");
-//
-//     bundle.set_formatter(Some(custom_formatter));
-//
-//     let msg = bundle.get_message("key-num-implicit").unwrap();
-//     let val = bundle.format_pattern(msg.value().unwrap(), None, &mut errors);
-//     assert_eq!(val, "Hello CUSTOM World");
+    //     fn custom_formatter<M: MemoizerKind>(num: &FluentValue, _intls: &M) -> Option<String> {
+    //         match num {
+    //             FluentValue::Number(_) => Some("CUSTOM".into()),
+    //             _ => None,
+    //         }
+    //     }
+    //
+    //     let res = FluentResource::try_new(
+    //         r#"
+    // key-num-implicit = Hello { 5.000 } World
+    // key-num-explicit = Hello { NUMBER(5, minimumFractionDigits: 2) } World
+    //     "#
+    //         .into(),
+    //     )
+    //     .unwrap();
+    //     let mut bundle = FluentBundle::default();
+    //     bundle.add_resource(res).unwrap();
+    //     bundle.set_use_isolating(false);
+    //
+    //     bundle
+    //         .add_function("NUMBER", |positional, named| match positional.get(0) {
+    //             Some(FluentValue::Number(n)) => {
+    //                 let mut num = n.clone();
+    //                 num.options.merge(named);
+    //                 FluentValue::Number(num)
+    //             }
+    //             _ => FluentValue::Error,
+    //         })
+    //         .unwrap();
+    //
+    //     let mut errors = vec![];
+    //
+    //     let msg = bundle.get_message("key-num-explicit").unwrap();
+    //     let val = bundle.format_pattern(msg.value().unwrap(), None, &mut errors);
+    //     assert_eq!(val, "Hello 5.00 World");
+    //
+    //     let msg = bundle.get_message("key-num-implicit").unwrap();
+    //     let val = bundle.format_pattern(msg.value().unwrap(), None, &mut errors);
+    //     assert_eq!(val, "Hello 5.000 World");
+    //
+    //     bundle.set_formatter(Some(custom_formatter));
+    //
+    //     let msg = bundle.get_message("key-num-implicit").unwrap();
+    //     let val = bundle.format_pattern(msg.value().unwrap(), None, &mut errors);
+    //     assert_eq!(val, "Hello CUSTOM World");
 }
@@ -0,0 +1,403 @@
+use chrono::{DateTime, NaiveDate};
+use fluent_bundle_for_carbide::types::FluentDateTime;
+use fluent_bundle_for_carbide::FluentArgs;
+use icu::locid::locale;
+
+mod helpers;
+use helpers::format_message;
+
+#[test]
+fn datetime_naive() {
+    let source = r#"
+short = { DATETIME($date, dateStyle: "short", timeStyle: "short") }
+zoned = { DATETIME($date, dateStyle: "short", timeStyle: "long", timezoneStyle: "gmt") }
+"#;
+
+    let naive = NaiveDate::from_ymd_opt(2024, 3, 1)
+        .unwrap()
+        .and_hms_opt(14, 30, 0)
+        .unwrap();
+
+    let dt = FluentDateTime::from(naive);
+    assert!(dt.naive);
+    assert!(dt.validate().is_ok());
+
+    let mut args = FluentArgs::new();
+    args.set("date", naive);
+    assert_eq!(
+        format_message(locale!("en"), source, "short", &args),
+        "3/1/24, 2:30\u{202f}PM"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "zoned", &args),
+        "DATETIME()"
+    );
+
+    let zoned = DateTime::parse_from_rfc3339("2024-03-01T14:30:00+01:00").unwrap();
+    args.set("date", FluentDateTime::from(zoned));
+    assert_eq!(
+        format_message(locale!("en"), source, "short", &args),
+        "3/1/24, 2:30\u{202f}PM"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "zoned", &args),
+        "3/1/24, 2:30:00\u{202f}PM GMT+01:00"
+    );
+}
+
+#[test]
+fn datetime_hour_cycle() {
+    let source = r#"
+default = { DATETIME($t, dateStyle: "hidden", timeStyle: "short") }
+h23 = { DATETIME($t, dateStyle: "hidden", timeStyle: "short", hourCycle: "h23") }
+h12 = { DATETIME($t, dateStyle: "hidden", timeStyle: "short", hourCycle: "h12") }
+with-date = { DATETIME($t, dateStyle: "short", timeStyle: "short", hourCycle: "h23") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set(
+        "t",
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(14, 30, 0)
+            .unwrap(),
+    );
+
+    assert_eq!(
+        format_message(locale!("en-US"), source, "default", &args),
+        "2:30\u{202f}PM"
+    );
+    assert_eq!(
+        format_message(locale!("en-US"), source, "h23", &args),
+        "14:30"
+    );
+    assert_eq!(
+        format_message(locale!("en-US"), source, "with-date", &args),
+        "3/1/24, 14:30"
+    );
+    assert_eq!(
+        format_message(locale!("de"), source, "h12", &args),
+        "2:30\u{202f}PM"
+    );
+}
+
+#[test]
+fn datetime_calendar() {
+    let source = r#"
+gregorian = { DATETIME($d, dateStyle: "long", timeStyle: "hidden") }
+islamic = { DATETIME($d, dateStyle: "long", timeStyle: "hidden", calendar: "islamic") }
+japanese = { DATETIME($d, dateStyle: "long", timeStyle: "hidden", calendar: "japanese") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set(
+        "d",
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+    );
+
+    assert_eq!(
+        format_message(locale!("en"), source, "gregorian", &args),
+        "March 1, 2024"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "islamic", &args),
+        "Shaʻban 20, 1445 AH"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "japanese", &args),
+        "March 1, 6 Reiwa"
+    );
+}
+
+#[test]
+fn datetime_as_string() {
+    let mut date = FluentDateTime::from(
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+    );
+    date.options.date_style = "long".into();
+    date.options.time_style = "hidden".into();
+
+    assert_eq!(
+        date.as_string(&locale!("en")).as_deref(),
+        Ok("March 1, 2024")
+    );
+}
+
+#[test]
+fn datetime_hash() {
+    use std::collections::HashSet;
+
+    let utc = DateTime::parse_from_rfc3339("2024-03-01T12:00:00+00:00").unwrap();
+    let cet = DateTime::parse_from_rfc3339("2024-03-01T13:00:00+01:00").unwrap();
+    let later = DateTime::parse_from_rfc3339("2024-03-01T12:00:01+00:00").unwrap();
+
+    let mut set = HashSet::new();
+    assert!(set.insert(FluentDateTime::from(utc)));
+    // The same instant in another offset is equal.
+    assert!(!set.insert(FluentDateTime::from(cet)));
+    assert!(set.insert(FluentDateTime::from(later)));
+
+    let mut long = FluentDateTime::from(utc);
+    long.options.date_style = "long".into();
+    assert!(set.insert(long.clone()));
+    assert!(set.contains(&long));
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn datetime_from_timestamp() {
+    let dt = FluentDateTime::from(1_714_000_000i64);
+    assert!(!dt.naive);
+    assert_eq!(dt.value.to_rfc3339(), "2024-04-24T23:06:40+00:00");
+
+    let dt = FluentDateTime::from((1_714_000_000i64, 7200));
+    assert_eq!(dt.value.to_rfc3339(), "2024-04-25T01:06:40+02:00");
+    assert_eq!(dt.value.timestamp(), 1_714_000_000);
+
+    // Out of range offsets are ignored, and timestamps are clamped.
+    assert_eq!(
+        FluentDateTime::from((0i64, 86_400)).value.to_rfc3339(),
+        "1970-01-01T00:00:00+00:00"
+    );
+    assert_eq!(
+        FluentDateTime::from(i64::MAX).value,
+        DateTime::<chrono::Utc>::MAX_UTC
+    );
+
+    let source = r#"
+created = { DATETIME($created_at, dateStyle: "long", timeStyle: "short") }
+"#;
+    let mut args = FluentArgs::new();
+    args.set("created_at", 1_714_000_000i64);
+    assert_eq!(
+        format_message(locale!("en"), source, "created", &args),
+        "April 24, 2024, 11:06\u{202f}PM"
+    );
+}
+
+#[test]
+fn datetime_builder_methods() {
+    use chrono::{FixedOffset, TimeZone};
+    use fluent_bundle_for_carbide::types::{
+        FluentDateStyle, FluentDateTimeOptions, FluentTimeStyle,
+    };
+
+    let date = FluentDateTime::from(
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+    )
+    .with_options(FluentDateTimeOptions {
+        date_style: FluentDateStyle::Long,
+        ..Default::default()
+    })
+    .map_options(|options| FluentDateTimeOptions {
+        time_style: FluentTimeStyle::Hidden,
+        ..options
+    });
+    assert_eq!(
+        date.as_string(&locale!("en")).as_deref(),
+        Ok("March 1, 2024")
+    );
+    assert!(date.naive);
+
+    let offset = FixedOffset::east_opt(3600).unwrap();
+    let date = date.with_value(offset.with_ymd_and_hms(2025, 12, 24, 18, 0, 0).unwrap());
+    assert_eq!(
+        date.as_string(&locale!("en")).as_deref(),
+        Ok("December 24, 2025")
+    );
+    assert!(!date.naive);
+}
+
+#[test]
+fn datetime_era() {
+    let source = r#"
+long = { DATETIME($d, dateStyle: "long", timeStyle: "hidden", era: "long") }
+short = { DATETIME($d, dateStyle: "long", timeStyle: "hidden", era: "short") }
+with-time = { DATETIME($d, dateStyle: "short", timeStyle: "short", era: "short") }
+japanese = { DATETIME($d, dateStyle: "long", timeStyle: "hidden", calendar: "japanese", era: "long") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set(
+        "d",
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(14, 30, 0)
+            .unwrap(),
+    );
+
+    assert_eq!(
+        format_message(locale!("en"), source, "long", &args),
+        "March 1, 2024 Anno Domini"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "short", &args),
+        "March 1, 2024 AD"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "with-time", &args),
+        "3/1/24 AD, 2:30\u{202f}PM"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "japanese", &args),
+        "March 1, 6 Reiwa"
+    );
+}
+
+#[test]
+fn datetime_fractional_second_digits() {
+    let source = r#"
+one = { DATETIME($t, dateStyle: "hidden", timeStyle: "medium", fractionalSecondDigits: 1) }
+three = { DATETIME($t, dateStyle: "hidden", timeStyle: "medium", fractionalSecondDigits: 3) }
+with-date = { DATETIME($t, dateStyle: "short", timeStyle: "medium", fractionalSecondDigits: 2) }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set(
+        "t",
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_milli_opt(14, 30, 5, 678)
+            .unwrap(),
+    );
+
+    assert_eq!(
+        format_message(locale!("en"), source, "one", &args),
+        "2:30:05.6\u{202f}PM"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "three", &args),
+        "2:30:05.678\u{202f}PM"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "with-date", &args),
+        "3/1/24, 2:30:05.67\u{202f}PM"
+    );
+
+    // The public option is clamped to the digits supported by ICU.
+    let mut dt = FluentDateTime::from(
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_milli_opt(14, 30, 5, 678)
+            .unwrap(),
+    );
+    dt.options.date_style = "hidden".into();
+    dt.options.time_style = "medium".into();
+    dt.options.fractional_second_digits = Some(12);
+    assert_eq!(
+        dt.as_string(&locale!("en")).unwrap(),
+        "2:30:05.678\u{202f}PM"
+    );
+}
+
+#[test]
+fn datetime_weekday() {
+    let source = r#"
+only = { DATETIME($d, dateStyle: "hidden", timeStyle: "hidden", weekday: "long") }
+short = { DATETIME($d, dateStyle: "hidden", timeStyle: "hidden", weekday: "short") }
+with-date = { DATETIME($d, dateStyle: "long", timeStyle: "hidden", weekday: "long") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set(
+        "d",
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(14, 30, 0)
+            .unwrap(),
+    );
+
+    assert_eq!(
+        format_message(locale!("en"), source, "only", &args),
+        "Friday"
+    );
+    assert_eq!(format_message(locale!("en"), source, "short", &args), "Fri");
+    assert_eq!(
+        format_message(locale!("en"), source, "with-date", &args),
+        "Friday, March 1, 2024"
+    );
+}
+
+#[test]
+fn date_and_time() {
+    use chrono::NaiveTime;
+    use fluent_bundle_for_carbide::types::{
+        FluentDate, FluentDateStyle, FluentTime, FluentTimeStyle,
+    };
+
+    let source = r#"
+date = { $date }
+long-date = { DATE($date, dateStyle: "long") }
+date-of-datetime = { DATE($datetime, dateStyle: "short") }
+time = { $time }
+short-time = { TIME($time, timeStyle: "short") }
+time-of-datetime = { TIME($datetime, timeStyle: "short") }
+date-as-datetime = { DATETIME($date, dateStyle: "short", timeStyle: "short") }
+time-as-datetime = { DATETIME($time, timeStyle: "short") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("date", NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+    args.set("time", NaiveTime::from_hms_opt(14, 30, 5).unwrap());
+    args.set(
+        "datetime",
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(14, 30, 0)
+            .unwrap(),
+    );
+
+    let en = locale!("en");
+    assert_eq!(
+        format_message(en.clone(), source, "date", &args),
+        "Mar 1, 2024"
+    );
+    assert_eq!(
+        format_message(en.clone(), source, "long-date", &args),
+        "March 1, 2024"
+    );
+    assert_eq!(
+        format_message(en.clone(), source, "date-of-datetime", &args),
+        "3/1/24"
+    );
+    assert_eq!(
+        format_message(en.clone(), source, "time", &args),
+        "2:30:05\u{202f}PM"
+    );
+    assert_eq!(
+        format_message(en.clone(), source, "short-time", &args),
+        "2:30\u{202f}PM"
+    );
+    assert_eq!(
+        format_message(en.clone(), source, "time-of-datetime", &args),
+        "2:30\u{202f}PM"
+    );
+    assert_eq!(
+        format_message(en.clone(), source, "date-as-datetime", &args),
+        "3/1/24, 12:00\u{202f}AM"
+    );
+    assert_eq!(
+        format_message(en.clone(), source, "time-as-datetime", &args),
+        "2:30\u{202f}PM"
+    );
+
+    let date = FluentDate::new(
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+        FluentDateStyle::Full,
+    );
+    assert_eq!(date.as_string(&en).unwrap(), "Friday, March 1, 2024");
+    let time = FluentTime::new(
+        NaiveTime::from_hms_opt(9, 5, 0).unwrap(),
+        FluentTimeStyle::Short,
+    );
+    assert_eq!(time.as_string(&en).unwrap(), "9:05\u{202f}AM");
+}
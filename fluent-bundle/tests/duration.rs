@@ -0,0 +1,118 @@
+use fluent_bundle_for_carbide::types::{
+    FluentDuration, FluentDurationDisplay, FluentDurationOptions,
+};
+use fluent_bundle_for_carbide::{FluentArgs, FluentBundle, FluentResource};
+use icu::locid::locale;
+use std::time::Duration;
+
+mod helpers;
+use helpers::format_message;
+
+#[test]
+fn duration_builtin() {
+    let source = r#"
+long = { DURATION($elapsed, style: "long") }
+short = { DURATION($elapsed) }
+narrow = { DURATION($elapsed, style: "narrow") }
+digital = { DURATION($elapsed, style: "digital") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("elapsed", Duration::from_secs(5445));
+    assert_eq!(
+        format_message(locale!("en"), source, "long", &args),
+        "1 hour, 30 minutes, 45 seconds"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "short", &args),
+        "1 hr, 30 min, 45 sec"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "narrow", &args),
+        "1h 30m 45s"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "digital", &args),
+        "1:30:45"
+    );
+
+    args.set("elapsed", Duration::from_secs(3605));
+    assert_eq!(
+        format_message(locale!("en"), source, "long", &args),
+        "1 hour, 5 seconds"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "digital", &args),
+        "1:00:05"
+    );
+
+    args.set("elapsed", Duration::ZERO);
+    assert_eq!(
+        format_message(locale!("en"), source, "short", &args),
+        "0 sec"
+    );
+}
+
+#[test]
+fn duration_milliseconds() {
+    let source = r#"
+short = { DURATION($ms) }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("ms", 90_000);
+    assert_eq!(
+        format_message(locale!("en"), source, "short", &args),
+        "1 min, 30 sec"
+    );
+}
+
+#[test]
+fn duration_display() {
+    let source = r#"
+minutes = { DURATION($elapsed, hoursDisplay: "hidden") }
+always = { DURATION($elapsed, style: "long", minutesDisplay: "always") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("elapsed", Duration::from_secs(3605));
+    assert_eq!(
+        format_message(locale!("en"), source, "minutes", &args),
+        "60 min, 5 sec"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "always", &args),
+        "1 hour, 0 minutes, 5 seconds"
+    );
+
+    let duration = FluentDuration::new(
+        Duration::from_secs(125),
+        FluentDurationOptions {
+            seconds_display: FluentDurationDisplay::Hidden,
+            ..Default::default()
+        },
+    );
+    assert_eq!(duration.as_string(&locale!("en")).unwrap(), "2 min");
+}
+
+#[test]
+fn duration_out_of_range() {
+    let source = r#"
+short = { DURATION($ms) }
+"#;
+    let res = FluentResource::try_new(source.to_string()).expect("Could not parse an FTL string.");
+    let mut bundle = FluentBundle::new(vec![locale!("en")]);
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(res)
+        .expect("Failed to add FTL resources to the bundle.");
+    let pattern = bundle.get_message("short").unwrap().value().unwrap();
+
+    for value in [f64::INFINITY, 1e30, -1.0] {
+        let mut args = FluentArgs::new();
+        args.set("ms", value);
+        let mut errors = vec![];
+        let value = bundle.format_pattern(pattern, Some(&args), &mut errors);
+        assert_eq!(value, "DURATION()");
+    }
+}
@@ -24,28 +24,25 @@ liked-count2 = { NUMBER($num) ->
     let mut bundle = FluentBundle::default();
 
     bundle
-        .add_function("NUMBER", |positional, named| match positional.get(0) {
+        .add_function_override("NUMBER", |positional, named| match positional.get(0) {
             Some(FluentValue::Number(n)) => {
                 let mut num = n.clone();
                 num.options.merge(named);
 
-                FluentValue::Number(num)
+                Ok(FluentValue::Number(num))
             }
             Some(FluentValue::String(s)) => {
                 let num: f64 = if let Ok(n) = s.to_owned().parse() {
                     n
                 } else {
-                    return FluentValue::Error;
-                };
-                let mut num = FluentNumber {
-                    value: num,
-                    options: Default::default(),
+                    return Ok(FluentValue::Error);
                 };
+                let mut num = FluentNumber::new(num, Default::default());
                 num.options.merge(named);
 
-                FluentValue::Number(num)
+                Ok(FluentValue::Number(num))
             }
-            _ => FluentValue::Error,
+            _ => Ok(FluentValue::Error),
         })
         .expect("Failed to add a function.");
 
@@ -102,3 +99,345 @@ liked-count2 = { NUMBER($num) ->
     let value = bundle.format_pattern(pattern, Some(&args), &mut errors);
     assert_eq!("One person liked your message", &value);
 }
+
+#[test]
+fn test_function_names() {
+    use fluent_bundle_for_carbide::FluentError;
+
+    let mut bundle: FluentBundle<FluentResource> = FluentBundle::default();
+
+    assert_eq!(
+        bundle.add_function("NUMBER", |_, _| Ok(FluentValue::None)),
+        Err(FluentError::FunctionNameConflict {
+            id: "NUMBER".to_string()
+        })
+    );
+    assert_eq!(
+        bundle.add_function("strlen", |_, _| Ok(FluentValue::None)),
+        Err(FluentError::InvalidFunctionName {
+            id: "strlen".to_string()
+        })
+    );
+    assert_eq!(
+        bundle.add_function_override("NUMBER!", |_, _| Ok(FluentValue::None)),
+        Err(FluentError::InvalidFunctionName {
+            id: "NUMBER!".to_string()
+        })
+    );
+
+    bundle
+        .add_function("STRLEN", |_, _| Ok(FluentValue::None))
+        .expect("Failed to add a function.");
+    assert!(bundle
+        .add_function("STRLEN", |_, _| Ok(FluentValue::None))
+        .is_err());
+
+    bundle
+        .add_function_override("NUMBER", |_, _| Ok("overridden".into()))
+        .expect("Failed to override a function.");
+    let res = FluentResource::try_new("num = { NUMBER(1) }".to_string()).unwrap();
+    bundle.add_resource(res).unwrap();
+    let mut errors = vec![];
+    let value = bundle.format_value("num", None, &mut errors);
+    assert_eq!(value.as_deref(), Some("overridden"));
+}
+
+#[test]
+fn test_string_function() {
+    use fluent_bundle_for_carbide::types::FluentDateTime;
+    use fluent_bundle_for_carbide::FluentBundleBuilder;
+    use icu::locid::locale;
+
+    let res = FluentResource::try_new(
+        r#"
+string = { STRING($name) }
+upper = { STRING($name, transform: "uppercase") }
+lower = { STRING($name, transform: "lowercase") }
+invalid = { STRING($name, transform: "reverse") }
+number = { STRING($count) }
+datetime = { STRING($date) }
+select = { STRING($items) ->
+    [one] plural category
+   *[other] string
+}
+"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let mut args = FluentArgs::new();
+    args.set("name", "Fluent");
+    args.set("count", 1234.5);
+    args.set("items", 1);
+    args.set(
+        "date",
+        FluentDateTime::from(
+            chrono::NaiveDate::from_ymd_opt(2024, 3, 7)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        ),
+    );
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(&res).unwrap();
+
+    let mut errors = vec![];
+    let format = |id, errors: &mut Vec<_>| {
+        bundle
+            .format_value(id, Some(&args), errors)
+            .unwrap()
+            .into_owned()
+    };
+
+    assert_eq!(format("string", &mut errors), "Fluent");
+    assert_eq!(format("upper", &mut errors), "FLUENT");
+    assert_eq!(format("lower", &mut errors), "fluent");
+    assert_eq!(format("invalid", &mut errors), "STRING()");
+    assert_eq!(format("number", &mut errors), "1,234.5");
+    // The string is not matched by its plural category.
+    assert_eq!(format("select", &mut errors), "string");
+
+    // The same result as the implicit conversion of the argument.
+    let date = FluentResource::try_new("date = { $date }".to_string()).unwrap();
+    let mut implicit = FluentBundle::new(vec![locale!("en-US")]);
+    implicit.add_resource(&date).unwrap();
+    assert_eq!(
+        format("datetime", &mut errors),
+        implicit
+            .format_value("date", Some(&args), &mut errors)
+            .unwrap()
+    );
+    assert!(errors.is_empty());
+
+    // A builder knows the locale only when the bundle is built.
+    let bundle = FluentBundleBuilder::new()
+        .with_locales(vec![locale!("de")])
+        .with_resource(&res)
+        .unwrap()
+        .build()
+        .unwrap();
+    let value = bundle.format_value("number", Some(&args), &mut errors);
+    assert_eq!(value.as_deref(), Some("1.234,5"));
+
+    let mut bundle =
+        fluent_bundle_for_carbide::concurrent::FluentBundle::new_concurrent(vec![locale!("en-US")]);
+    bundle.add_resource(&res).unwrap();
+    let value = bundle.format_value("upper", Some(&args), &mut errors);
+    assert_eq!(value.as_deref(), Some("FLUENT"));
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn test_plural_function() {
+    use icu::locid::locale;
+
+    let res = FluentResource::try_new(
+        r#"
+cardinal = { PLURAL($n) }
+ordinal = { PLURAL($n, type: "ordinal") }
+digits = { PLURAL($n, minimumFractionDigits: 1) }
+hint = The plural form of { $n } is { PLURAL($n) }
+"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(&res).unwrap();
+
+    let mut errors = vec![];
+    let mut format = |id, n: f64| {
+        let mut args = FluentArgs::new();
+        args.set("n", n);
+        bundle
+            .format_value(id, Some(&args), &mut errors)
+            .unwrap()
+            .into_owned()
+    };
+
+    assert_eq!(format("cardinal", 0.0), "other");
+    assert_eq!(format("cardinal", 1.0), "one");
+    assert_eq!(format("cardinal", 1.5), "other");
+    assert_eq!(format("cardinal", 2.0), "other");
+    assert_eq!(format("ordinal", 1.0), "one");
+    assert_eq!(format("ordinal", 2.0), "two");
+    assert_eq!(format("ordinal", 3.0), "few");
+    assert_eq!(format("ordinal", 11.0), "other");
+    assert_eq!(format("digits", 1.0), "other");
+    assert_eq!(format("hint", 1.0), "The plural form of 1 is one");
+
+    let mut bundle = FluentBundle::new(vec![locale!("pl")]);
+    bundle.add_resource(&res).unwrap();
+    let mut args = FluentArgs::new();
+    for (n, category) in [(1, "one"), (3, "few"), (5, "many"), (22, "few")] {
+        args.set("n", n);
+        assert_eq!(
+            bundle
+                .format_value("cardinal", Some(&args), &mut errors)
+                .as_deref(),
+            Some(category)
+        );
+    }
+
+    args.set("n", "text");
+    assert_eq!(
+        bundle
+            .format_value("cardinal", Some(&args), &mut errors)
+            .as_deref(),
+        Some("PLURAL()")
+    );
+}
+
+#[test]
+fn test_function_error() {
+    use fluent_bundle_for_carbide::{FluentError, FluentFunctionError};
+
+    let res = FluentResource::try_new(
+        r#"
+with-arg = Hello, { CHECKED($name) }!
+without-arg = Hello, { CHECKED() }!
+selector = { CHECKED($name) ->
+    [John] Hi John
+   *[other] Hi
+}
+"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let mut bundle = FluentBundle::default();
+    bundle.set_use_isolating(false);
+    bundle
+        .add_function("CHECKED", |_, _| {
+            Err(FluentFunctionError::new("Not allowed."))
+        })
+        .expect("Failed to add a function.");
+    bundle.add_resource(res).unwrap();
+
+    let mut args = FluentArgs::new();
+    args.set("name", "John");
+    let error = FluentError::FunctionError {
+        name: "CHECKED".to_string(),
+        message: "Not allowed.".to_string(),
+    };
+
+    for (id, expected) in [
+        ("with-arg", "Hello, John!"),
+        ("without-arg", "Hello, {CHECKED()}!"),
+        ("selector", "Hi John"),
+    ] {
+        let mut errors = vec![];
+        let value = bundle.format_value(id, Some(&args), &mut errors);
+        assert_eq!(value.as_deref(), Some(expected));
+        assert_eq!(errors, vec![error.clone()]);
+    }
+    assert_eq!(error.to_string(), "Function CHECKED() failed: Not allowed.");
+}
+
+#[test]
+fn test_number_currency_arguments() {
+    use fluent_bundle_for_carbide::{FluentError, FluentWarning};
+
+    let res = FluentResource::try_new(
+        r#"
+missing = { NUMBER($price, style: "currency") }
+unused = { NUMBER($price, currency: "USD") }
+valid = { NUMBER($price, style: "currency", currency: "USD") }
+too-long = { NUMBER($price, style: "currency", currency: "TOOLONGCODE") }
+non-ascii = { NUMBER($price, style: "currency", currency: "€UR") }
+"#
+        .to_string(),
+    )
+    .unwrap();
+
+    let mut bundle = FluentBundle::new(vec![icu::locid::locale!("en-US")]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(res).unwrap();
+
+    let mut args = FluentArgs::new();
+    args.set("price", 12.5);
+
+    let format = |id| {
+        let pattern = bundle.get_message(id).unwrap().value().unwrap();
+        let mut errors = vec![];
+        let mut warnings = vec![];
+        let value =
+            bundle.format_pattern_with_warnings(pattern, Some(&args), &mut errors, &mut warnings);
+        (value.into_owned(), errors, warnings)
+    };
+
+    let (value, errors, warnings) = format("missing");
+    assert_eq!(value, "12.5");
+    assert_eq!(
+        errors,
+        vec![FluentError::MissingFunctionArgument {
+            function: "NUMBER".to_string(),
+            argument: "currency".to_string(),
+        }]
+    );
+    assert!(warnings.is_empty());
+
+    let (value, errors, warnings) = format("unused");
+    assert_eq!(value, "12.5");
+    assert!(errors.is_empty());
+    assert_eq!(
+        warnings,
+        vec![FluentWarning::UnusedFunctionArgument {
+            function: "NUMBER".to_string(),
+            argument: "currency".to_string(),
+        }]
+    );
+
+    let (value, errors, warnings) = format("valid");
+    assert_eq!(value, "$12.50");
+    assert!(errors.is_empty() && warnings.is_empty());
+
+    // Invalid currency codes are reported like a missing one.
+    for id in ["too-long", "non-ascii"] {
+        let (value, errors, _) = format(id);
+        assert_eq!(value, "12.5");
+        assert_eq!(
+            errors,
+            vec![FluentError::MissingFunctionArgument {
+                function: "NUMBER".to_string(),
+                argument: "currency".to_string(),
+            }]
+        );
+    }
+}
+
+#[test]
+fn test_has_and_remove_function() {
+    let mut bundle: FluentBundle<FluentResource> = FluentBundle::default();
+    let res = FluentResource::try_new("num = { NUMBER(1) }\nCUSTOM = Not a function".to_string())
+        .unwrap();
+    bundle.add_resource(res).unwrap();
+
+    assert!(bundle.has_function("NUMBER"));
+    assert!(bundle.has_function("DATETIME"));
+    assert!(!bundle.has_function("CUSTOM"));
+
+    // Removing a message of the same name is not possible.
+    assert!(!bundle.remove_function("CUSTOM"));
+    assert!(bundle.has_message("CUSTOM"));
+    assert!(!bundle.remove_function("MISSING"));
+
+    assert!(bundle.remove_function("NUMBER"));
+    assert!(!bundle.has_function("NUMBER"));
+    assert!(!bundle.remove_function("NUMBER"));
+
+    let mut errors = vec![];
+    bundle.format_value("num", None, &mut errors);
+    assert!(!errors.is_empty());
+
+    bundle
+        .add_function_override("NUMBER", |_, _| Ok("replaced".into()))
+        .expect("Failed to add a function.");
+    let mut errors = vec![];
+    assert_eq!(
+        bundle.format_value("num", None, &mut errors).as_deref(),
+        Some("replaced")
+    );
+}
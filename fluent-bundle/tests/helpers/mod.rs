@@ -1,10 +1,31 @@
+#![allow(dead_code)]
+
 use std::collections::HashMap;
 use std::fs::File;
 use std::io;
 use std::io::Read;
 
+use fluent_bundle_for_carbide::{FluentArgs, FluentBundle, FluentResource};
+use icu::locid::Locale;
 use serde::{Deserialize, Serialize};
 
+/// Formats the message `id` of `source` for the locale, asserting that no errors occur.
+pub fn format_message(locale: Locale, source: &str, id: &str, args: &FluentArgs) -> String {
+    let res = FluentResource::try_new(source.to_string()).expect("Could not parse an FTL string.");
+    let mut bundle = FluentBundle::new(vec![locale]);
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(res)
+        .expect("Failed to add FTL resources to the bundle.");
+
+    let msg = bundle.get_message(id).expect("Message doesn't exist.");
+    let pattern = msg.value().expect("Message has no value.");
+    let mut errors = vec![];
+    let value = bundle.format_pattern(pattern, Some(args), &mut errors);
+    assert!(errors.is_empty(), "{:?}", errors);
+    value.to_string()
+}
+
 #[macro_export]
 macro_rules! test {
     ($desc:stmt, $closure:block) => {{
@@ -0,0 +1,128 @@
+use fluent_bundle_for_carbide::types::{
+    FluentListOptions, FluentRelativeTime, FluentRelativeTimeStyle, FluentRelativeTimeUnit,
+    FluentValue,
+};
+use fluent_bundle_for_carbide::{FluentArgs, FluentBundle, FluentError, FluentResource};
+use icu::locid::{locale, Locale};
+
+mod helpers;
+use helpers::format_message;
+
+#[test]
+fn list_builtin() {
+    let source = r#"
+and = { LIST("apples", "bananas", "oranges") }
+or = { LIST("apples", "bananas", "oranges", type: "disjunction") }
+unit = { LIST("1 hr", "30 min", type: "unit", style: "narrow") }
+long = { LIST("apples", "bananas", "oranges", type: "conjunction", style: "long") }
+short = { LIST("apples", "bananas", "oranges", type: "conjunction", style: "short") }
+"#;
+
+    let args = FluentArgs::new();
+    assert_eq!(
+        format_message(locale!("en"), source, "and", &args),
+        "apples, bananas, and oranges"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "or", &args),
+        "apples, bananas, or oranges"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "unit", &args),
+        "1 hr 30 min"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "long", &args),
+        "apples, bananas, and oranges"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "short", &args),
+        "apples, bananas, & oranges"
+    );
+    assert_eq!(
+        format_message(locale!("de"), source, "and", &args),
+        "apples, bananas und oranges"
+    );
+}
+
+#[test]
+fn list_argument() {
+    let source = r#"
+fruits = { $fruits }
+or = { LIST($fruits, type: "disjunction") }
+count = { $fruits ->
+    [one] One fruit: { $fruits }
+   *[other] Fruits: { $fruits }
+}
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("fruits", vec!["apples", "bananas"]);
+    assert_eq!(
+        format_message(locale!("en"), source, "fruits", &args),
+        "apples and bananas"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "or", &args),
+        "apples or bananas"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "count", &args),
+        "Fruits: apples and bananas"
+    );
+
+    args.set("fruits", FluentValue::from(vec!["apples"]));
+    assert_eq!(
+        format_message(locale!("en"), source, "count", &args),
+        "One fruit: apples"
+    );
+}
+
+#[test]
+fn list_missing_locale_data() {
+    let options = FluentListOptions::default();
+    let items = ["apples", "bananas", "oranges"];
+    assert_eq!(
+        options.format(&locale!("tlh"), &items),
+        options.format(&Locale::UND, &items)
+    );
+    assert!(options.format(&locale!("tlh"), &items).is_ok());
+}
+
+#[test]
+fn list_item_format_error() {
+    let source = r#"
+items = { $items }
+or = { LIST($items, type: "disjunction") }
+"#;
+    let res = FluentResource::try_new(source.to_string()).unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en")]);
+    bundle.set_use_isolating(false);
+    bundle.add_resource(res).unwrap();
+
+    let never = FluentRelativeTime::new(
+        f64::NAN,
+        FluentRelativeTimeUnit::Day,
+        FluentRelativeTimeStyle::Long,
+    );
+    let mut args = FluentArgs::new();
+    args.set(
+        "items",
+        FluentValue::List(
+            vec!["today".into(), never.into()],
+            FluentListOptions::default(),
+        ),
+    );
+
+    for (id, expected) in [("items", "{$items}"), ("or", "{LIST()}")] {
+        let pattern = bundle.get_message(id).unwrap().value().unwrap();
+        let mut errors = vec![];
+        let value = bundle.format_pattern(pattern, Some(&args), &mut errors);
+        assert_eq!(value, expected);
+        assert!(
+            matches!(errors.as_slice(), [FluentError::RelativeTimeFormat(_)]),
+            "{:?}",
+            errors
+        );
+    }
+}
@@ -0,0 +1,789 @@
+use fluent_bundle_for_carbide::types::{FluentNumber, FluentNumberNotation, FluentNumberOptions};
+use fluent_bundle_for_carbide::FluentArgs;
+use icu::locid::{locale, Locale};
+
+mod helpers;
+use helpers::format_message;
+
+#[test]
+fn number_compact_notation() {
+    let source = r#"
+short = { NUMBER($count, notation: "compact", compactDisplay: "short") }
+long = { NUMBER($count, notation: "compact", compactDisplay: "long") }
+fraction = { NUMBER($count, notation: "compact", minimumFractionDigits: 2) }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("count", 1_234_567);
+    assert_eq!(
+        format_message(locale!("en"), source, "short", &args),
+        "1.2M"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "long", &args),
+        "1.2 million"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "fraction", &args),
+        "1.23M"
+    );
+
+    args.set("count", 3400);
+    assert_eq!(
+        format_message(locale!("en"), source, "short", &args),
+        "3.4K"
+    );
+
+    args.set("count", 999_999);
+    assert_eq!(
+        format_message(locale!("en"), source, "fraction", &args),
+        "1.00M"
+    );
+
+    args.set("count", 42);
+    assert_eq!(format_message(locale!("en"), source, "short", &args), "42");
+}
+
+#[test]
+fn number_compact_notation_options() {
+    let num = FluentNumber::new(
+        15_300.0,
+        FluentNumberOptions {
+            notation: FluentNumberNotation::Compact,
+            ..Default::default()
+        },
+    );
+    assert_eq!(num.as_string(&locale!("en")).as_deref(), Ok("15K"));
+}
+
+#[test]
+fn number_compact_notation_style() {
+    let source = r#"
+percent = { NUMBER($n, notation: "compact", style: "percent") }
+currency = { NUMBER($n, notation: "compact", style: "currency", currency: "USD") }
+unit = { NUMBER($n, notation: "compact", style: "unit", unit: "meter") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("n", 123.4);
+    assert_eq!(
+        format_message(locale!("en"), source, "percent", &args),
+        "12K%"
+    );
+
+    args.set("n", 1_500_000);
+    assert_eq!(
+        format_message(locale!("en"), source, "currency", &args),
+        "$1.5M"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "unit", &args),
+        "1.5M m"
+    );
+}
+
+#[test]
+fn number_compact_notation_sign_display() {
+    let source = r#"
+always = { NUMBER($n, notation: "compact", signDisplay: "always") }
+never = { NUMBER($n, notation: "compact", signDisplay: "never") }
+fraction = { NUMBER($n, notation: "compact", signDisplay: "always", maximumFractionDigits: 2) }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("n", 15_300);
+    assert_eq!(
+        format_message(locale!("en"), source, "always", &args),
+        "+15K"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "fraction", &args),
+        "+15.3K"
+    );
+
+    args.set("n", -15_300);
+    assert_eq!(format_message(locale!("en"), source, "never", &args), "15K");
+}
+
+#[test]
+fn number_currency_style() {
+    let source = r#"
+symbol = { NUMBER($price, style: "currency", currency: "USD", currencyDisplay: "symbol") }
+code = { NUMBER($price, style: "currency", currency: "USD", currencyDisplay: "code") }
+euro = { NUMBER($price, style: "currency", currency: "EUR") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("price", 1234.56);
+    assert_eq!(
+        format_message(locale!("en-US"), source, "symbol", &args),
+        "$1,234.56"
+    );
+    assert_eq!(
+        format_message(locale!("en-US"), source, "code", &args),
+        "USD\u{a0}1,234.56"
+    );
+    assert_eq!(
+        format_message(locale!("en-US"), source, "euro", &args),
+        "€1,234.56"
+    );
+    assert_eq!(
+        format_message(locale!("de"), source, "euro", &args),
+        "1.234,56\u{a0}€"
+    );
+
+    args.set("price", -5);
+    assert_eq!(
+        format_message(locale!("en-US"), source, "symbol", &args),
+        "-$5.00"
+    );
+}
+
+#[test]
+fn number_percent_style() {
+    let source = r#"
+percent = { NUMBER($ratio, style: "percent") }
+fraction = { NUMBER($ratio, style: "percent", minimumFractionDigits: 1) }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("ratio", 0.5);
+    assert_eq!(
+        format_message(locale!("en"), source, "percent", &args),
+        "50%"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "fraction", &args),
+        "50.0%"
+    );
+    assert_eq!(
+        format_message(locale!("sv"), source, "percent", &args),
+        "50\u{a0}%"
+    );
+    assert_eq!(
+        format_message(locale!("tr"), source, "percent", &args),
+        "%50"
+    );
+
+    args.set("ratio", -0.256);
+    assert_eq!(
+        format_message(locale!("en"), source, "percent", &args),
+        "-26%"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "fraction", &args),
+        "-25.6%"
+    );
+
+    args.set("ratio", 12.5);
+    assert_eq!(
+        format_message(locale!("en"), source, "percent", &args),
+        "1,250%"
+    );
+}
+
+#[test]
+fn number_unit_style() {
+    use fluent_bundle_for_carbide::types::FluentNumberStyle;
+
+    let source = r#"
+speed = { NUMBER($speed, style: "unit", unit: "kilometer-per-hour") }
+narrow = { NUMBER($speed, style: "unit", unit: "kilometer-per-hour", unitDisplay: "narrow") }
+long = { NUMBER($speed, style: "unit", unit: "kilometer-per-hour", unitDisplay: "long") }
+temperature = { NUMBER($speed, style: "unit", unit: "celsius") }
+unknown = { NUMBER($speed, style: "unit", unit: "parsec") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("speed", 1234.5);
+    assert_eq!(
+        format_message(locale!("en"), source, "speed", &args),
+        "1,234.5 km/h"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "narrow", &args),
+        "1,234.5km/h"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "long", &args),
+        "1,234.5 km/h"
+    );
+    assert_eq!(
+        format_message(locale!("de"), source, "speed", &args),
+        "1.234,5 km/h"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "temperature", &args),
+        "1,234.5°C"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "unknown", &args),
+        "1,234.5"
+    );
+
+    args.set("speed", -42);
+    assert_eq!(
+        format_message(locale!("en"), source, "speed", &args),
+        "-42 km/h"
+    );
+
+    let options = FluentNumberOptions {
+        style: FluentNumberStyle::Unit,
+        ..Default::default()
+    };
+    assert_eq!(options.missing_option(), Some("unit"));
+    let options = FluentNumberOptions {
+        unit: Some("liter".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(options.unused_options(), vec!["unit"]);
+}
+
+#[test]
+fn number_sign_display() {
+    let source = r#"
+auto = { NUMBER($n, signDisplay: "auto") }
+never = { NUMBER($n, signDisplay: "never") }
+always = { NUMBER($n, signDisplay: "always") }
+except-zero = { NUMBER($n, signDisplay: "exceptZero") }
+negative = { NUMBER($n, signDisplay: "negative") }
+scientific = { NUMBER($n, notation: "scientific", signDisplay: "always") }
+"#;
+
+    let cases: [(f64, [&str; 5]); 3] = [
+        (5.0, ["5", "5", "+5", "+5", "5"]),
+        (-5.0, ["-5", "5", "-5", "-5", "-5"]),
+        (0.0, ["0", "0", "+0", "0", "0"]),
+    ];
+    let ids = ["auto", "never", "always", "except-zero", "negative"];
+
+    for (value, expected) in cases {
+        let mut args = FluentArgs::new();
+        args.set("n", value);
+        for (id, expected) in ids.iter().zip(expected) {
+            assert_eq!(
+                format_message(locale!("en"), source, id, &args),
+                expected,
+                "{} with {}",
+                id,
+                value
+            );
+        }
+    }
+
+    let mut args = FluentArgs::new();
+    args.set("n", 1500);
+    assert_eq!(
+        format_message(locale!("en"), source, "scientific", &args),
+        "+1.500E+03"
+    );
+}
+
+#[test]
+fn number_significant_digits() {
+    let source = r#"
+significant = { NUMBER($n, minimumSignificantDigits: 3, maximumSignificantDigits: 5) }
+both = { NUMBER($n, maximumSignificantDigits: 2, maximumFractionDigits: 3) }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("n", 0.00123);
+    assert_eq!(
+        format_message(locale!("en"), source, "significant", &args),
+        "0.00123"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "both", &args),
+        "0.0012"
+    );
+
+    args.set("n", 123456);
+    assert_eq!(
+        format_message(locale!("en"), source, "significant", &args),
+        "123,460"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "both", &args),
+        "120,000"
+    );
+
+    args.set("n", 1.0);
+    assert_eq!(
+        format_message(locale!("en"), source, "significant", &args),
+        "1.00"
+    );
+
+    args.set("n", 9.99996);
+    assert_eq!(
+        format_message(locale!("en"), source, "significant", &args),
+        "10.0"
+    );
+
+    args.set("n", 0);
+    assert_eq!(
+        format_message(locale!("en"), source, "significant", &args),
+        "0.00"
+    );
+}
+
+#[test]
+fn number_currency_name() {
+    use fluent_bundle_for_carbide::{FluentBundle, FluentError, FluentResource};
+
+    let source =
+        r#"name = { NUMBER($price, style: "currency", currency: "USD", currencyDisplay: "name") }"#;
+    let res = FluentResource::try_new(source.to_string()).unwrap();
+    let mut bundle = FluentBundle::new(vec![locale!("en-US")]);
+    bundle.add_resource(res).unwrap();
+
+    let mut args = FluentArgs::new();
+    args.set("price", 1234.56);
+
+    // The locale data has no currency names, so the name is reported as an error
+    // instead of being displayed like the code.
+    let pattern = bundle.get_message("name").unwrap().value().unwrap();
+    let mut errors = vec![];
+    bundle.format_pattern(pattern, Some(&args), &mut errors);
+    assert!(
+        matches!(&errors[..], [FluentError::NumberFormat(_)]),
+        "{:?}",
+        errors
+    );
+}
+
+#[test]
+fn number_currency_narrow_symbol() {
+    let source = r#"
+krona = { NUMBER($price, style: "currency", currency: "SEK") }
+krona-narrow = { NUMBER($price, style: "currency", currency: "SEK", currencyDisplay: "narrowSymbol") }
+dollar = { NUMBER($price, style: "currency", currency: "USD") }
+dollar-narrow = { NUMBER($price, style: "currency", currency: "USD", currencyDisplay: "narrowSymbol") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("price", 12);
+    assert_eq!(
+        format_message(locale!("en-US"), source, "krona", &args),
+        "SEK\u{a0}12.00"
+    );
+    assert_eq!(
+        format_message(locale!("en-US"), source, "krona-narrow", &args),
+        "kr\u{a0}12.00"
+    );
+    assert_eq!(
+        format_message(locale!("sv"), source, "dollar", &args),
+        "12,00\u{a0}US$"
+    );
+    assert_eq!(
+        format_message(locale!("sv"), source, "dollar-narrow", &args),
+        "12,00\u{a0}$"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn number_serde() {
+    use fluent_bundle_for_carbide::types::{FluentNumberCurrencyDisplayStyle, FluentNumberStyle};
+
+    let number: FluentNumber = serde_json::from_str(
+        r#"{"value": 3.14, "options": {"style": "currency", "currency": "EUR", "currencyDisplay": "narrowSymbol", "minimumFractionDigits": 2}}"#,
+    )
+    .unwrap();
+    assert_eq!(number.value, 3.14);
+    assert_eq!(number.options.style, FluentNumberStyle::Currency);
+    assert_eq!(number.options.currency.as_deref(), Some("EUR"));
+    assert_eq!(
+        number.options.currency_display,
+        FluentNumberCurrencyDisplayStyle::NarrowSymbol
+    );
+    assert_eq!(number.options.minimum_fraction_digits, Some(2));
+
+    let json = serde_json::to_value(&number).unwrap();
+    assert_eq!(json["options"]["style"], "currency");
+    assert_eq!(json["options"]["roundingMode"], "halfExpand");
+    assert_eq!(json["options"]["useGrouping"], "auto");
+
+    let number: FluentNumber = serde_json::from_str(r#"{"value": 5}"#).unwrap();
+    assert_eq!(number.options, FluentNumberOptions::default());
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn number_from_rust_decimal() {
+    use std::str::FromStr;
+
+    let source = r#"
+plain = { $amount }
+"#;
+
+    let decimal = rust_decimal::Decimal::from_str("12345678901234567.89").unwrap();
+    let number = FluentNumber::from(decimal);
+    assert_eq!(number.options.minimum_fraction_digits, Some(2));
+
+    let mut args = FluentArgs::new();
+    args.set("amount", number);
+    assert_eq!(
+        format_message(locale!("en"), source, "plain", &args),
+        "12,345,678,901,234,567.89"
+    );
+
+    args.set("amount", rust_decimal::Decimal::from_str("0.10").unwrap());
+    assert_eq!(
+        format_message(locale!("en"), source, "plain", &args),
+        "0.10"
+    );
+}
+
+#[test]
+fn number_rounding_increment() {
+    let source = r#"
+francs = { NUMBER($price, style: "currency", currency: "CHF", roundingIncrement: 5, minimumFractionDigits: 2) }
+quarter = { NUMBER($price, roundingIncrement: 25, minimumFractionDigits: 2, maximumFractionDigits: 2) }
+hundreds = { NUMBER($price, roundingIncrement: 100, maximumFractionDigits: 0) }
+invalid = { NUMBER($price, roundingIncrement: 3, minimumFractionDigits: 2, maximumFractionDigits: 2) }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("price", 1.234);
+    assert_eq!(
+        format_message(locale!("en-US"), source, "francs", &args),
+        "CHF\u{a0}1.25"
+    );
+    assert_eq!(
+        format_message(locale!("en-US"), source, "quarter", &args),
+        "1.25"
+    );
+    assert_eq!(
+        format_message(locale!("en-US"), source, "invalid", &args),
+        "1.23"
+    );
+
+    args.set("price", 1.13);
+    assert_eq!(
+        format_message(locale!("en-US"), source, "quarter", &args),
+        "1.25"
+    );
+
+    args.set("price", 1249);
+    assert_eq!(
+        format_message(locale!("en-US"), source, "hundreds", &args),
+        "1,200"
+    );
+}
+
+#[test]
+fn number_trailing_zero_display() {
+    let source = r#"
+strip = { NUMBER($n, minimumFractionDigits: 2, trailingZeroDisplay: "stripIfInteger") }
+auto = { NUMBER($n, minimumFractionDigits: 2, trailingZeroDisplay: "auto") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("n", 3.0);
+    assert_eq!(format_message(locale!("en"), source, "strip", &args), "3");
+    assert_eq!(format_message(locale!("en"), source, "auto", &args), "3.00");
+
+    // Numbers with a fraction keep their minimum fraction digits.
+    args.set("n", 3.1);
+    assert_eq!(
+        format_message(locale!("en"), source, "strip", &args),
+        "3.10"
+    );
+
+    // Numbers that round to an integer are stripped as well.
+    args.set("n", 2.9999);
+    assert_eq!(format_message(locale!("en"), source, "strip", &args), "3");
+
+    args.set("n", -0.0);
+    assert_eq!(format_message(locale!("en"), source, "strip", &args), "-0");
+}
+
+#[test]
+fn number_ordinal_selection() {
+    let source = r#"
+rank = { ORDINAL($rank) ->
+    [one] { $rank }st
+    [two] { $rank }nd
+    [few] { $rank }rd
+   *[other] { $rank }th
+}
+rank-type = { NUMBER($rank, type: "ordinal") ->
+    [one] { $rank }st
+   *[other] { $rank }th
+}
+count = { $rank ->
+    [one] one
+   *[other] other
+}
+"#;
+
+    let cases = [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (22, "22nd"),
+        (23, "23rd"),
+        (101, "101st"),
+    ];
+
+    let mut args = FluentArgs::new();
+    for (rank, expected) in cases {
+        args.set("rank", rank);
+        assert_eq!(
+            format_message(locale!("en"), source, "rank", &args),
+            expected
+        );
+    }
+
+    args.set("rank", 21);
+    assert_eq!(
+        format_message(locale!("en"), source, "rank-type", &args),
+        "21st"
+    );
+
+    // Selecting on the plain number still uses the cardinal rules.
+    assert_eq!(
+        format_message(locale!("en"), source, "count", &args),
+        "other"
+    );
+}
+
+#[test]
+fn number_missing_locale_data() {
+    // Locales without data are formatted like the root locale instead of failing.
+    let num = FluentNumber::from(1234.5);
+    assert_eq!(num.as_string(&locale!("tlh")), num.as_string(&Locale::UND));
+    assert_eq!(num.as_string(&locale!("tlh")).as_deref(), Ok("1,234.5"));
+}
+
+#[test]
+fn number_ordering() {
+    use std::cmp::Ordering;
+
+    let numbers: Vec<FluentNumber> = [-2.5, -1.0, 0.0, 0.25, 1.0, 3.0]
+        .into_iter()
+        .map(FluentNumber::from)
+        .collect();
+    for pair in numbers.windows(2) {
+        assert!(pair[0] < pair[1]);
+        assert_eq!(pair[0].total_cmp(&pair[1]), Ordering::Less);
+    }
+
+    // Equal values with different options are not ordered, as they are not `==`.
+    let fractional = FluentNumber::new(
+        1.0,
+        FluentNumberOptions {
+            minimum_fraction_digits: Some(2),
+            ..Default::default()
+        },
+    );
+    assert_ne!(fractional, FluentNumber::from(1));
+    assert_eq!(fractional.partial_cmp(&FluentNumber::from(1)), None);
+    assert!(fractional < FluentNumber::from(2));
+    assert_eq!(
+        FluentNumber::from(1).partial_cmp(&FluentNumber::from(1)),
+        Some(Ordering::Equal)
+    );
+
+    // NaN is not ordered, but has a place in the total order.
+    let nan = FluentNumber::from(f64::NAN);
+    assert_eq!(nan.partial_cmp(&FluentNumber::from(1)), None);
+    assert_eq!(
+        nan.total_cmp(&FluentNumber::from(f64::INFINITY)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        FluentNumber::from(-0.0).total_cmp(&FluentNumber::from(0.0)),
+        Ordering::Less
+    );
+    assert_eq!(
+        FluentNumber::from(-0.0).partial_cmp(&FluentNumber::from(0.0)),
+        Some(Ordering::Equal)
+    );
+
+    let mut sorted = [FluentNumber::from(3), nan, FluentNumber::from(-1)];
+    sorted.sort_by(FluentNumber::total_cmp);
+    assert_eq!(sorted[0].value, -1.0);
+    assert_eq!(sorted[1].value, 3.0);
+    assert!(sorted[2].value.is_nan());
+}
+
+#[test]
+fn number_scientific_locale_signs() {
+    let source = r#"
+scientific = { NUMBER($n, notation: "scientific") }
+engineering = { NUMBER($n, notation: "engineering") }
+"#;
+
+    let cases = [
+        (locale!("en"), 0.00015, "scientific", "1.500E-04"),
+        (locale!("en"), 15000.0, "engineering", "15.000E+03"),
+        (locale!("fa"), 0.00015, "scientific", "۱٫۵۰۰E\u{200e}−۰۴"),
+        (locale!("fa"), 1500.0, "scientific", "۱٫۵۰۰E\u{200e}+۰۳"),
+        (locale!("ar"), 0.00015, "scientific", "1.500E\u{200e}-04"),
+    ];
+
+    for (locale, n, id, expected) in cases {
+        let mut args = FluentArgs::new();
+        args.set("n", n);
+        assert_eq!(format_message(locale, source, id, &args), expected);
+    }
+}
+
+#[test]
+fn number_negative_zero() {
+    use std::str::FromStr;
+
+    for (input, fraction_digits, expected) in [
+        ("-0", None, "-0"),
+        ("-0.0", Some(1), "-0.0"),
+        ("-0.00", Some(2), "-0.00"),
+        ("0", None, "0"),
+    ] {
+        let number = FluentNumber::from_str(input).unwrap();
+        assert_eq!(number.value.is_sign_negative(), input.starts_with('-'));
+        assert_eq!(number.options.minimum_fraction_digits, fraction_digits);
+        assert_eq!(number.as_string(&locale!("en")).unwrap(), expected);
+    }
+    assert_eq!(
+        FluentNumber::from(-0.0).as_string(&locale!("en")).unwrap(),
+        "-0"
+    );
+
+    let source = r#"
+literal = { -0.0 }
+number = { NUMBER($n, minimumFractionDigits: 1) }
+"#;
+    let mut args = FluentArgs::new();
+    args.set("n", -0.0);
+    assert_eq!(
+        format_message(locale!("en"), source, "literal", &args),
+        "-0.0"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "number", &args),
+        "-0.0"
+    );
+}
+
+#[test]
+fn number_builder_methods() {
+    use fluent_bundle_for_carbide::types::{FluentNumberGrouping, FluentNumberStyle};
+
+    let number = FluentNumber::from(1234.5)
+        .with_options(FluentNumberOptions {
+            minimum_fraction_digits: Some(2),
+            ..Default::default()
+        })
+        .map_options(|options| FluentNumberOptions {
+            use_grouping: FluentNumberGrouping::Never,
+            ..options
+        });
+    assert_eq!(number.as_string(&locale!("en")).unwrap(), "1234.50");
+
+    let number = number.with_value(0.5);
+    assert_eq!(number.as_string(&locale!("en")).unwrap(), "0.50");
+
+    let number = number.map_options(|options| FluentNumberOptions {
+        style: FluentNumberStyle::Percent,
+        minimum_fraction_digits: None,
+        ..options
+    });
+    assert_eq!(number.as_string(&locale!("en")).unwrap(), "50%");
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn number_num_traits() {
+    use num_traits::{FromPrimitive, ToPrimitive};
+
+    let num = FluentNumber::from_i64(-42).unwrap();
+    assert_eq!(num.to_i64(), Some(-42));
+    assert_eq!(num.to_u64(), None);
+
+    let num = FluentNumber::from_u64(42).unwrap();
+    assert_eq!(num.to_u64(), Some(42));
+    assert_eq!(num.to_u8(), Some(42));
+
+    let num = FluentNumber::from_f32(1.5).unwrap();
+    assert_eq!(num.to_f32(), Some(1.5));
+    assert_eq!(num.to_i64(), Some(1));
+
+    let num = FluentNumber::from_f64(-0.25).unwrap();
+    assert_eq!(num.to_f64(), Some(-0.25));
+    assert_eq!(num.options, FluentNumberOptions::default());
+
+    assert_eq!(FluentNumber::from(f64::NAN).to_i64(), None);
+    assert_eq!(FluentNumber::from(1e20).to_i64(), None);
+}
+
+#[test]
+fn number_non_finite() {
+    use fluent_bundle_for_carbide::types::{FluentNumberSignDisplay, FluentNumberStyle};
+
+    let nan = FluentNumber::from(f64::NAN);
+    let infinity = FluentNumber::from(f64::INFINITY);
+    let negative_infinity = FluentNumber::from(f64::NEG_INFINITY);
+
+    assert!(nan.is_nan() && !nan.is_infinite() && !nan.is_finite());
+    assert!(!infinity.is_nan() && infinity.is_infinite() && !infinity.is_finite());
+    assert!(negative_infinity.is_infinite());
+    assert!(!FluentNumber::from(1.5).is_nan() && !FluentNumber::from(1.5).is_infinite());
+
+    for locale in [
+        locale!("en"),
+        locale!("de"),
+        locale!("ar"),
+        locale!("fa"),
+        locale!("sv"),
+    ] {
+        for number in [&nan, &infinity, &negative_infinity] {
+            for notation in ["standard", "scientific", "engineering", "compact"] {
+                let number = number.clone().map_options(|options| FluentNumberOptions {
+                    notation: notation.into(),
+                    ..options
+                });
+                assert!(
+                    number.as_string(&locale).is_ok(),
+                    "{:?} in {}",
+                    number,
+                    locale
+                );
+            }
+        }
+    }
+
+    let en = locale!("en");
+    assert_eq!(nan.as_string(&en).unwrap(), "NaN");
+    assert_eq!(infinity.as_string(&en).unwrap(), "∞");
+    assert_eq!(negative_infinity.as_string(&en).unwrap(), "-∞");
+    // Swedish uses U+2212 MINUS SIGN.
+    assert_eq!(
+        negative_infinity.as_string(&locale!("sv")).unwrap(),
+        "\u{2212}∞"
+    );
+
+    let always = infinity.clone().map_options(|options| FluentNumberOptions {
+        sign_display: FluentNumberSignDisplay::Always,
+        ..options
+    });
+    assert_eq!(always.as_string(&en).unwrap(), "+∞");
+
+    let percent = negative_infinity
+        .clone()
+        .map_options(|options| FluentNumberOptions {
+            style: FluentNumberStyle::Percent,
+            ..options
+        });
+    assert_eq!(percent.as_string(&en).unwrap(), "-∞%");
+
+    let source = "value = { $n }\n";
+    let mut args = FluentArgs::new();
+    args.set("n", f64::NEG_INFINITY);
+    assert_eq!(format_message(locale!("de"), source, "value", &args), "-∞");
+}
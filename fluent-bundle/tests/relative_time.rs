@@ -0,0 +1,126 @@
+use fluent_bundle_for_carbide::types::{
+    FluentRelativeTime, FluentRelativeTimeNumeric, FluentRelativeTimeStyle, FluentRelativeTimeUnit,
+};
+use fluent_bundle_for_carbide::{FluentArgs, FluentError};
+use icu::locid::locale;
+
+mod helpers;
+use helpers::format_message;
+
+#[test]
+fn relative_time_builtin() {
+    let source = r#"
+days = { RELTIME($n, unit: "day") }
+hours-short = { RELTIME($n, unit: "hour", style: "short") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("n", -3);
+    assert_eq!(
+        format_message(locale!("en"), source, "days", &args),
+        "3 days ago"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "hours-short", &args),
+        "3 hr. ago"
+    );
+    assert_eq!(
+        format_message(locale!("de"), source, "days", &args),
+        "vor 3 Tagen"
+    );
+
+    args.set("n", 2);
+    assert_eq!(
+        format_message(locale!("en"), source, "days", &args),
+        "in 2 days"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "hours-short", &args),
+        "in 2 hr."
+    );
+}
+
+#[test]
+fn relative_time_argument() {
+    let source = r#"
+plain = { $when }
+narrow = { RELTIME($when, style: "narrow") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set(
+        "when",
+        FluentRelativeTime::new(
+            1.0,
+            FluentRelativeTimeUnit::Year,
+            FluentRelativeTimeStyle::Long,
+        ),
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "plain", &args),
+        "in 1 year"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "narrow", &args),
+        "in 1y"
+    );
+}
+
+#[test]
+fn relative_time_numeric_auto() {
+    let source = r#"
+auto = { RELTIME($n, unit: "day", numeric: "auto") }
+always = { RELTIME($n, unit: "day", numeric: "always") }
+"#;
+
+    let mut args = FluentArgs::new();
+    args.set("n", -1);
+    assert_eq!(
+        format_message(locale!("en"), source, "auto", &args),
+        "yesterday"
+    );
+    assert_eq!(
+        format_message(locale!("en"), source, "always", &args),
+        "1 day ago"
+    );
+
+    args.set("n", 1);
+    assert_eq!(
+        format_message(locale!("en"), source, "auto", &args),
+        "tomorrow"
+    );
+    assert_eq!(
+        format_message(locale!("de"), source, "auto", &args),
+        "morgen"
+    );
+
+    // Values without a phrase fall back to the number.
+    args.set("n", 3);
+    assert_eq!(
+        format_message(locale!("en"), source, "auto", &args),
+        "in 3 days"
+    );
+
+    let when = FluentRelativeTime::new(
+        0.0,
+        FluentRelativeTimeUnit::Day,
+        FluentRelativeTimeStyle::Long,
+    )
+    .with_numeric(FluentRelativeTimeNumeric::Auto);
+    assert_eq!(when.as_string(&locale!("en")).unwrap(), "today");
+}
+
+#[test]
+fn relative_time_non_finite() {
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let when = FluentRelativeTime::new(
+            value,
+            FluentRelativeTimeUnit::Day,
+            FluentRelativeTimeStyle::Long,
+        );
+        assert!(matches!(
+            when.as_string(&locale!("en")),
+            Err(FluentError::RelativeTimeFormat(_))
+        ));
+    }
+}
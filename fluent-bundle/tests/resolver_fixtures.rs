@@ -10,9 +10,9 @@ use fluent_bundle_for_carbide::resolver::ResolverError;
 use fluent_bundle_for_carbide::FluentArgs;
 use fluent_bundle_for_carbide::FluentError;
 use fluent_bundle_for_carbide::{FluentBundle, FluentResource, FluentValue};
+use icu::locid::Locale;
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
-use icu::locid::Locale;
 
 use helpers::*;
 
@@ -135,16 +135,18 @@ fn create_bundle(
         for f in fns {
             let result = match f.as_str() {
                 "CONCAT" => bundle.add_function(f.as_str(), |args, _name_args| {
-                    args.iter()
+                    Ok(args
+                        .iter()
                         .fold(String::new(), |acc, x| match x {
                             FluentValue::String(s) => acc + s,
                             FluentValue::Number(n) => acc + &n.value.to_string(),
                             _ => acc,
                         })
-                        .into()
+                        .into())
                 }),
                 "SUM" => bundle.add_function(f.as_str(), |args, _name_args| {
-                    args.iter()
+                    Ok(args
+                        .iter()
                         .fold(0.0, |acc, x| {
                             if let FluentValue::Number(v) = x {
                                 acc + v.value
@@ -152,13 +154,13 @@ fn create_bundle(
                                 panic!("Type cannot be used in SUM");
                             }
                         })
-                        .into()
+                        .into())
                 }),
                 "IDENTITY" => bundle.add_function(f.as_str(), |args, _name_args| {
-                    args.get(0).cloned().unwrap_or(FluentValue::Error)
+                    Ok(args.get(0).cloned().unwrap_or(FluentValue::Error))
                 }),
-                "NUMBER" => bundle.add_function(f.as_str(), |args, _name_args| {
-                    args.get(0).expect("Argument must be passed").clone()
+                "NUMBER" => bundle.add_function_override(f.as_str(), |args, _name_args| {
+                    Ok(args.get(0).expect("Argument must be passed").clone())
                 }),
                 _ => unimplemented!("No such function."),
             };
@@ -194,10 +196,7 @@ fn get_resource(resource: &TestResource) -> FluentResource {
     } else {
         let (res, errors) = match res {
             Ok(r) => (r, vec![]),
-            Err((res, err)) => {
-                let err = err.into_iter().map(|err| err.into()).collect();
-                (res, err)
-            }
+            Err((res, err)) => (res, err),
         };
         test_errors(&errors, Some(&resource.errors));
         res
@@ -320,7 +319,7 @@ fn test_errors(errors: &[FluentError], reference: Option<&[TestError]>) {
     assert_eq!(errors.len(), reference.len());
     for (error, reference) in errors.iter().zip(reference) {
         match error {
-            FluentError::ResolverError(err) => match err {
+            FluentError::ResolverError { error: err, .. } => match err {
                 ResolverError::Reference(_) => {
                     assert_eq!(reference.desc.as_ref(), Some(&err.to_string()));
                     assert_eq!(reference.error_type, "Reference");
@@ -328,20 +327,21 @@ fn test_errors(errors: &[FluentError], reference: Option<&[TestError]>) {
                 ResolverError::NoValue(_) => {
                     assert_eq!(reference.error_type, "NoValue");
                 }
-                ResolverError::Cyclic => {
-                    assert_eq!(reference.error_type, "Cyclic");
-                }
                 ResolverError::TooManyPlaceables => {
                     assert_eq!(reference.error_type, "TooManyPlaceables");
                 }
                 _ => unimplemented!(),
             },
-            FluentError::ParserError(_) => {
+            FluentError::CyclicReference { .. } => {
+                assert_eq!(reference.error_type, "Cyclic");
+            }
+            FluentError::ParserError { .. } => {
                 assert_eq!(reference.error_type, "Parser");
             }
             FluentError::Overriding { .. } => {
                 assert_eq!(reference.error_type, "Overriding");
             }
+            _ => unimplemented!(),
         }
     }
 }
@@ -0,0 +1,58 @@
+use fluent_bundle_for_carbide::{FluentError, FluentResource, FluentResourceError};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+fn write_ftl(name: &str, source: &str) -> PathBuf {
+    let path =
+        std::env::temp_dir().join(format!("fluent-bundle-{}-{}.ftl", name, std::process::id()));
+    fs::write(&path, source).expect("Failed to write the FTL file.");
+    path
+}
+
+#[test]
+fn from_file() {
+    let path = write_ftl("from-file", "hello = Hello\n");
+
+    let (res, errors) = FluentResource::from_file(&path).expect("Failed to read the file.");
+    assert!(errors.is_empty());
+    assert_eq!(res.source(), "hello = Hello\n");
+    assert_eq!(res.entries().count(), 1);
+
+    let res = FluentResource::try_from_file(&path).expect("Failed to load the resource.");
+    assert_eq!(res.entries().count(), 1);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn from_file_with_syntax_errors() {
+    let path = write_ftl("syntax-errors", "hello = Hello\nbroken = {");
+
+    let (res, errors) = FluentResource::from_file(&path).expect("Failed to read the file.");
+    assert_eq!(res.entries().count(), 2);
+    assert!(matches!(
+        errors[..],
+        [FluentError::ParserError { line: 2, .. }]
+    ));
+
+    match FluentResource::try_from_file(&path) {
+        Err(FluentResourceError::Parse(parse_errors)) => assert_eq!(parse_errors, errors),
+        other => panic!("Expected a parse error, got {:?}", other),
+    }
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn from_missing_file() {
+    let path = std::env::temp_dir().join("fluent-bundle-missing-file.ftl");
+
+    let err = FluentResource::from_file(&path).expect_err("The file does not exist.");
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+    match FluentResource::try_from_file(&path) {
+        Err(FluentResourceError::Io(err)) => assert_eq!(err.kind(), io::ErrorKind::NotFound),
+        other => panic!("Expected an IO error, got {:?}", other),
+    }
+}
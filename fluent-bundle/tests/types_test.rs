@@ -10,8 +10,8 @@ use icu::locid::locale;
 
 #[test]
 fn fluent_value_try_number() {
-    let value = FluentValue::try_number("invalid");
-    assert_eq!(value, "invalid".into());
+    assert!(FluentValue::try_number("invalid").is_err());
+    assert_eq!(FluentValue::try_number("5"), Ok(FluentValue::from(5)));
 }
 
 #[test]
@@ -77,6 +77,82 @@ fn fluent_value_from() {
 
     assert_eq!(value_f64, FluentValue::from(23.5));
     assert_eq!(value_isize, FluentValue::from(-23));
+
+    assert_eq!(FluentValue::from(true), "true".into());
+    assert_eq!(FluentValue::from(&false), "false".into());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn fluent_value_serde() {
+    use chrono::DateTime;
+    use fluent_bundle_for_carbide::types::{FluentDateStyle, FluentDateTime};
+
+    let mut args = FluentArgs::new();
+    args.set("name", "John");
+    args.set("count", 3.14);
+    let mut date =
+        FluentDateTime::from(DateTime::parse_from_rfc3339("2024-01-01T00:00:00+00:00").unwrap());
+    date.options.date_style = FluentDateStyle::Long;
+    args.set("date", date);
+
+    let json = serde_json::to_value(&args).unwrap();
+    assert_eq!(json["name"], "John");
+    assert_eq!(json["count"]["type"], "number");
+    assert_eq!(json["count"]["value"], 3.14);
+    assert_eq!(json["date"]["type"], "datetime");
+    assert_eq!(json["date"]["value"], "2024-01-01T00:00:00+00:00");
+    assert_eq!(json["date"]["options"]["dateStyle"], "long");
+
+    let round_trip: FluentArgs = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        round_trip.iter().collect::<Vec<_>>(),
+        args.iter().collect::<Vec<_>>()
+    );
+
+    let value: FluentValue = serde_json::from_str(r#"{"type": "number", "value": 5}"#).unwrap();
+    assert_eq!(value, FluentValue::from(5));
+    assert!(serde_json::to_string(&FluentValue::Error).is_err());
+}
+
+#[test]
+fn fluent_value_accessors() {
+    use chrono::DateTime;
+    use fluent_bundle_for_carbide::types::FluentDateTime;
+
+    let mut number = FluentValue::from(5);
+    assert_eq!(number.as_number().map(|n| n.value), Some(5.0));
+    assert_eq!(number.as_str(), None);
+    assert_eq!(number.as_datetime(), None);
+    number.as_number_mut().unwrap().value = 6.0;
+    assert_eq!(number.into_number().map(|n| n.value), Some(6.0));
+
+    let mut string = FluentValue::from("fluent");
+    assert_eq!(string.as_str(), Some("fluent"));
+    assert_eq!(string.as_number(), None);
+    string.as_str_mut().unwrap().to_mut().push_str("-rs");
+    assert_eq!(string.clone().into_number(), None);
+    assert_eq!(string.into_str().as_deref(), Some("fluent-rs"));
+
+    let mut date =
+        FluentDateTime::from(DateTime::parse_from_rfc3339("2024-01-01T00:00:00+00:00").unwrap());
+    let mut value = FluentValue::from(date.clone());
+    assert_eq!(value.as_datetime(), Some(&date));
+    value.as_datetime_mut().unwrap().options.calendar = Some("japanese".to_string());
+    date.options.calendar = Some("japanese".to_string());
+    assert_eq!(value.clone().into_str(), None);
+    assert_eq!(value.into_datetime(), Some(date));
+}
+
+#[test]
+fn fluent_value_display() {
+    assert_eq!(FluentValue::from("my str").to_string(), "my str");
+    assert_eq!(FluentValue::from(1234.5).to_string(), "1,234.5");
+    assert_eq!(FluentValue::from(vec!["a", "b"]).to_string(), "a, b");
+    assert_eq!(FluentValue::Error.to_string(), "<error>");
+    assert_eq!(FluentValue::None.to_string(), "");
+
+    assert_eq!(FluentNumber::from(-5).to_string(), "-5");
 }
 
 #[test]
@@ -153,3 +229,70 @@ fn fluent_number_style() {
 //         }
 //     );
 // }
+
+#[test]
+fn fluent_number_format_percent_change() {
+    let en = locale!("en");
+
+    assert_eq!(FluentNumber::format_percent_change(0.05, &en), "+5%");
+    assert_eq!(FluentNumber::format_percent_change(-0.12, &en), "-12%");
+    assert_eq!(FluentNumber::format_percent_change(0.0, &en), "0%");
+    assert_eq!(FluentNumber::format_percent_change(-0.001, &en), "0%");
+
+    // Swedish uses U+2212 MINUS SIGN rather than the ASCII hyphen, and spaces the percent sign.
+    let sv = locale!("sv");
+    assert_eq!(
+        FluentNumber::format_percent_change(-0.12, &sv),
+        "\u{2212}12\u{a0}%"
+    );
+}
+
+#[cfg(feature = "time-crate")]
+#[test]
+fn fluent_datetime_from_time_primitive() {
+    use fluent_bundle_for_carbide::types::FluentDateTime;
+    use time::macros::datetime;
+
+    let dt = FluentDateTime::from_primitive_utc(datetime!(2024-03-01 14:30:15));
+    assert_eq!(dt.value.to_rfc3339(), "2024-03-01T14:30:15+00:00");
+
+    let dt = FluentDateTime::from_primitive_local(datetime!(2024-03-01 14:30:15), 3600)
+        .expect("Offset is in range");
+    assert_eq!(dt.value.to_rfc3339(), "2024-03-01T14:30:15+01:00");
+
+    assert!(FluentDateTime::from_primitive_local(datetime!(2024-03-01 14:30:15), 86_400).is_none());
+}
+
+#[test]
+fn fluent_number_as_fixed_point_string() {
+    let num = FluentNumber::from(1234.5678);
+    assert_eq!(num.as_fixed_point_string(0), "1235");
+    assert_eq!(num.as_fixed_point_string(2), "1234.57");
+    assert_eq!(num.as_fixed_point_string(6), "1234.567800");
+
+    let num = FluentNumber::from(-0.5);
+    assert_eq!(num.as_fixed_point_string(1), "-0.5");
+}
+
+#[cfg(feature = "time-crate")]
+#[test]
+fn fluent_datetime_from_time_types() {
+    use fluent_bundle_for_carbide::types::FluentDateTime;
+    use time::macros::{date, datetime, time};
+
+    let dt = FluentDateTime::from(datetime!(2024-03-01 14:30:15.5 +02:00));
+    assert!(!dt.naive);
+    assert_eq!(dt.value.to_rfc3339(), "2024-03-01T14:30:15.500+02:00");
+
+    // Offsets of a day or more can not be represented by chrono, so the instant is kept in UTC.
+    let dt = FluentDateTime::from(datetime!(2024-03-01 14:30:15 +25:00));
+    assert_eq!(dt.value.to_rfc3339(), "2024-02-29T13:30:15+00:00");
+
+    let dt = FluentDateTime::from(date!(2024 - 03 - 01));
+    assert!(dt.naive);
+    assert_eq!(dt.value.to_rfc3339(), "2024-03-01T00:00:00+00:00");
+
+    let dt = FluentDateTime::from(time!(14:30:15));
+    assert!(dt.naive);
+    assert_eq!(dt.value.to_rfc3339(), "1970-01-01T14:30:15+00:00");
+}
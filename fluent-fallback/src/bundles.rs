@@ -3,9 +3,9 @@ use crate::{
     env::LocalesProvider,
     errors::LocalizationError,
     generator::{BundleGenerator, BundleIterator, BundleStream},
-    types::{L10nAttribute, L10nKey, L10nMessage, ResourceId},
+    types::{FormatKey, L10nAttribute, L10nKey, L10nMessage, LocalizationResult, ResourceId},
 };
-use fluent_bundle_for_carbide::{FluentArgs, FluentBundle, FluentError};
+use fluent_bundle_for_carbide::{concurrent::FluentBundle, FluentArgs, FluentError};
 use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 
@@ -47,6 +47,8 @@ where
     }
 }
 
+// The sync methods only fail in async mode, boxing the error is not worth a breaking change.
+#[allow(clippy::result_large_err)]
 impl<G> Bundles<G>
 where
     G: BundleGenerator,
@@ -72,7 +74,7 @@ where
         id: &'l str,
         args: Option<&'l FluentArgs<'_>>,
         errors: &mut Vec<LocalizationError>,
-    ) -> Option<Cow<'l, str>> {
+    ) -> LocalizationResult<'l> {
         match &self.0 {
             BundlesInner::Iter(cache) => Self::format_value_from_iter(cache, id, args, errors),
             BundlesInner::Stream(stream) => {
@@ -112,7 +114,7 @@ where
         id: &'l str,
         args: Option<&'l FluentArgs>,
         errors: &mut Vec<LocalizationError>,
-    ) -> Result<Option<Cow<'l, str>>, LocalizationError> {
+    ) -> Result<LocalizationResult<'l>, LocalizationError> {
         match &self.0 {
             BundlesInner::Iter(cache) => Ok(Self::format_value_from_iter(cache, id, args, errors)),
             BundlesInner::Stream(_) => Err(LocalizationError::SyncRequestInAsyncMode),
@@ -130,6 +132,38 @@ where
         }
     }
 
+    /// Formats the values of the messages in a single pass through the bundles,
+    /// the same as [`Bundles::format_values_sync`] but without owning the arguments.
+    /// In async mode, [`LocalizationError::SyncRequestInAsyncMode`] is reported and
+    /// none of the messages are formatted.
+    pub(crate) fn format_values_by_id_sync<'l>(
+        &'l self,
+        keys: &[(&str, Option<&FluentArgs>)],
+        errors: &mut Vec<LocalizationError>,
+    ) -> Vec<Option<Cow<'l, str>>> {
+        match &self.0 {
+            BundlesInner::Iter(cache) => Self::format_values_from_iter(cache, keys, errors),
+            BundlesInner::Stream(_) => {
+                errors.push(LocalizationError::SyncRequestInAsyncMode);
+                vec![None; keys.len()]
+            }
+        }
+    }
+
+    /// Asynchronous version of [`Bundles::format_values_by_id_sync`].
+    pub(crate) async fn format_values_by_id<'l>(
+        &'l self,
+        keys: &[(&str, Option<&FluentArgs<'_>>)],
+        errors: &mut Vec<LocalizationError>,
+    ) -> Vec<Option<Cow<'l, str>>> {
+        match &self.0 {
+            BundlesInner::Iter(cache) => Self::format_values_from_iter(cache, keys, errors),
+            BundlesInner::Stream(stream) => {
+                Self::format_values_from_stream(stream, keys, errors).await
+            }
+        }
+    }
+
     pub fn format_messages_sync<'l>(
         &'l self,
         keys: &'l [L10nKey<'l>],
@@ -164,7 +198,10 @@ macro_rules! format_value_from_inner {
                             errors: format_errors,
                         });
                     }
-                    return Some(result);
+                    return LocalizationResult {
+                        value: Some(result),
+                        locale_used: Some(&bundle.locales[0]),
+                    };
                 } else {
                     $errors.push(LocalizationError::MissingValue {
                         id: $id.to_string(),
@@ -189,7 +226,10 @@ macro_rules! format_value_from_inner {
                 locale: None,
             });
         }
-        return None;
+        return LocalizationResult {
+            value: None,
+            locale_used: None,
+        };
     };
 }
 
@@ -217,17 +257,17 @@ macro_rules! format_values_from_inner {
                 .zip(&mut cells)
                 .filter(|(_, cell)| !matches!(cell, Value::Present(_)))
             {
-                if let Some(msg) = bundle.get_message(&key.id) {
+                if let Some(msg) = bundle.get_message(key.id()) {
                     if let Some(value) = msg.value() {
                         let mut format_errors = vec![];
                         *cell = Value::Present(bundle.format_pattern(
                             value,
-                            key.args.as_ref(),
+                            key.args(),
                             &mut format_errors,
                         ));
                         if !format_errors.is_empty() {
                             $errors.push(LocalizationError::Resolver {
-                                id: key.id.to_string(),
+                                id: key.id().to_string(),
                                 locale: bundle.locales[0].clone(),
                                 errors: format_errors,
                             });
@@ -236,14 +276,14 @@ macro_rules! format_values_from_inner {
                         *cell = Value::Missing;
                         has_missing = true;
                         $errors.push(LocalizationError::MissingValue {
-                            id: key.id.to_string(),
+                            id: key.id().to_string(),
                             locale: Some(bundle.locales[0].clone()),
                         });
                     }
                 } else {
                     has_missing = true;
                     $errors.push(LocalizationError::MissingMessage {
-                        id: key.id.to_string(),
+                        id: key.id().to_string(),
                         locale: Some(bundle.locales[0].clone()),
                     });
                 }
@@ -260,14 +300,14 @@ macro_rules! format_values_from_inner {
                 Value::Present(value) => Some(value),
                 Value::Missing => {
                     $errors.push(LocalizationError::MissingValue {
-                        id: key.id.to_string(),
+                        id: key.id().to_string(),
                         locale: None,
                     });
                     None
                 }
                 Value::None => {
                     $errors.push(LocalizationError::MissingMessage {
-                        id: key.id.to_string(),
+                        id: key.id().to_string(),
                         locale: None,
                     });
                     None
@@ -346,7 +386,7 @@ where
         id: &'l str,
         args: Option<&'l FluentArgs>,
         errors: &mut Vec<LocalizationError>,
-    ) -> Option<Cow<'l, str>> {
+    ) -> LocalizationResult<'l> {
         let mut bundle_iter = cache.into_iter();
         format_value_from_inner!(bundle_iter.next(), id, args, errors);
     }
@@ -356,7 +396,7 @@ where
         id: &'l str,
         args: Option<&'l FluentArgs<'_>>,
         errors: &mut Vec<LocalizationError>,
-    ) -> Option<Cow<'l, str>> {
+    ) -> LocalizationResult<'l> {
         use futures::StreamExt;
 
         let mut bundle_stream = stream.stream();
@@ -373,9 +413,9 @@ where
         format_messages_from_inner!(bundle_stream.next().await, keys, errors);
     }
 
-    async fn format_values_from_stream<'l>(
+    async fn format_values_from_stream<'l, K: FormatKey>(
         stream: &'l AsyncCache<G::Stream, G::Resource>,
-        keys: &'l [L10nKey<'l>],
+        keys: &[K],
         errors: &mut Vec<LocalizationError>,
     ) -> Vec<Option<Cow<'l, str>>> {
         use futures::StreamExt;
@@ -415,9 +455,9 @@ where
         format_messages_from_inner!(bundle_iter.next(), keys, errors);
     }
 
-    fn format_values_from_iter<'l>(
+    fn format_values_from_iter<'l, K: FormatKey>(
         cache: &'l Cache<G::Iter, G::Resource>,
-        keys: &'l [L10nKey<'l>],
+        keys: &[K],
         errors: &mut Vec<LocalizationError>,
     ) -> Vec<Option<Cow<'l, str>>> {
         let mut bundle_iter = cache.into_iter();
@@ -1,23 +1,20 @@
-use std::{
-    cell::{RefCell, UnsafeCell},
-    cmp::Ordering,
-    pin::Pin,
-    task::Context,
-    task::Poll,
-    task::Waker,
-};
+use std::{cmp::Ordering, pin::Pin, sync::Mutex, task::Context, task::Poll, task::Waker};
 
 use crate::generator::{BundleIterator, BundleStream};
-use chunky_vec::ChunkyVec;
+use elsa::sync::FrozenVec;
 use futures::{ready, Stream};
-use pin_cell::{PinCell, PinMut};
 
+/// Caches the items of an iterator, so they can be iterated over many times.
+///
+/// The cache is `Send` and `Sync` if the iterator is `Send` and its items are
+/// `Send` and `Sync`, so a [`Localization`](crate::Localization) can be shared
+/// between threads.
 pub struct Cache<I, R>
 where
     I: Iterator,
 {
-    iter: RefCell<I>,
-    items: UnsafeCell<ChunkyVec<I::Item>>,
+    iter: Mutex<I>,
+    items: FrozenVec<Box<I::Item>>,
     res: std::marker::PhantomData<R>,
 }
 
@@ -27,32 +24,30 @@ where
 {
     pub fn new(iter: I) -> Self {
         Self {
-            iter: RefCell::new(iter),
-            items: Default::default(),
+            iter: Mutex::new(iter),
+            items: FrozenVec::new(),
             res: std::marker::PhantomData,
         }
     }
 
     pub fn len(&self) -> usize {
-        unsafe {
-            let items = self.items.get();
-            (*items).len()
-        }
+        self.items.len()
     }
 
     pub fn get(&self, index: usize) -> Option<&I::Item> {
-        unsafe {
-            let items = self.items.get();
-            (*items).get(index)
-        }
+        self.items.get(index)
     }
 
-    /// Push, immediately getting a reference to the element
-    pub fn push_get(&self, new_value: I::Item) -> &I::Item {
-        unsafe {
-            let items = self.items.get();
-            (*items).push_get(new_value)
+    /// Returns the item at the `index`, getting it from the iterator if it isn't
+    /// cached yet.
+    fn get_or_next(&self, index: usize) -> Option<&I::Item> {
+        let mut iter = self.iter.lock().unwrap();
+        // Another thread may have cached the item while waiting for the lock.
+        if let Some(item) = self.get(index) {
+            return Some(item);
         }
+        let item = iter.next()?;
+        Some(self.items.push_get(Box::new(item)))
     }
 }
 
@@ -61,7 +56,7 @@ where
     I: BundleIterator + Iterator,
 {
     pub fn prefetch(&self) {
-        self.iter.borrow_mut().prefetch_sync();
+        self.iter.lock().unwrap().prefetch_sync();
     }
 }
 
@@ -89,13 +84,9 @@ where
             }
             Ordering::Equal => {
                 // Get the next item from the iterator
-                let item = self.cache.iter.borrow_mut().next();
+                let item = self.cache.get_or_next(self.curr);
                 self.curr += 1;
-                if let Some(item) = item {
-                    Some(self.cache.push_get(item))
-                } else {
-                    None
-                }
+                item
             }
             Ordering::Greater => {
                 // Ran off the end of the cache
@@ -122,15 +113,16 @@ where
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Caches the items of a stream, so they can be streamed many times, see [`Cache`].
 pub struct AsyncCache<S, R>
 where
     S: Stream,
 {
-    stream: PinCell<S>,
-    items: UnsafeCell<ChunkyVec<S::Item>>,
+    stream: futures::lock::Mutex<Pin<Box<S>>>,
+    items: FrozenVec<Box<S::Item>>,
     // TODO: Should probably be an SmallVec<[Waker; 1]> or something? I guess
     // multiple pending wakes are not really all that common.
-    pending_wakes: RefCell<Vec<Waker>>,
+    pending_wakes: Mutex<Vec<Waker>>,
     res: std::marker::PhantomData<R>,
 }
 
@@ -140,33 +132,19 @@ where
 {
     pub fn new(stream: S) -> Self {
         Self {
-            stream: PinCell::new(stream),
-            items: Default::default(),
+            stream: futures::lock::Mutex::new(Box::pin(stream)),
+            items: FrozenVec::new(),
             pending_wakes: Default::default(),
             res: std::marker::PhantomData,
         }
     }
 
     pub fn len(&self) -> usize {
-        unsafe {
-            let items = self.items.get();
-            (*items).len()
-        }
+        self.items.len()
     }
 
     pub fn get(&self, index: usize) -> Poll<Option<&S::Item>> {
-        unsafe {
-            let items = self.items.get();
-            (*items).get(index).into()
-        }
-    }
-
-    /// Push, immediately getting a reference to the element
-    pub fn push_get(&self, new_value: S::Item) -> &S::Item {
-        unsafe {
-            let items = self.items.get();
-            (*items).push_get(new_value)
-        }
+        self.items.get(index).into()
     }
 
     pub fn stream(&self) -> AsyncCacheStream<'_, S, R> {
@@ -182,10 +160,12 @@ where
     S: BundleStream + Stream,
 {
     pub async fn prefetch(&self) {
-        let pin = unsafe { Pin::new_unchecked(&self.stream) };
-        unsafe { PinMut::as_mut(&mut pin.borrow_mut()).get_unchecked_mut() }
+        let mut stream = self.stream.lock().await;
+        unsafe { stream.as_mut().get_unchecked_mut() }
             .prefetch_async()
-            .await
+            .await;
+        drop(stream);
+        self.wake_pending();
     }
 }
 
@@ -193,20 +173,43 @@ impl<S, R> AsyncCache<S, R>
 where
     S: Stream,
 {
-    // Helper function that gets the next value from wrapped stream.
-    fn poll_next_item(&self, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
-        let pin = unsafe { Pin::new_unchecked(&self.stream) };
-        let poll = PinMut::as_mut(&mut pin.borrow_mut()).poll_next(cx);
-        if poll.is_ready() {
-            let wakers = std::mem::take(&mut *self.pending_wakes.borrow_mut());
-            for waker in wakers {
-                waker.wake();
+    // Helper function that gets the item at the `index` from the wrapped stream,
+    // unless another task cached it in the meantime.
+    fn poll_next_item(&self, index: usize, cx: &mut Context<'_>) -> Poll<Option<&S::Item>> {
+        let mut stream = match self.stream.try_lock() {
+            Some(stream) => stream,
+            None => {
+                // The task using the stream wakes this one once it is done. The lock is
+                // tried again, in case it was released before the waker was registered.
+                self.pending_wakes.lock().unwrap().push(cx.waker().clone());
+                match self.stream.try_lock() {
+                    Some(stream) => stream,
+                    None => return Poll::Pending,
+                }
             }
+        };
+        if let Some(item) = self.items.get(index) {
+            return Some(item).into();
+        }
+        let poll = stream
+            .as_mut()
+            .poll_next(cx)
+            .map(|item| item.map(|item| self.items.push_get(Box::new(item))));
+        drop(stream);
+        if poll.is_ready() {
+            self.wake_pending();
         } else {
-            self.pending_wakes.borrow_mut().push(cx.waker().clone());
+            self.pending_wakes.lock().unwrap().push(cx.waker().clone());
         }
         poll
     }
+
+    fn wake_pending(&self) {
+        let wakers = std::mem::take(&mut *self.pending_wakes.lock().unwrap());
+        for waker in wakers {
+            waker.wake();
+        }
+    }
 }
 
 pub struct AsyncCacheStream<'a, S, R>
@@ -236,13 +239,9 @@ where
             }
             Ordering::Equal => {
                 // Get the next item from the stream
-                let item = ready!(self.cache.poll_next_item(cx));
+                let item = ready!(self.cache.poll_next_item(self.curr, cx));
                 self.curr += 1;
-                if let Some(item) = item {
-                    Some(self.cache.push_get(item)).into()
-                } else {
-                    None.into()
-                }
+                item.into()
             }
             Ordering::Greater => {
                 // Ran off the end of the cache
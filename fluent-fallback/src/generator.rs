@@ -1,11 +1,13 @@
-use fluent_bundle_for_carbide::{FluentBundle, FluentError, FluentResource};
+use fluent_bundle_for_carbide::{concurrent::FluentBundle, FluentError, FluentResource};
 use futures::Stream;
+use icu::locid::Locale;
 use rustc_hash::FxHashSet;
 use std::borrow::Borrow;
-use icu::locid::Locale;
 
 use crate::types::ResourceId;
 
+/// The bundles use the concurrent memoizer, so that a [`Localization`](crate::Localization)
+/// can be shared between threads.
 pub type FluentBundleResult<R> = Result<FluentBundle<R>, (FluentBundle<R>, Vec<FluentError>)>;
 
 pub trait BundleIterator {
@@ -45,7 +45,7 @@
 //! let value = bundles.format_value_sync("hello-world", None, &mut errors)
 //!     .expect("Failed to format a value");
 //!
-//! assert_eq!(value, Some("Hello World [en]".into()));
+//! assert_eq!(value.value, Some("Hello World [en]".into()));
 //! ```
 //!
 //! The above example is far from the ergonomical API style the Fluent project
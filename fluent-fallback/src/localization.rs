@@ -1,19 +1,22 @@
 use crate::{
     bundles::Bundles,
     env::LocalesProvider,
+    errors::LocalizationError,
     generator::{BundleGenerator, BundleIterator, BundleStream},
     types::ResourceId,
 };
+use fluent_bundle_for_carbide::FluentArgs;
 use once_cell::sync::OnceCell;
 use rustc_hash::FxHashSet;
-use std::rc::Rc;
+use std::borrow::Cow;
+use std::sync::Arc;
 
 pub struct Localization<G, P>
 where
     G: BundleGenerator<LocalesIter = P::Iter>,
     P: LocalesProvider,
 {
-    bundles: OnceCell<Rc<Bundles<G>>>,
+    bundles: OnceCell<Arc<Bundles<G>>>,
     generator: G,
     provider: P,
     sync: bool,
@@ -124,9 +127,9 @@ where
     G: BundleGenerator<LocalesIter = P::Iter>,
     P: LocalesProvider,
 {
-    pub fn bundles(&self) -> &Rc<Bundles<G>> {
+    pub fn bundles(&self) -> &Arc<Bundles<G>> {
         self.bundles.get_or_init(|| {
-            Rc::new(Bundles::new(
+            Arc::new(Bundles::new(
                 self.sync,
                 self.res_ids.clone(),
                 &self.generator,
@@ -134,4 +137,28 @@ where
             ))
         })
     }
+
+    /// Formats the values of many messages at once, e.g. all the messages of a screen.
+    /// The bundles are traversed once for all the messages, rather than once for each
+    /// message, and only the messages missing from a bundle are looked up in the next one.
+    ///
+    /// The result is parallel to `keys`, with `None` for the messages that have no value
+    /// in any of the bundles. If the localization is in async mode,
+    /// [`LocalizationError::SyncRequestInAsyncMode`] is reported instead.
+    pub fn format_values_sync<'l>(
+        &'l self,
+        keys: &[(&str, Option<&FluentArgs>)],
+        errors: &mut Vec<LocalizationError>,
+    ) -> Vec<Option<Cow<'l, str>>> {
+        self.bundles().format_values_by_id_sync(keys, errors)
+    }
+
+    /// Asynchronous version of [`Localization::format_values_sync`].
+    pub async fn format_values<'l>(
+        &'l self,
+        keys: &[(&str, Option<&FluentArgs<'_>>)],
+        errors: &mut Vec<LocalizationError>,
+    ) -> Vec<Option<Cow<'l, str>>> {
+        self.bundles().format_values_by_id(keys, errors).await
+    }
 }
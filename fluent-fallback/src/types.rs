@@ -1,4 +1,5 @@
 use fluent_bundle_for_carbide::FluentArgs;
+use icu::locid::Locale;
 use std::borrow::Cow;
 
 #[derive(Debug)]
@@ -16,6 +17,33 @@ impl<'l> From<&'l str> for L10nKey<'l> {
     }
 }
 
+/// The id and arguments of a message to format, either an [`L10nKey`] or a
+/// `(id, args)` pair.
+pub(crate) trait FormatKey {
+    fn id(&self) -> &str;
+    fn args(&self) -> Option<&FluentArgs<'_>>;
+}
+
+impl FormatKey for L10nKey<'_> {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn args(&self) -> Option<&FluentArgs<'_>> {
+        self.args.as_ref()
+    }
+}
+
+impl FormatKey for (&str, Option<&FluentArgs<'_>>) {
+    fn id(&self) -> &str {
+        self.0
+    }
+
+    fn args(&self) -> Option<&FluentArgs<'_>> {
+        self.1
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct L10nAttribute<'l> {
     pub name: Cow<'l, str>,
@@ -28,6 +56,16 @@ pub struct L10nMessage<'l> {
     pub attributes: Vec<L10nAttribute<'l>>,
 }
 
+/// A formatted value, together with the locale of the bundle it was found in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalizationResult<'l> {
+    pub value: Option<Cow<'l, str>>,
+    /// The locale the value was formatted in, which differs from the first
+    /// requested locale if the message fell back to another locale.
+    /// It is `None` if the message has no value in any of the locales.
+    pub locale_used: Option<&'l Locale>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ResourceType {
     /// This is a required resource.
@@ -2,8 +2,9 @@ use std::borrow::Cow;
 use std::fs;
 
 use fluent_bundle_for_carbide::{
+    concurrent::FluentBundle,
     resolver::errors::{ReferenceKind, ResolverError},
-    FluentArgs, FluentBundle, FluentError, FluentResource,
+    FluentArgs, FluentError, FluentResource,
 };
 use fluent_fallback_for_carbide::{
     env::LocalesProvider,
@@ -11,10 +12,10 @@ use fluent_fallback_for_carbide::{
     types::{L10nKey, ResourceId},
     Localization, LocalizationError,
 };
+use icu::locid::{locale, Locale};
 use rustc_hash::FxHashSet;
 use std::cell::RefCell;
 use std::rc::Rc;
-use icu::locid::{locale, Locale};
 
 struct InnerLocales {
     locales: RefCell<Vec<Locale>>,
@@ -65,7 +66,7 @@ impl Iterator for BundleIter {
     fn next(&mut self) -> Option<Self::Item> {
         let locale = self.locales.next()?;
 
-        let mut bundle = FluentBundle::new(vec![locale.clone()]);
+        let mut bundle = FluentBundle::new_concurrent(vec![locale.clone()]);
         bundle.set_use_isolating(false);
 
         let mut errors = vec![];
@@ -76,7 +77,7 @@ impl Iterator for BundleIter {
             let res = match FluentResource::try_new(source) {
                 Ok(res) => res,
                 Err((res, err)) => {
-                    errors.extend(err.into_iter().map(Into::into));
+                    errors.extend(err);
                     res
                 }
             };
@@ -98,7 +99,7 @@ impl futures::Stream for BundleIter {
         _cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Option<Self::Item>> {
         if let Some(locale) = self.locales.next() {
-            let mut bundle = FluentBundle::new(vec![locale.clone()]);
+            let mut bundle = FluentBundle::new_concurrent(vec![locale.clone()]);
             bundle.set_use_isolating(false);
 
             let mut errors = vec![];
@@ -108,7 +109,7 @@ impl futures::Stream for BundleIter {
                 let res = match FluentResource::try_new(source) {
                     Ok(res) => res,
                     Err((res, err)) => {
-                        errors.extend(err.into_iter().map(Into::into));
+                        errors.extend(err);
                         res
                     }
                 };
@@ -163,21 +164,64 @@ fn localization_format() {
     let value = bundles
         .format_value_sync("hello-world", None, &mut errors)
         .unwrap();
-    assert_eq!(value, Some(Cow::Borrowed("Hello World [pl]")));
+    assert_eq!(value.value, Some(Cow::Borrowed("Hello World [pl]")));
+    assert_eq!(value.locale_used, Some(&locale!("pl")));
 
     let value = bundles
         .format_value_sync("missing-message", None, &mut errors)
         .unwrap();
-    assert_eq!(value, None);
+    assert_eq!(value.value, None);
+    assert_eq!(value.locale_used, None);
 
     let value = bundles
         .format_value_sync("hello-world-3", None, &mut errors)
         .unwrap();
-    assert_eq!(value, Some(Cow::Borrowed("Hello World 3 [en]")));
+    assert_eq!(value.value, Some(Cow::Borrowed("Hello World 3 [en]")));
+    assert_eq!(value.locale_used, Some(&locale!("en-US")));
 
     assert_eq!(errors.len(), 4);
 }
 
+#[test]
+fn localization_format_values() {
+    let resource_ids: Vec<ResourceId> = vec!["test.ftl".into(), "test2.ftl".into()];
+    let locales = Locales::new(vec![locale!("pl"), locale!("en-US")]);
+    let res_mgr = ResourceManager;
+    let mut errors = vec![];
+
+    let loc = Localization::with_env(resource_ids, true, locales, res_mgr);
+
+    let mut args = FluentArgs::new();
+    args.set("userName", "John");
+
+    let values = loc.format_values_sync(
+        &[
+            ("hello-world", None),
+            ("missing-message", None),
+            ("hello-world-3", None),
+            ("message-4", Some(&args)),
+        ],
+        &mut errors,
+    );
+    assert_eq!(
+        values,
+        vec![
+            Some(Cow::Borrowed("Hello World [pl]")),
+            None,
+            Some(Cow::Borrowed("Hello World 3 [en]")),
+            Some(Cow::Owned("Hello, John. [pl]".to_string())),
+        ]
+    );
+    assert_eq!(errors.len(), 4);
+
+    errors.clear();
+    let locales = Locales::new(vec![locale!("en-US")]);
+    let loc = Localization::with_env(vec!["test.ftl".into()], false, locales, ResourceManager);
+    let values = loc.format_values_sync(&[("hello-world", None)], &mut errors);
+    assert_eq!(values, vec![None]);
+    assert_eq!(errors, vec![LocalizationError::SyncRequestInAsyncMode]);
+}
+
 #[test]
 fn localization_on_change() {
     let resource_ids: Vec<ResourceId> = vec!["test.ftl".into(), "test2.ftl".into()];
@@ -192,7 +236,7 @@ fn localization_on_change() {
     let value = bundles
         .format_value_sync("hello-world", None, &mut errors)
         .unwrap();
-    assert_eq!(value, Some(Cow::Borrowed("Hello World [en]")));
+    assert_eq!(value.value, Some(Cow::Borrowed("Hello World [en]")));
 
     locales.insert(0, locale!("pl"));
     loc.on_change();
@@ -201,7 +245,7 @@ fn localization_on_change() {
     let value = bundles
         .format_value_sync("hello-world", None, &mut errors)
         .unwrap();
-    assert_eq!(value, Some(Cow::Borrowed("Hello World [pl]")));
+    assert_eq!(value.value, Some(Cow::Borrowed("Hello World [pl]")));
 }
 
 #[test]
@@ -473,11 +517,12 @@ fn localization_format_missing_argument_error() {
         vec![LocalizationError::Resolver {
             id: "message-4".to_string(),
             locale: locale!("en-US"),
-            errors: vec![FluentError::ResolverError(ResolverError::Reference(
-                ReferenceKind::Variable {
+            errors: vec![FluentError::ResolverError {
+                id: Some("message-4".to_string()),
+                error: ResolverError::Reference(ReferenceKind::Variable {
                     id: "userName".to_string(),
-                }
-            ))],
+                }),
+            }],
         },]
     );
 }
@@ -512,7 +557,7 @@ fn localization_duplicate_resources() {
     let value = bundles
         .format_value_sync("hello-world", None, &mut errors)
         .unwrap();
-    assert_eq!(value, Some(Cow::Borrowed("Hello World [pl]")));
+    assert_eq!(value.value, Some(Cow::Borrowed("Hello World [pl]")));
 
     assert_eq!(errors.len(), 0, "There were no errors");
 }
@@ -20,16 +20,15 @@ pub mod negotiate;
 
 pub use accepted_languages::parse as parse_accepted_languages;
 pub use negotiate::negotiate_languages;
+pub use negotiate::negotiate_languages_with_scores;
 pub use negotiate::NegotiationStrategy;
 
 use icu::locid::{Locale, ParserError};
 
-pub fn convert_vec_str_to_locales<'a, I, J>(
-    input: I,
-) -> Result<Vec<Locale>, ParserError>
-    where
-        I: IntoIterator<Item = J>,
-        J: AsRef<[u8]> + 'a,
+pub fn convert_vec_str_to_locales<'a, I, J>(input: I) -> Result<Vec<Locale>, ParserError>
+where
+    I: IntoIterator<Item = J>,
+    J: AsRef<[u8]> + 'a,
 {
     input
         .into_iter()
@@ -38,12 +37,61 @@ pub fn convert_vec_str_to_locales<'a, I, J>(
 }
 
 pub fn convert_vec_str_to_locales_lossy<'a, I, J>(input: I) -> Vec<Locale>
-    where
-        I: IntoIterator<Item = J>,
-        J: AsRef<[u8]> + 'a,
+where
+    I: IntoIterator<Item = J>,
+    J: AsRef<[u8]> + 'a,
 {
     input
         .into_iter()
         .filter_map(|t| Locale::try_from_bytes(t.as_ref()).ok())
         .collect()
-}
\ No newline at end of file
+}
+
+/// Returns the best match for the `requested` locales among the `available` locales,
+/// using [`NegotiationStrategy::Lookup`].
+///
+/// If none of the available locales match, the `default` is returned instead.
+pub fn best_match(
+    requested: &[Locale],
+    available: &[Locale],
+    default: Option<&Locale>,
+) -> Option<Locale> {
+    negotiate_languages(requested, available, default, NegotiationStrategy::Lookup)
+        .first()
+        .map(|locale| (*locale).clone())
+}
+
+/// Returns true if any of the `available` locales matches the `locale`.
+pub fn is_locale_supported(locale: &Locale, available: &[Locale]) -> bool {
+    best_match(std::slice::from_ref(locale), available, None).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu::locid::locale;
+
+    #[test]
+    fn best_match_locales() {
+        let available = convert_vec_str_to_locales_lossy(["de", "fr", "en-GB", "en-US"]);
+        let default = locale!("en-US");
+
+        let requested = convert_vec_str_to_locales_lossy(["pl", "fr-CA", "en"]);
+        assert_eq!(
+            best_match(&requested, &available, Some(&default)),
+            Some(locale!("fr"))
+        );
+
+        let requested = convert_vec_str_to_locales_lossy(["pl"]);
+        assert_eq!(
+            best_match(&requested, &available, Some(&default)),
+            Some(default.clone())
+        );
+        assert_eq!(best_match(&requested, &available, None), None);
+        assert_eq!(best_match(&requested, &[], None), None);
+
+        assert!(is_locale_supported(&locale!("de-AT"), &available));
+        assert!(is_locale_supported(&locale!("en"), &available));
+        assert!(!is_locale_supported(&locale!("pl"), &available));
+    }
+}
@@ -1,39 +1,99 @@
 use icu::locid::Locale;
+use icu::locid_transform::{LocaleExpander, TransformResult};
 
-static REGION_MATCHING_KEYS: &[&str] = &[
-    "az", "bg", "cs", "de", "es", "fi", "fr", "hu", "it", "lt", "lv", "nl", "pl", "ro", "ru",
-];
-
-pub trait MockLikelySubtags {
+/// Adds and removes likely subtags using the CLDR data compiled into ICU.
+pub trait LikelySubtags {
+    /// Adds the likely script and region, e.g. `zh` becomes `zh-Hans-CN`.
+    ///
+    /// Returns true if the locale was modified. Locales without a language,
+    /// such as `und`, are never maximized.
     fn maximize(&mut self) -> bool;
+
+    /// Removes the subtags that would be added by [`LikelySubtags::maximize`],
+    /// e.g. `zh-Hans-CN` becomes `zh`.
+    ///
+    /// Returns true if the locale was modified.
+    fn minimize(&mut self) -> bool;
 }
 
-impl MockLikelySubtags for Locale {
+impl LikelySubtags for Locale {
     fn maximize(&mut self) -> bool {
-        let extended = match self.to_string().as_str() {
-            "en" => "en-Latn-US",
-            "fr" => "fr-Latn-FR",
-            "sr" => "sr-Cyrl-SR",
-            "sr-RU" => "sr-Latn-SR",
-            "az-IR" => "az-Arab-IR",
-            "zh-GB" => "zh-Hant-GB",
-            "zh-US" => "zh-Hant-US",
-            _ => {
-                let lang = self.id.language;
-
-                for subtag in REGION_MATCHING_KEYS {
-                    if lang.as_str() == *subtag {
-                        self.id.region = Some(subtag.parse().unwrap());
-                        return true;
-                    }
-                }
-                return false;
-            }
-        };
-        let langid: Locale = extended.parse().expect("Failed to parse langid.");
-        self.id.language = langid.id.language;
-        self.id.script = langid.id.script;
-        self.id.region = langid.id.region;
-        true
+        if self.id.language.is_empty() {
+            return false;
+        }
+        LocaleExpander::new().maximize(&mut self.id) == TransformResult::Modified
+    }
+
+    fn minimize(&mut self) -> bool {
+        LocaleExpander::new().minimize(&mut self.id) == TransformResult::Modified
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maximize_locales() {
+        let cases = [
+            ("en", "en-Latn-US"),
+            ("fr", "fr-Latn-FR"),
+            ("de", "de-Latn-DE"),
+            ("es", "es-Latn-ES"),
+            ("it", "it-Latn-IT"),
+            ("pt", "pt-Latn-BR"),
+            ("nl", "nl-Latn-NL"),
+            ("pl", "pl-Latn-PL"),
+            ("ru", "ru-Cyrl-RU"),
+            ("uk", "uk-Cyrl-UA"),
+            ("sr", "sr-Cyrl-RS"),
+            ("sr-ME", "sr-Latn-ME"),
+            ("el", "el-Grek-GR"),
+            ("tr", "tr-Latn-TR"),
+            ("az-IR", "az-Arab-IR"),
+            ("zh", "zh-Hans-CN"),
+            ("zh-TW", "zh-Hant-TW"),
+            ("zh-Hant", "zh-Hant-TW"),
+            ("ar", "ar-Arab-EG"),
+            ("he", "he-Hebr-IL"),
+            ("fa", "fa-Arab-IR"),
+            ("hi", "hi-Deva-IN"),
+            ("bn", "bn-Beng-BD"),
+            ("th", "th-Thai-TH"),
+            ("ja", "ja-Jpan-JP"),
+            ("ko", "ko-Kore-KR"),
+            ("sw", "sw-Latn-TZ"),
+        ];
+
+        for (input, expected) in cases {
+            let mut locale: Locale = input.parse().unwrap();
+            assert!(locale.maximize(), "{} was not maximized", input);
+            assert_eq!(locale.to_string(), expected);
+        }
+
+        let mut locale: Locale = "en-Latn-US".parse().unwrap();
+        assert!(!locale.maximize());
+
+        let mut locale = Locale::UND;
+        assert!(!locale.maximize());
+        assert_eq!(locale, Locale::UND);
+    }
+
+    #[test]
+    fn minimize_locales() {
+        let cases = [
+            ("en-Latn-US", "en"),
+            ("en-GB", "en-GB"),
+            ("zh-Hans-CN", "zh"),
+            ("zh-Hant-TW", "zh-TW"),
+            ("sr-Latn-RS", "sr-Latn"),
+            ("ar-Arab-EG", "ar"),
+        ];
+
+        for (input, expected) in cases {
+            let mut locale: Locale = input.parse().unwrap();
+            locale.minimize();
+            assert_eq!(locale.to_string(), expected);
+        }
+    }
+}
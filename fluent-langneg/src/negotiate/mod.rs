@@ -119,13 +119,13 @@
 //! ```
 //!
 
-use icu::locid::Locale;
+use std::borrow::Borrow;
+
 use icu::locid::subtags::{Language, Variants};
+use icu::locid::{LanguageIdentifier, Locale};
 
-#[cfg(not(feature = "cldr"))]
 mod likely_subtags;
-#[cfg(not(feature = "cldr"))]
-use likely_subtags::MockLikelySubtags;
+pub use likely_subtags::LikelySubtags;
 
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum NegotiationStrategy {
@@ -134,31 +134,56 @@ pub enum NegotiationStrategy {
     Lookup,
 }
 
-pub fn filter_matches<'a, R: 'a + AsRef<Locale>, A: 'a + AsRef<Locale>>(
+pub fn filter_matches<'a, R: 'a + Borrow<Locale>, A: 'a + Borrow<Locale>>(
     requested: &[R],
     available: &'a [A],
     strategy: NegotiationStrategy,
 ) -> Vec<&'a A> {
+    filter_matches_with_scores(requested, available, strategy)
+        .into_iter()
+        .map(|(locale, _)| locale)
+        .collect()
+}
+
+/// The scores of the 6 levels of the strategy, see [`negotiate_languages_with_scores`].
+const SCORE_EXACT: f32 = 1.0;
+const SCORE_AVAILABLE_RANGE: f32 = 0.9;
+const SCORE_MAXIMIZED: f32 = 0.5;
+const SCORE_VARIANT_RANGE: f32 = 0.8;
+const SCORE_MAXIMIZED_WITHOUT_REGION: f32 = 0.7;
+const SCORE_REGION_RANGE: f32 = 0.7;
+const SCORE_UND: f32 = 0.1;
+
+/// Like [`filter_matches`], with the score of the level at which each locale matched.
+fn filter_matches_with_scores<'a, R: 'a + Borrow<Locale>, A: 'a + Borrow<Locale>>(
+    requested: &[R],
+    available: &'a [A],
+    strategy: NegotiationStrategy,
+) -> Vec<(&'a A, f32)> {
     let mut supported_locales = vec![];
 
-    let mut available_locales: Vec<&A> = available.iter().collect();
+    // Undetermined locales are kept aside, they are only used as the last fallback.
+    let (und_locales, mut available_locales): (Vec<&A>, Vec<&A>) = available
+        .iter()
+        .partition(|locale| is_und((*locale).borrow()));
 
     for req in requested {
-        let mut req = req.as_ref().to_owned();
+        let mut req = req.borrow().to_owned();
         macro_rules! test_strategy {
-            ($self_as_range:expr, $other_as_range:expr) => {{
+            ($self_as_range:expr, $other_as_range:expr, $score:expr) => {{
                 let mut match_found = false;
                 available_locales.retain(|locale| {
                     if strategy != NegotiationStrategy::Filtering && match_found {
                         return true;
                     }
 
-                    if locale
-                        .as_ref()
-                        .matches(&req, $self_as_range, $other_as_range)
-                    {
+                    if Borrow::<Locale>::borrow(*locale).matches(
+                        &req,
+                        $self_as_range,
+                        $other_as_range,
+                    ) {
                         match_found = true;
-                        supported_locales.push(*locale);
+                        supported_locales.push((*locale, $score));
                         return false;
                     }
                     true
@@ -175,10 +200,10 @@ pub fn filter_matches<'a, R: 'a + AsRef<Locale>, A: 'a + AsRef<Locale>>(
         }
 
         // 1) Try to find a simple (case-insensitive) string match for the request.
-        test_strategy!(false, false);
+        test_strategy!(false, false, SCORE_EXACT);
 
         // 2) Try to match against the available locales treated as ranges.
-        test_strategy!(true, false);
+        test_strategy!(true, false, SCORE_AVAILABLE_RANGE);
 
         // Per Unicode TR35, 4.4 Locale Matching, we don't add likely subtags to
         // requested locales, so we'll skip it from the rest of the steps.
@@ -188,7 +213,7 @@ pub fn filter_matches<'a, R: 'a + AsRef<Locale>, A: 'a + AsRef<Locale>>(
 
         // 3) Try to match against a maximized version of the requested locale
         if req.maximize() {
-            test_strategy!(true, false);
+            test_strategy!(true, false, SCORE_MAXIMIZED);
         }
 
         // 4) Try to match against a variant as a range
@@ -200,12 +225,9 @@ pub fn filter_matches<'a, R: 'a + AsRef<Locale>, A: 'a + AsRef<Locale>>(
                     return true;
                 }
 
-                if locale
-                    .as_ref()
-                    .matches(&req, true, true)
-                {
+                if Borrow::<Locale>::borrow(*locale).matches(&req, true, true) {
                     match_found = true;
-                    supported_locales.push(*locale);
+                    supported_locales.push((*locale, SCORE_VARIANT_RANGE));
                     return false;
                 }
                 true
@@ -223,22 +245,49 @@ pub fn filter_matches<'a, R: 'a + AsRef<Locale>, A: 'a + AsRef<Locale>>(
         // 5) Try to match against the likely subtag without region
         req.id.region = None;
         if req.maximize() {
-            test_strategy!(true, false);
+            test_strategy!(true, false, SCORE_MAXIMIZED_WITHOUT_REGION);
         }
 
         // 6) Try to match against a region as a range
         req.id.region = None;
-        test_strategy!(true, true);
+        test_strategy!(true, true, SCORE_REGION_RANGE);
+    }
+
+    // An undetermined requested locale accepts any of the remaining locales, but only
+    // after all other requested locales were negotiated.
+    if requested.iter().any(|req| is_und(req.borrow())) {
+        match strategy {
+            NegotiationStrategy::Filtering => supported_locales.extend(
+                available_locales
+                    .into_iter()
+                    .map(|locale| (locale, SCORE_UND)),
+            ),
+            NegotiationStrategy::Lookup if !supported_locales.is_empty() => {}
+            NegotiationStrategy::Matching | NegotiationStrategy::Lookup => {
+                if !available_locales.is_empty() {
+                    supported_locales.push((available_locales.remove(0), SCORE_UND));
+                }
+            }
+        }
+    }
+
+    match strategy {
+        NegotiationStrategy::Lookup => {
+            if supported_locales.is_empty() {
+                supported_locales.extend(und_locales.first().map(|locale| (*locale, SCORE_UND)));
+            }
+        }
+        _ => supported_locales.extend(und_locales.into_iter().map(|locale| (locale, SCORE_UND))),
     }
 
     supported_locales
 }
 
-pub fn negotiate_languages<
-    'a,
-    R: 'a + AsRef<Locale>,
-    A: 'a + AsRef<Locale> + PartialEq,
->(
+fn is_und(locale: &Locale) -> bool {
+    locale.id == LanguageIdentifier::UND
+}
+
+pub fn negotiate_languages<'a, R: 'a + Borrow<Locale>, A: 'a + Borrow<Locale> + PartialEq>(
     requested: &[R],
     available: &'a [A],
     default: Option<&'a A>,
@@ -258,41 +307,88 @@ pub fn negotiate_languages<
     supported
 }
 
+/// Negotiates the locales like [`negotiate_languages`] without a default, and pairs each
+/// supported locale with a score from `0.0` to `1.0` of how well it matches the requested
+/// locale, e.g. to warn users when content is not in a language close to the one they prefer.
+///
+/// The score depends on the level of the strategy, see the [module](self) documentation,
+/// at which the locale matched:
+///
+/// | Level                                  | Example                     | Score |
+/// |----------------------------------------|-----------------------------|-------|
+/// | 1) Exact match                         | `en-US` for `en-US`         | 1.0   |
+/// | 2) Available locale as a range         | `en` for `en-US`            | 0.9   |
+/// | 3) Maximized requested locale          | `en-US` for `en`            | 0.5   |
+/// | 4) Different variant                   | `ja-JP-mac` for `ja-JP-win` | 0.8   |
+/// | 5) Maximized locale without the region | `en-US` for `en-CA`         | 0.7   |
+/// | 6) Different region                    | `en-AU` for `en-GB`         | 0.7   |
+/// | Undetermined locales                   | any for `und`, or `und`     | 0.1   |
+///
+/// A locale of the same language in a different region scores 0.7, and a locale
+/// only found by adding the likely subtags to the requested one scores 0.5. The
+/// locales are still returned in the order of the levels, so a later locale can
+/// have a higher score than an earlier one.
+///
+/// # Example
+///
+/// ```
+/// use fluent_langneg_for_carbide::negotiate::negotiate_languages_with_scores;
+/// use fluent_langneg_for_carbide::NegotiationStrategy;
+/// use icu::locid::locale;
+///
+/// let requested = [locale!("de-AT"), locale!("en-GB")];
+/// let available = [locale!("en-AU"), locale!("de-AT")];
+///
+/// let supported = negotiate_languages_with_scores(&requested, &available, NegotiationStrategy::Filtering);
+/// assert_eq!(supported, vec![(locale!("de-AT"), 1.0), (locale!("en-AU"), 0.7)]);
+/// ```
+pub fn negotiate_languages_with_scores<R: Borrow<Locale>, A: Borrow<Locale>>(
+    requested: &[R],
+    available: &[A],
+    strategy: NegotiationStrategy,
+) -> Vec<(Locale, f32)> {
+    filter_matches_with_scores(requested, available, strategy)
+        .into_iter()
+        .map(|(locale, score)| (locale.borrow().clone(), score))
+        .collect()
+}
 
 trait Matches {
-    fn matches(
-        &self,
-        other: &Self,
-        self_as_range: bool,
-        other_as_range: bool,
-    ) -> bool;
+    fn matches(&self, other: &Self, self_as_range: bool, other_as_range: bool) -> bool;
 }
 
 impl Matches for Locale {
     fn matches(&self, other: &Self, self_as_range: bool, other_as_range: bool) -> bool {
-        self.id.language
+        self.id
+            .language
             .matches(&other.id.language, self_as_range, other_as_range)
-            && subtag_matches(&self.id.script, &other.id.script, self_as_range, other_as_range)
-            && subtag_matches(&self.id.region, &other.id.region, self_as_range, other_as_range)
+            && subtag_matches(
+                &self.id.script,
+                &other.id.script,
+                self_as_range,
+                other_as_range,
+            )
+            && subtag_matches(
+                &self.id.region,
+                &other.id.region,
+                self_as_range,
+                other_as_range,
+            )
             && subtags_match(
-            &self.id.variants,
-            &other.id.variants,
-            self_as_range,
-            other_as_range,
-        )
+                &self.id.variants,
+                &other.id.variants,
+                self_as_range,
+                other_as_range,
+            )
     }
 }
 
 impl Matches for Language {
     fn matches(&self, other: &Self, self_as_range: bool, other_as_range: bool) -> bool {
-        (self_as_range && self.is_empty())
-            || (other_as_range && other.is_empty())
-            || self == other
+        (self_as_range && self.is_empty()) || (other_as_range && other.is_empty()) || self == other
     }
 }
 
-
-
 fn subtag_matches<P: PartialEq>(
     subtag1: &Option<P>,
     subtag2: &Option<P>,
@@ -302,14 +398,102 @@ fn subtag_matches<P: PartialEq>(
     (as_range1 && subtag1.is_none()) || (as_range2 && subtag2.is_none()) || subtag1 == subtag2
 }
 
-fn subtags_match(
-    subtag1: &Variants,
-    subtag2: &Variants,
-    as_range1: bool,
-    as_range2: bool,
-) -> bool {
+fn subtags_match(subtag1: &Variants, subtag2: &Variants, as_range1: bool, as_range2: bool) -> bool {
     // or is some and is empty!
-    (as_range1 && subtag1.is_empty())
-        || (as_range2 && subtag2.is_empty())
-        || subtag1 == subtag2
-}
\ No newline at end of file
+    (as_range1 && subtag1.is_empty()) || (as_range2 && subtag2.is_empty()) || subtag1 == subtag2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert_vec_str_to_locales_lossy;
+
+    #[test]
+    fn negotiate_und() {
+        let available = convert_vec_str_to_locales_lossy(["en-US", "fr"]);
+
+        let requested = convert_vec_str_to_locales_lossy(["und"]);
+        let supported =
+            negotiate_languages(&requested, &available, None, NegotiationStrategy::Filtering);
+        assert_eq!(supported, available.iter().collect::<Vec<_>>());
+
+        let supported =
+            negotiate_languages(&requested, &available, None, NegotiationStrategy::Lookup);
+        assert_eq!(supported, vec![&available[0]]);
+
+        let requested = convert_vec_str_to_locales_lossy(["fr", "und"]);
+        let supported =
+            negotiate_languages(&requested, &available, None, NegotiationStrategy::Filtering);
+        assert_eq!(supported, vec![&available[1], &available[0]]);
+
+        let supported =
+            negotiate_languages(&requested, &available, None, NegotiationStrategy::Matching);
+        assert_eq!(supported, vec![&available[1], &available[0]]);
+
+        let available = convert_vec_str_to_locales_lossy(["und", "de", "fr"]);
+        let requested = convert_vec_str_to_locales_lossy(["fr-CA", "pl"]);
+        let supported =
+            negotiate_languages(&requested, &available, None, NegotiationStrategy::Filtering);
+        assert_eq!(supported, vec![&available[2], &available[0]]);
+
+        let requested = convert_vec_str_to_locales_lossy(["pl"]);
+        let supported =
+            negotiate_languages(&requested, &available, None, NegotiationStrategy::Lookup);
+        assert_eq!(supported, vec![&available[0]]);
+    }
+
+    #[test]
+    fn negotiate_scores() {
+        let scores = |requested: &[&str], available: &[&str], strategy| {
+            let requested = convert_vec_str_to_locales_lossy(requested);
+            let available = convert_vec_str_to_locales_lossy(available);
+            negotiate_languages_with_scores(&requested, &available, strategy)
+                .into_iter()
+                .map(|(locale, score)| (locale.to_string(), score))
+                .collect::<Vec<_>>()
+        };
+        let filtering = NegotiationStrategy::Filtering;
+
+        assert_eq!(
+            scores(&["en-US"], &["en-US"], filtering),
+            vec![("en-US".into(), 1.0)]
+        );
+        assert_eq!(
+            scores(&["en-US"], &["en"], filtering),
+            vec![("en".into(), 0.9)]
+        );
+        assert_eq!(
+            scores(&["en"], &["en-GB", "en-US"], filtering),
+            vec![("en-US".into(), 0.5), ("en-GB".into(), 0.7),]
+        );
+        assert_eq!(
+            scores(&["ja-JP-windows"], &["ja-JP-macos"], filtering),
+            vec![("ja-JP-macos".into(), 0.8)]
+        );
+        assert_eq!(
+            scores(&["en-CA"], &["en-ZA", "en-US"], filtering),
+            vec![("en-US".into(), 0.7), ("en-ZA".into(), 0.7),]
+        );
+        assert_eq!(
+            scores(&["pl", "und"], &["fr", "und"], filtering),
+            vec![("fr".into(), 0.1), ("und".into(), 0.1),]
+        );
+        assert_eq!(scores(&["pl"], &["fr"], filtering), vec![]);
+
+        // The scores don't change the negotiated locales.
+        let requested = convert_vec_str_to_locales_lossy(["de-AT", "fr", "en"]);
+        let available = convert_vec_str_to_locales_lossy(["en-GB", "fr-CA", "de", "en-US"]);
+        for strategy in [
+            NegotiationStrategy::Filtering,
+            NegotiationStrategy::Matching,
+            NegotiationStrategy::Lookup,
+        ] {
+            let supported = negotiate_languages(&requested, &available, None, strategy);
+            let scored = negotiate_languages_with_scores(&requested, &available, strategy);
+            assert_eq!(
+                scored.iter().map(|(locale, _)| locale).collect::<Vec<_>>(),
+                supported
+            );
+        }
+    }
+}
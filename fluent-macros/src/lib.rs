@@ -0,0 +1,70 @@
+//! Macros for `fluent`, use them through the `macros` feature of `fluent`.
+
+use fluent_syntax_for_carbide::parser::{parse_runtime, ParserError};
+use proc_macro::TokenStream;
+use quote::quote;
+use std::path::Path;
+use std::{env, fs};
+use syn::{parse_macro_input, LitStr};
+
+/// Embeds an `.ftl` file into the binary as a `FluentResource`, checking its
+/// syntax at compile time.
+///
+/// The path is relative to the crate root. Syntax errors are reported as compile
+/// errors with the line and column in the file, and changes to the file trigger
+/// a recompilation.
+#[proc_macro]
+pub fn include_ftl(input: TokenStream) -> TokenStream {
+    let file = parse_macro_input!(input as LitStr);
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let path = Path::new(&manifest_dir).join(file.value());
+
+    let source = match fs::read_to_string(&path) {
+        Ok(source) => source,
+        Err(err) => {
+            return syn::Error::new(
+                file.span(),
+                format!("Failed to read {}: {}", path.display(), err),
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    if let Err((_, errors)) = parse_runtime(source.as_str()) {
+        let mut errors = errors
+            .iter()
+            .map(|err| syn::Error::new(file.span(), describe_error(err, &path, &source)));
+        let mut combined = errors
+            .next()
+            .expect("Parsing failed with at least one error.");
+        combined.extend(errors);
+        return combined.to_compile_error().into();
+    }
+
+    let path = path.to_string_lossy();
+    quote! {
+        {
+            // Makes cargo rebuild the crate when the file changes.
+            const _: &[u8] = include_bytes!(#path);
+
+            ::fluent_for_carbide::FluentResource::try_new(include_str!(#path).to_string())
+                .expect("The resource was parsed at compile time.")
+        }
+    }
+    .into()
+}
+
+fn describe_error(err: &ParserError, path: &Path, source: &str) -> String {
+    let before = &source[..err.pos.start.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + 1;
+
+    format!("{}:{}:{}: {}", path.display(), line, column, err)
+}
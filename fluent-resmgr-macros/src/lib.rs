@@ -0,0 +1,89 @@
+//! Macros for `fluent-resmgr`, use them through the `embed` feature of `fluent-resmgr`.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+use syn::{parse_macro_input, LitStr};
+
+/// Embeds all the `.ftl` files of a directory into the binary, creating a
+/// `ResourceManager` with an `EmbeddedLoader`.
+///
+/// The directory is relative to the crate root and is organized as
+/// `{locale}/{res_id}`, where the resource id may contain subdirectories.
+#[proc_macro]
+pub fn embed(input: TokenStream) -> TokenStream {
+    let dir = parse_macro_input!(input as LitStr);
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let root = Path::new(&manifest_dir).join(dir.value());
+
+    let files = match collect_files(&root) {
+        Ok(files) => files,
+        Err(err) => {
+            return syn::Error::new(
+                dir.span(),
+                format!(
+                    "Failed to read the resources in {}: {}",
+                    root.display(),
+                    err
+                ),
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    let files = files.iter().map(|(locale, res_id, path)| {
+        let path = path.to_string_lossy();
+        quote! { (#locale, #res_id, include_str!(#path)) }
+    });
+
+    quote! {
+        ::fluent_resmgr_for_carbide::ResourceManager::new_with_loader(
+            ::fluent_resmgr_for_carbide::EmbeddedLoader::new(&[#(#files),*])
+        )
+    }
+    .into()
+}
+
+/// Returns the locale, resource id and path of every `.ftl` file, sorted by locale
+/// and resource id.
+fn collect_files(root: &Path) -> io::Result<Vec<(String, String, PathBuf)>> {
+    let mut files = vec![];
+
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let locale = entry.file_name().to_string_lossy().into_owned();
+        let mut paths = vec![];
+        collect_ftl_paths(&entry.path(), &mut paths)?;
+
+        for path in paths {
+            let res_id = path
+                .strip_prefix(entry.path())
+                .expect("The path is inside the locale directory.")
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.push((locale.clone(), res_id, path));
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+fn collect_ftl_paths(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_ftl_paths(&path, paths)?;
+        } else if path.extension().is_some_and(|extension| extension == "ftl") {
+            paths.push(fs::canonicalize(path)?);
+        }
+    }
+    Ok(())
+}
@@ -1,3 +1,14 @@
+pub mod loader;
 pub mod resource_manager;
 
+pub use loader::{EmbeddedLoader, FilesystemLoader, InMemoryLoader, LoadError, ResourceLoader};
 pub use resource_manager::ResourceManager;
+
+/// Embeds the `.ftl` files of a directory, creating a [`ResourceManager`] with an
+/// [`EmbeddedLoader`].
+///
+/// ```ignore
+/// let mgr = fluent_resmgr::embed!("./translations");
+/// ```
+#[cfg(feature = "embed")]
+pub use fluent_resmgr_macros_for_carbide::embed;
@@ -0,0 +1,343 @@
+use icu::locid::Locale;
+use rustc_hash::FxHashMap;
+use std::path::Path;
+use std::{fs, io};
+use thiserror::Error;
+
+/// Loads the source of localization resources for a
+/// [`ResourceManager`](crate::ResourceManager), e.g. from the filesystem, a database
+/// or memory.
+pub trait ResourceLoader {
+    /// Returns the source of the resource `resource_id` in the `locale`.
+    fn load(&self, locale: &Locale, resource_id: &str) -> Result<String, LoadError>;
+
+    /// Describes where the resource is loaded from, used in error messages.
+    fn location(&self, locale: &Locale, resource_id: &str) -> String {
+        format!("{}/{}", locale, resource_id)
+    }
+}
+
+/// Errors generated while loading the source of a resource
+#[derive(Error, Debug)]
+pub enum LoadError {
+    /// The resource does not exist at the location
+    #[error("The resource {0} was not found")]
+    NotFound(String),
+
+    /// Error while reading the resource file at the path
+    #[error("Failed to read {0}: {1}")]
+    Io(String, #[source] io::Error),
+
+    /// Any other error of a custom loader
+    #[error("{0}")]
+    Other(#[from] Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// [FilesystemLoader] reads the resources from files, organized by a path scheme
+/// such as `"./translations/{locale}/{res_id}"`.
+///
+/// Besides `{locale}`, the subtags of the locale can be used with `{language}`,
+/// `{script}` and `{region}`. A missing script or region expands to nothing, so
+/// `"./l10n/{language}/{script}/{res_id}"` loads `./l10n/sr/Latn/app.ftl` for `sr-Latn`
+/// and `./l10n/en/app.ftl` for `en`. If the file for the full locale doesn't exist,
+/// the region and then the script are left out, e.g. `sr-Latn-RS` falls back to
+/// `./l10n/sr/Latn/app.ftl` and then to `./l10n/sr/app.ftl`.
+pub struct FilesystemLoader {
+    path_scheme: String,
+}
+
+impl FilesystemLoader {
+    pub fn new(path_scheme: String) -> Self {
+        FilesystemLoader { path_scheme }
+    }
+
+    pub fn path_scheme(&self) -> &str {
+        &self.path_scheme
+    }
+
+    /// Returns the path of the first file that exists for the resource, or the path
+    /// for the full locale if there is none.
+    pub fn resource_path(&self, locale: &Locale, resource_id: &str) -> String {
+        let mut paths = self.resource_paths(resource_id, locale);
+        let idx = paths
+            .iter()
+            .position(|path| Path::new(path).is_file())
+            .unwrap_or(0);
+        paths.swap_remove(idx)
+    }
+
+    /// Asynchronous version of [`ResourceLoader::load`], reading the file without
+    /// blocking the runtime.
+    #[cfg(feature = "async-tokio")]
+    pub async fn load_async(
+        &self,
+        locale: &Locale,
+        resource_id: &str,
+    ) -> Result<String, LoadError> {
+        let mut first_error = None;
+
+        for path in self.resource_paths(resource_id, locale) {
+            match tokio::fs::read_to_string(&path).await {
+                Ok(source) => return Ok(source),
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                    first_error.get_or_insert(LoadError::Io(path, error));
+                }
+                Err(error) => return Err(LoadError::Io(path, error)),
+            }
+        }
+
+        Err(first_error.expect("There is at least one path."))
+    }
+
+    /// Returns the locales that have a directory in the path scheme, by listing the
+    /// directory before the `{locale}` placeholder. Directory names that are not valid
+    /// locales are skipped.
+    ///
+    /// Returns an [`io::ErrorKind::InvalidInput`] error if the path scheme has no
+    /// `{locale}` placeholder.
+    pub fn available_locales(&self) -> Result<Vec<Locale>, io::Error> {
+        let (dir, prefix, suffix) =
+            split_path_scheme(&self.path_scheme, "{locale}").ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "The path scheme has no {locale} placeholder.",
+                )
+            })?;
+        // Only the rest of the path component belongs to the directory name.
+        let suffix = suffix.split('/').next().unwrap_or_default();
+
+        let mut locales = vec![];
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if !entry.file_type()?.is_dir() {
+                continue;
+            }
+            let name = entry.file_name();
+            let locale = name
+                .to_str()
+                .and_then(|name| name.strip_prefix(prefix))
+                .and_then(|name| name.strip_suffix(suffix))
+                .and_then(|name| name.parse::<Locale>().ok());
+            if let Some(locale) = locale {
+                locales.push(locale);
+            }
+        }
+
+        locales.sort_by_cached_key(|locale| locale.to_string());
+        Ok(locales)
+    }
+
+    /// Returns the ids of the resources available for the `locale`, including the
+    /// resources found by leaving out the region and the script.
+    pub fn available_resources_for(&self, locale: &Locale) -> Result<Vec<String>, io::Error> {
+        let mut resource_ids = vec![];
+        // The directories of the more specific paths are skipped when listing the
+        // directories of the fallback paths.
+        let mut dirs: Vec<&Path> = vec![];
+
+        let paths = self.resource_paths("{res_id}", locale);
+        for path in &paths {
+            let Some((dir, prefix, suffix)) = split_path_scheme(path, "{res_id}") else {
+                continue;
+            };
+            let mut files = vec![];
+            let result = collect_files(dir.as_ref(), "", &dirs, &mut files);
+            dirs.push(dir.as_ref());
+            match result {
+                Ok(()) => {}
+                Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+                Err(error) => return Err(error),
+            }
+
+            for file in files {
+                let resource_id = file
+                    .strip_prefix(prefix)
+                    .and_then(|file| file.strip_suffix(suffix));
+                if let Some(resource_id) = resource_id {
+                    if !resource_ids.iter().any(|id| id == resource_id) {
+                        resource_ids.push(resource_id.to_string());
+                    }
+                }
+            }
+        }
+
+        resource_ids.sort();
+        Ok(resource_ids)
+    }
+
+    /// Returns the paths to try for the resource, starting with the full locale and
+    /// then leaving out the region and the script.
+    fn resource_paths(&self, resource_id: &str, locale: &Locale) -> Vec<String> {
+        let script = locale
+            .id
+            .script
+            .as_ref()
+            .map_or("", |script| script.as_str());
+        let region = locale
+            .id
+            .region
+            .as_ref()
+            .map_or("", |region| region.as_str());
+
+        let mut paths: Vec<String> = vec![];
+        for (script, region) in [(script, region), (script, ""), ("", "")] {
+            let path = self.expand_path(resource_id, locale, script, region);
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    fn expand_path(
+        &self,
+        resource_id: &str,
+        locale: &Locale,
+        script: &str,
+        region: &str,
+    ) -> String {
+        let path = self
+            .path_scheme
+            .replace("{locale}", &locale.to_string())
+            .replace("{language}", locale.id.language.as_str())
+            .replace("{script}", script)
+            .replace("{region}", region)
+            .replace("{res_id}", resource_id);
+
+        // Empty subtags leave consecutive slashes behind.
+        let mut normalized = String::with_capacity(path.len());
+        for c in path.chars() {
+            if c != '/' || !normalized.ends_with('/') {
+                normalized.push(c);
+            }
+        }
+        normalized
+    }
+}
+
+impl ResourceLoader for FilesystemLoader {
+    fn load(&self, locale: &Locale, resource_id: &str) -> Result<String, LoadError> {
+        let mut first_error = None;
+
+        for path in self.resource_paths(resource_id, locale) {
+            match fs::read_to_string(&path) {
+                Ok(source) => return Ok(source),
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                    first_error.get_or_insert(LoadError::Io(path, error));
+                }
+                Err(error) => return Err(LoadError::Io(path, error)),
+            }
+        }
+
+        Err(first_error.expect("There is at least one path."))
+    }
+
+    fn location(&self, locale: &Locale, resource_id: &str) -> String {
+        self.resource_path(locale, resource_id)
+    }
+}
+
+/// [InMemoryLoader] serves the resources from memory, which is mostly useful for testing.
+#[derive(Default)]
+pub struct InMemoryLoader {
+    /// The sources by locale and resource id.
+    resources: FxHashMap<(String, String), String>,
+}
+
+impl InMemoryLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the source of the resource `resource_id` in the `locale`.
+    pub fn insert(&mut self, locale: &Locale, resource_id: &str, source: String) {
+        self.resources
+            .insert((locale.to_string(), resource_id.to_string()), source);
+    }
+}
+
+impl<I> From<I> for InMemoryLoader
+where
+    I: IntoIterator<Item = ((String, String), String)>,
+{
+    fn from(resources: I) -> Self {
+        InMemoryLoader {
+            resources: resources.into_iter().collect(),
+        }
+    }
+}
+
+impl ResourceLoader for InMemoryLoader {
+    fn load(&self, locale: &Locale, resource_id: &str) -> Result<String, LoadError> {
+        self.resources
+            .get(&(locale.to_string(), resource_id.to_string()))
+            .cloned()
+            .ok_or_else(|| LoadError::NotFound(self.location(locale, resource_id)))
+    }
+}
+
+/// [EmbeddedLoader] serves the resources that are embedded into the binary. It is usually
+/// created with the [`embed!`](crate::embed) macro, which collects the `.ftl` files of a
+/// directory at compile time.
+pub struct EmbeddedLoader {
+    /// The locale, resource id and source of every embedded file.
+    files: &'static [(&'static str, &'static str, &'static str)],
+}
+
+impl EmbeddedLoader {
+    /// Create a new [`EmbeddedLoader`] from the locale, resource id and source of every
+    /// embedded file.
+    pub const fn new(files: &'static [(&'static str, &'static str, &'static str)]) -> Self {
+        EmbeddedLoader { files }
+    }
+}
+
+impl ResourceLoader for EmbeddedLoader {
+    fn load(&self, locale: &Locale, resource_id: &str) -> Result<String, LoadError> {
+        let locale_string = locale.to_string();
+        self.files
+            .iter()
+            .find(|(l, r, _)| *l == locale_string && *r == resource_id)
+            .map(|(_, _, source)| source.to_string())
+            .ok_or_else(|| LoadError::NotFound(self.location(locale, resource_id)))
+    }
+}
+
+/// Splits the path at the `placeholder` into the directory before it, and the text
+/// before and after the placeholder that is not part of the directory.
+fn split_path_scheme<'a>(path: &'a str, placeholder: &str) -> Option<(&'a str, &'a str, &'a str)> {
+    let (before, after) = path.split_once(placeholder)?;
+    Some(match before.rfind('/') {
+        Some(idx) => (&before[..=idx], &before[idx + 1..], after),
+        None => (".", before, after),
+    })
+}
+
+/// Collects the paths of all the files in `dir` and its subdirectories, relative to `dir`.
+/// The `excluded` directories are skipped.
+fn collect_files(
+    dir: &Path,
+    relative: &str,
+    excluded: &[&Path],
+    files: &mut Vec<String>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let Some(name) = entry
+            .file_name()
+            .to_str()
+            .map(|name| format!("{}{}", relative, name))
+        else {
+            continue;
+        };
+        if entry.file_type()?.is_dir() {
+            let path = entry.path();
+            if !excluded.contains(&path.as_path()) {
+                collect_files(&path, &format!("{}/", name), excluded, files)?;
+            }
+        } else {
+            files.push(name);
+        }
+    }
+    Ok(())
+}
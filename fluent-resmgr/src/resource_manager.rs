@@ -1,25 +1,31 @@
-use elsa::FrozenMap;
-use fluent_bundle_for_carbide::{FluentBundle, FluentResource};
+use elsa::sync::{FrozenMap, FrozenVec};
+use fluent_bundle_for_carbide::{concurrent, FluentBundle, FluentError, FluentResource};
 use fluent_fallback_for_carbide::{
     generator::{BundleGenerator, FluentBundleResult},
     types::ResourceId,
 };
+use fluent_syntax_for_carbide::parser::ParserError;
 use futures::stream::Stream;
-use rustc_hash::FxHashSet;
-use std::io;
-use std::{fs, iter};
-use thiserror::Error;
+#[cfg(feature = "async-tokio")]
+use futures::StreamExt;
 use icu::locid::Locale;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{fs, io};
+use thiserror::Error;
 
-fn read_file(path: &str) -> Result<String, io::Error> {
-    fs::read_to_string(path)
-}
+use crate::loader::{FilesystemLoader, LoadError, ResourceLoader};
 
 /// [ResourceManager] provides a standalone solution for managing localization resources which
-/// can be used by `fluent-fallback` or other higher level bindings.
-pub struct ResourceManager {
+/// can be used by `fluent-fallback` or other higher level bindings. The sources of the
+/// resources are provided by a [`ResourceLoader`], which reads them from the filesystem
+/// by default.
+pub struct ResourceManager<L = FilesystemLoader> {
+    loader: L,
     resources: FrozenMap<String, Box<FluentResource>>,
-    path_scheme: String,
+    /// The locations and ids of the loaded resources.
+    loaded: FrozenVec<Box<(String, String)>>,
+    /// The locales used to load the resources of aliased locales.
+    aliases: FxHashMap<String, Locale>,
 }
 
 impl ResourceManager {
@@ -32,40 +38,102 @@ impl ResourceManager {
     ///
     /// .
     /// └── translations
-    ///     ├── en-US
-    ///     │   ├── app.ftl
-    ///     │   └── errors.ftl
-    ///     └── pl
-    ///         ├── app.ftl
-    ///         └── errors.ftl
+    ///     ├── en-US
+    ///     │   ├── app.ftl
+    ///     │   └── errors.ftl
+    ///     └── pl
+    ///         ├── app.ftl
+    ///         └── errors.ftl
     ///
+    /// See [`FilesystemLoader`] for the other placeholders of the path scheme.
     pub fn new(path_scheme: String) -> Self {
+        Self::new_with_loader(FilesystemLoader::new(path_scheme))
+    }
+}
+
+impl<L: ResourceLoader> ResourceManager<L> {
+    /// Create a new and empty [`ResourceManager`] that loads the resources with a
+    /// custom [`ResourceLoader`], e.g. from a database or over the network.
+    pub fn new_with_loader(loader: L) -> Self {
         ResourceManager {
+            loader,
             resources: FrozenMap::new(),
-            path_scheme,
+            loaded: FrozenVec::new(),
+            aliases: FxHashMap::default(),
         }
     }
 
-    /// Returns a [`FluentResource`], by either reading the file and loading it into
-    /// memory, or retrieving it from an in-memory cache.
+    /// Builder variant of [`ResourceManager::add_alias`].
+    pub fn with_alias(mut self, short: &str, full: Locale) -> Self {
+        self.add_alias(short, full);
+        self
+    }
+
+    /// Loads the resources of the `short` locale, e.g. `en`, from the files of the
+    /// `full` locale, e.g. `en-US`. The bundles keep the requested locale.
+    pub fn add_alias(&mut self, short: &str, full: Locale) {
+        self.aliases.insert(short.to_string(), full);
+    }
+
+    /// Returns the locale the resources of `locale` are loaded from.
+    fn resolve_alias(&self, locale: &Locale) -> Locale {
+        self.aliases
+            .get(&locale.to_string())
+            .cloned()
+            .unwrap_or_else(|| locale.clone())
+    }
+
+    /// Returns the [`ResourceLoader`] of the manager.
+    pub fn loader(&self) -> &L {
+        &self.loader
+    }
+
+    /// Returns a [`FluentResource`], by either loading it into memory, or retrieving it
+    /// from an in-memory cache.
     fn get_resource(
         &self,
         resource_id: &str,
-        locale: &str,
+        locale: &Locale,
     ) -> Result<&FluentResource, ResourceManagerError> {
-        let path = self
-            .path_scheme
-            .replace("{locale}", locale)
-            .replace("{res_id}", resource_id);
-        Ok(if let Some(resource) = self.resources.get(&path) {
-            resource
-        } else {
-            let resource = match FluentResource::try_new(read_file(&path)?) {
-                Ok(resource) => resource,
-                Err((resource, _err)) => resource,
-            };
-            self.resources.insert(path.to_string(), Box::new(resource))
-        })
+        if let Some(resource) = self.resources.get(&cache_key(resource_id, locale)) {
+            return Ok(resource);
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span = load_span(&self.loader, resource_id, locale).entered();
+
+        let source = self.loader.load(locale, resource_id)?;
+        self.insert_resource(resource_id, locale, source)
+    }
+
+    /// Parses the resource and caches it. If the resource was loaded in the
+    /// meantime, the cached resource is kept. Resources with syntax errors are
+    /// not cached.
+    fn insert_resource(
+        &self,
+        resource_id: &str,
+        locale: &Locale,
+        source: String,
+    ) -> Result<&FluentResource, ResourceManagerError> {
+        let key = cache_key(resource_id, locale);
+        if let Some(resource) = self.resources.get(&key) {
+            return Ok(resource);
+        }
+        let location = self.loader.location(locale, resource_id);
+        // Resources with syntax errors are watched too, so fixing them is noticed.
+        if !self.loaded.iter().any(|(loaded, _)| *loaded == location) {
+            self.loaded
+                .push(Box::new((location.clone(), resource_id.to_string())));
+        }
+        let resource = parse_resource(&location, source)?;
+        Ok(self.resources.insert(key, Box::new(resource)))
+    }
+
+    /// Removes all the cached resources, so they are loaded again the next time they are
+    /// requested. This is needed to pick up changes reported by [`ResourceManager::watch`].
+    pub fn clear_cache(&mut self) {
+        self.resources = FrozenMap::new();
+        self.loaded = FrozenVec::new();
     }
 
     /// Gets a [`FluentBundle`] from a list of resources. The bundle will only contain the
@@ -75,31 +143,20 @@ impl ResourceManager {
     /// to other locales.
     pub fn get_bundle(
         &self,
-        locales: Vec<Locale>,
+        locales: impl IntoIterator<Item = Locale>,
         resource_ids: Vec<String>,
     ) -> Result<FluentBundle<&FluentResource>, Vec<ResourceManagerError>> {
-        let mut errors: Vec<ResourceManagerError> = vec![];
-        let mut bundle = FluentBundle::new(locales.clone());
-        let locale = &locales[0];
-
-        for resource_id in &resource_ids {
-            match self.get_resource(resource_id, &locale.to_string()) {
-                Ok(resource) => {
-                    if let Err(errs) = bundle.add_resource(resource) {
-                        for error in errs {
-                            errors.push(ResourceManagerError::Fluent(error));
-                        }
-                    }
-                }
-                Err(error) => errors.push(error),
-            };
-        }
+        let locales: Vec<Locale> = locales.into_iter().collect();
+        let locale = self.resolve_alias(&locales[0]);
 
-        if errors.is_empty() {
-            Ok(bundle)
-        } else {
-            Err(errors)
-        }
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("get_bundle", locale = %locales[0]).entered();
+
+        let resources = resource_ids
+            .iter()
+            .map(|resource_id| self.get_resource(resource_id, &locale));
+
+        build_bundle(locales, resources)
     }
 
     /// Returns an iterator for a [`FluentBundle`] for each locale provided. Each
@@ -108,51 +165,263 @@ impl ResourceManager {
     /// unlike `get_bundle` and only use the single locale of the bundle.
     pub fn get_bundles(
         &self,
-        locales: Vec<Locale>,
+        locales: impl IntoIterator<Item = Locale>,
         resource_ids: Vec<String>,
     ) -> impl Iterator<Item = Result<FluentBundle<&FluentResource>, Vec<ResourceManagerError>>>
     {
-        let mut idx = 0;
-
-        iter::from_fn(move || {
-            locales.get(idx).map(|locale| {
-                idx += 1;
-                let mut errors: Vec<ResourceManagerError> = vec![];
-                let mut bundle = FluentBundle::new(vec![locale.clone()]);
-
-                for resource_id in &resource_ids {
-                    match self.get_resource(resource_id, &locale.to_string()) {
-                        Ok(resource) => {
-                            if let Err(errs) = bundle.add_resource(resource) {
-                                for error in errs {
-                                    errors.push(ResourceManagerError::Fluent(error))
-                                }
-                            }
-                        }
-                        Err(error) => errors.push(error),
+        locales.into_iter().map(move |locale| {
+            let resolved = self.resolve_alias(&locale);
+            let resources = resource_ids
+                .iter()
+                .map(|resource_id| self.get_resource(resource_id, &resolved));
+
+            build_bundle(vec![locale], resources)
+        })
+    }
+}
+
+impl ResourceManager<FilesystemLoader> {
+    /// Asynchronous version of [`ResourceManager::get_resource`], reading the file
+    /// without blocking the runtime.
+    #[cfg(feature = "async-tokio")]
+    async fn get_resource_async(
+        &self,
+        resource_id: &str,
+        locale: &Locale,
+    ) -> Result<&FluentResource, ResourceManagerError> {
+        if let Some(resource) = self.resources.get(&cache_key(resource_id, locale)) {
+            return Ok(resource);
+        }
+
+        #[cfg(feature = "tracing")]
+        let span = load_span(&self.loader, resource_id, locale);
+
+        let load = self.loader.load_async(locale, resource_id);
+        #[cfg(feature = "tracing")]
+        let load = tracing::Instrument::instrument(load, span.clone());
+        let source = load.await?;
+
+        #[cfg(feature = "tracing")]
+        let _span = span.entered();
+        self.insert_resource(resource_id, locale, source)
+    }
+
+    /// Returns the locales that have a directory in the path scheme, see
+    /// [`FilesystemLoader::available_locales`].
+    pub fn available_locales(&self) -> Result<Vec<Locale>, io::Error> {
+        self.loader.available_locales()
+    }
+
+    /// Returns the ids of the resources available for the `locale`, see
+    /// [`FilesystemLoader::available_resources_for`].
+    pub fn available_resources_for(&self, locale: &Locale) -> Result<Vec<String>, io::Error> {
+        self.loader.available_resources_for(locale)
+    }
+
+    /// Watches the files of all the resources loaded so far, calling `callback` with the
+    /// ids of the resources that changed on disk. The watching stops when the returned
+    /// [`WatchHandle`] is dropped.
+    ///
+    /// The changes are not applied to the cached resources, call
+    /// [`ResourceManager::clear_cache`] before rebuilding the affected bundles.
+    #[cfg(feature = "watch")]
+    pub fn watch<F>(&self, callback: F) -> Result<WatchHandle, ResourceManagerError>
+    where
+        F: Fn(Vec<String>) + Send + 'static,
+    {
+        use notify::{EventKind, RecursiveMode, Watcher};
+
+        // The directories are watched rather than the files, as editors often replace
+        // a file when saving it.
+        let resources: Vec<(std::path::PathBuf, String)> = self
+            .loaded
+            .iter()
+            .map(|(path, resource_id)| {
+                let path = fs::canonicalize(path).unwrap_or_else(|_| path.into());
+                (path, resource_id.clone())
+            })
+            .collect();
+
+        let directories: FxHashSet<std::path::PathBuf> = resources
+            .iter()
+            .filter_map(|(path, _)| path.parent().map(Into::into))
+            .collect();
+
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let Ok(event) = event else {
+                    return;
+                };
+                if !matches!(
+                    event.kind,
+                    EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                ) {
+                    return;
+                }
+
+                let mut changed: Vec<String> = vec![];
+                for (path, resource_id) in &resources {
+                    if event.paths.contains(path) && !changed.contains(resource_id) {
+                        changed.push(resource_id.clone());
                     }
                 }
 
-                if !errors.is_empty() {
-                    Err(errors)
-                } else {
-                    Ok(bundle)
+                if !changed.is_empty() {
+                    callback(changed);
                 }
+            })?;
+
+        for directory in &directories {
+            watcher.watch(directory, RecursiveMode::NonRecursive)?;
+        }
+
+        Ok(WatchHandle { _watcher: watcher })
+    }
+
+    /// Asynchronous version of [`ResourceManager::get_bundle`]. The resources are
+    /// read concurrently using `tokio::fs`.
+    #[cfg(feature = "async-tokio")]
+    pub async fn get_bundle_async(
+        &self,
+        locales: Vec<Locale>,
+        resource_ids: Vec<String>,
+    ) -> Result<FluentBundle<&FluentResource>, Vec<ResourceManagerError>> {
+        let locale = self.resolve_alias(&locales[0]);
+        let resources = futures::future::join_all(
+            resource_ids
+                .iter()
+                .map(|resource_id| self.get_resource_async(resource_id, &locale)),
+        )
+        .await;
+
+        build_bundle(locales, resources)
+    }
+
+    /// Asynchronous version of [`ResourceManager::get_bundles`], returning a stream
+    /// of a [`FluentBundle`] for each locale provided.
+    #[cfg(feature = "async-tokio")]
+    pub fn get_bundles_async(
+        &self,
+        locales: Vec<Locale>,
+        resource_ids: Vec<String>,
+    ) -> impl Stream<Item = Result<FluentBundle<&FluentResource>, Vec<ResourceManagerError>>> {
+        futures::stream::iter(locales)
+            .then(move |locale| self.get_bundle_async(vec![locale], resource_ids.clone()))
+    }
+}
+
+/// The span of loading and parsing a resource.
+#[cfg(feature = "tracing")]
+fn load_span<L: ResourceLoader>(loader: &L, resource_id: &str, locale: &Locale) -> tracing::Span {
+    tracing::debug_span!(
+        "load_resource",
+        resource.path = %loader.location(locale, resource_id),
+        locale = %locale,
+    )
+}
+
+fn cache_key(resource_id: &str, locale: &Locale) -> String {
+    format!("{}/{}", locale, resource_id)
+}
+
+/// Parses the source of the resource at `path`, returning all the syntax errors.
+fn parse_resource(path: &str, source: String) -> Result<FluentResource, ResourceManagerError> {
+    FluentResource::try_new(source).map_err(|(_, errors)| {
+        let errors = errors
+            .into_iter()
+            .filter_map(|error| match error {
+                FluentError::ParserError {
+                    error,
+                    line,
+                    column,
+                } => Some(ResourceParseError {
+                    error,
+                    line,
+                    column,
+                }),
+                _ => None,
             })
-        })
+            .collect();
+
+        ResourceManagerError::Parse {
+            path: path.to_string(),
+            errors,
+        }
+    })
+}
+
+/// Adds the resources to a new [`FluentBundle`], collecting the errors of both
+/// loading and adding the resources.
+fn build_bundle<'a>(
+    locales: Vec<Locale>,
+    resources: impl IntoIterator<Item = Result<&'a FluentResource, ResourceManagerError>>,
+) -> Result<FluentBundle<&'a FluentResource>, Vec<ResourceManagerError>> {
+    let mut errors: Vec<ResourceManagerError> = vec![];
+    let mut bundle = FluentBundle::new(locales);
+
+    for resource in resources {
+        match resource {
+            Ok(resource) => {
+                if let Err(errs) = bundle.add_resource(resource) {
+                    for error in errs {
+                        errors.push(ResourceManagerError::Fluent(error));
+                    }
+                }
+            }
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(bundle)
+    } else {
+        Err(errors)
     }
 }
 
 /// Errors generated during the process of retrieving the localization resources
 #[derive(Error, Debug)]
 pub enum ResourceManagerError {
-    /// Error while reading the resource file
+    /// Error while loading the source of the resource
     #[error("{0}")]
-    Io(#[from] std::io::Error),
+    Load(#[from] LoadError),
+
+    /// Syntax errors in the resource file at the path
+    #[error(
+        "Failed to parse {path}: {}",
+        .errors.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    Parse {
+        path: String,
+        errors: Vec<ResourceParseError>,
+    },
 
     /// Error while trying to add a resource to the bundle
     #[error("{0}")]
-    Fluent(#[from] fluent_bundle_for_carbide::FluentError),
+    Fluent(#[from] FluentError),
+
+    /// Error while watching the resource files
+    #[cfg(feature = "watch")]
+    #[error("{0}")]
+    Watch(#[from] notify::Error),
+}
+
+/// A syntax error in a resource file.
+#[derive(Error, Debug)]
+#[error("{line}:{column}: {error}")]
+pub struct ResourceParseError {
+    pub error: ParserError,
+    /// The line of the error, starting at 1.
+    pub line: usize,
+    /// The column of the error in characters, starting at 1.
+    pub column: usize,
+}
+
+/// Returned by [`ResourceManager::watch`], the resource files are watched
+/// until the handle is dropped.
+#[cfg(feature = "watch")]
+pub struct WatchHandle {
+    _watcher: notify::RecommendedWatcher,
 }
 
 // Due to limitation of trait, we need a nameable Iterator type.  Due to the
@@ -168,7 +437,7 @@ impl Iterator for BundleIter {
     fn next(&mut self) -> Option<Self::Item> {
         let locale = self.locales.next()?;
 
-        let mut bundle = FluentBundle::new(vec![locale.clone()]);
+        let mut bundle = concurrent::FluentBundle::new_concurrent(vec![locale.clone()]);
 
         for res_id in self.res_ids.iter() {
             let full_path = format!("./tests/resources/{}/{}", locale, res_id);
@@ -222,6 +491,7 @@ impl BundleGenerator for ResourceManager {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::loader::{EmbeddedLoader, InMemoryLoader};
     use icu::locid::locale;
 
     #[test]
@@ -230,12 +500,12 @@ mod test {
 
         let _bundle = res_mgr.get_bundle(vec![locale!("en-US")], vec!["test.ftl".into()]);
         let res_1 = res_mgr
-            .get_resource("test.ftl", "en-US")
+            .get_resource("test.ftl", &locale!("en-US"))
             .expect("Could not get resource");
 
         let _bundle2 = res_mgr.get_bundle(vec![locale!("en-US")], vec!["test.ftl".into()]);
         let res_2 = res_mgr
-            .get_resource("test.ftl", "en-US")
+            .get_resource("test.ftl", &locale!("en-US"))
             .expect("Could not get resource");
 
         assert!(
@@ -249,7 +519,7 @@ mod test {
         let res_mgr = ResourceManager::new("./tests/resources/{locale}/{res_id}".into());
 
         let _bundle = res_mgr.get_bundle(vec![locale!("en-US")], vec!["test.ftl".into()]);
-        let res = res_mgr.get_resource("nonexistent.ftl", "en-US");
+        let res = res_mgr.get_resource("nonexistent.ftl", &locale!("en-US"));
 
         assert!(res.is_err());
     }
@@ -262,31 +532,247 @@ mod test {
         assert!(bundle.is_err());
     }
 
-    // TODO - Syntax errors should be surfaced. This test has an invalid resource that
-    // should fail, but currently isn't.
-    // https://github.com/projectfluent/fluent-rs/issues/280
     #[test]
-    fn get_bundle_ignores_errors() {
+    fn locale_alias() {
+        let res_mgr = ResourceManager::new("./tests/resources/{locale}/{res_id}".into())
+            .with_alias("en", locale!("en-US"))
+            .with_alias("pl-PL", locale!("pl"));
+
+        let bundle = res_mgr
+            .get_bundle(vec![locale!("en")], vec!["test.ftl".into()])
+            .expect("Could not retrieve bundle");
+        assert!(bundle.has_message("hello-world"));
+        assert_eq!(bundle.locales, vec![locale!("en")]);
+
+        let bundles: Vec<_> = res_mgr
+            .get_bundles(
+                vec![locale!("en"), locale!("pl-PL"), locale!("de")],
+                vec!["test.ftl".into()],
+            )
+            .collect();
+        assert!(bundles[0].is_ok());
+        assert_eq!(bundles[1].as_ref().unwrap().locales, vec![locale!("pl-PL")]);
+        assert!(bundles[2].is_err());
+
+        // The resources are cached under the full locale.
+        assert!(res_mgr
+            .resources
+            .get(&cache_key("test.ftl", &locale!("en-US")))
+            .is_some());
+        assert!(res_mgr
+            .resources
+            .get(&cache_key("test.ftl", &locale!("en")))
+            .is_none());
+    }
+
+    #[cfg(feature = "async-tokio")]
+    #[tokio::test]
+    async fn get_bundle_async() {
         let res_mgr = ResourceManager::new("./tests/resources/{locale}/{res_id}".into());
+
         let bundle = res_mgr
+            .get_bundle_async(vec![locale!("en-US")], vec!["test.ftl".into()])
+            .await
+            .expect("Could not retrieve bundle");
+        assert!(bundle.has_message("hello-world"));
+
+        let res = res_mgr
+            .get_bundle_async(vec![locale!("en-US")], vec!["nonexistent.ftl".into()])
+            .await;
+        assert!(res.is_err());
+
+        let bundles = res_mgr
+            .get_bundles_async(
+                vec![locale!("en-US"), locale!("pl")],
+                vec!["test.ftl".into()],
+            )
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(bundles.len(), 2);
+        assert!(bundles.iter().all(|bundle| bundle.is_ok()));
+
+        let res_1 = res_mgr
+            .get_resource("test.ftl", &locale!("pl"))
+            .expect("Could not get resource");
+        let res_2 = res_mgr
+            .get_resource_async("test.ftl", &locale!("pl"))
+            .await
+            .expect("Could not get resource");
+        assert!(std::ptr::eq(res_1, res_2));
+    }
+
+    #[cfg(feature = "watch")]
+    #[test]
+    fn watch() {
+        use std::time::Duration;
+
+        let dir = std::env::temp_dir().join(format!("fluent-resmgr-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("en-US")).unwrap();
+        fs::write(dir.join("en-US/test.ftl"), "hello = Hello").unwrap();
+        fs::write(dir.join("en-US/other.ftl"), "other = Other").unwrap();
+
+        let mut res_mgr = ResourceManager::new(format!("{}/{{locale}}/{{res_id}}", dir.display()));
+        res_mgr
             .get_bundle(
                 vec![locale!("en-US")],
-                vec!["test.ftl".into(), "invalid.ftl".into()],
+                vec!["test.ftl".into(), "other.ftl".into()],
             )
             .expect("Could not retrieve bundle");
 
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let handle = res_mgr
+            .watch(move |ids| sender.send(ids).unwrap())
+            .expect("Could not watch the resources");
+
+        fs::write(dir.join("en-US/test.ftl"), "hello = Hi").unwrap();
+        let changed = receiver
+            .recv_timeout(Duration::from_secs(5))
+            .expect("No change was reported");
+        assert_eq!(changed, vec!["test.ftl".to_string()]);
+        drop(handle);
+
+        res_mgr.clear_cache();
+        let bundle = res_mgr
+            .get_bundle(vec![locale!("en-US")], vec!["test.ftl".into()])
+            .expect("Could not retrieve bundle");
         let mut errors = vec![];
-        let msg = bundle.get_message("hello-world").expect("Message exists");
-        let pattern = msg.value().expect("Message has a value");
-        let value = bundle.format_pattern(pattern, None, &mut errors);
-        assert_eq!(value, "Hello World");
-        assert!(errors.is_empty());
+        let msg = bundle.get_message("hello").expect("Message exists");
+        let value = bundle.format_pattern(msg.value().unwrap(), None, &mut errors);
+        assert_eq!(value, "Hi");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn available_locales_and_resources() {
+        let res_mgr = ResourceManager::new("./tests/resources/{locale}/{res_id}".into());
+        assert_eq!(
+            res_mgr
+                .available_locales()
+                .expect("Could not list the locales"),
+            vec![locale!("en-US"), locale!("pl")]
+        );
+        assert_eq!(
+            res_mgr
+                .available_resources_for(&locale!("en-US"))
+                .expect("Could not list the resources"),
+            vec!["invalid.ftl".to_string(), "test.ftl".to_string()]
+        );
+        assert_eq!(
+            res_mgr
+                .available_resources_for(&locale!("de"))
+                .expect("Could not list the resources"),
+            Vec::<String>::new()
+        );
+
+        let res_mgr =
+            ResourceManager::new("./tests/multilevel/{language}/{script}/{res_id}".into());
+        assert_eq!(
+            res_mgr.available_locales().map_err(|error| error.kind()),
+            Err(io::ErrorKind::InvalidInput)
+        );
+        assert_eq!(
+            res_mgr
+                .available_resources_for(&locale!("sr-Latn"))
+                .expect("Could not list the resources"),
+            vec!["test.ftl".to_string()]
+        );
+    }
+
+    #[test]
+    fn custom_loader() {
+        let mut loader = InMemoryLoader::new();
+        loader.insert(&locale!("en-US"), "test.ftl", "hello = Hello".into());
+        loader.insert(&locale!("pl"), "test.ftl", "hello = Witaj".into());
+        let res_mgr = ResourceManager::new_with_loader(loader);
+
+        let bundle = res_mgr
+            .get_bundle(vec![locale!("pl")], vec!["test.ftl".into()])
+            .expect("Could not retrieve bundle");
+        let mut errors = vec![];
+        let msg = bundle.get_message("hello").expect("Message exists");
+        let value = bundle.format_pattern(msg.value().unwrap(), None, &mut errors);
+        assert_eq!(value, "Witaj");
+
+        match res_mgr.get_resource("test.ftl", &locale!("de")) {
+            Err(ResourceManagerError::Load(LoadError::NotFound(location))) => {
+                assert_eq!(location, "de/test.ftl")
+            }
+            _ => panic!("Expected a missing resource"),
+        }
+
+        struct FailingLoader;
+
+        impl ResourceLoader for FailingLoader {
+            fn load(&self, _locale: &Locale, _resource_id: &str) -> Result<String, LoadError> {
+                Err(LoadError::Other("The database is offline.".into()))
+            }
+        }
 
+        let res_mgr = ResourceManager::new_with_loader(FailingLoader);
+        let errors = res_mgr
+            .get_bundle(vec![locale!("en-US")], vec!["test.ftl".into()])
+            .err()
+            .expect("The loader error is reported");
+        assert_eq!(errors[0].to_string(), "The database is offline.");
+    }
+
+    #[test]
+    fn embedded_loader() {
+        static FILES: &[(&str, &str, &str)] = &[
+            ("en-US", "test.ftl", "hello-world = Hello World"),
+            ("pl", "test.ftl", "hello-world = Witaj Świecie"),
+        ];
+        let res_mgr = ResourceManager::new_with_loader(EmbeddedLoader::new(FILES));
+
+        let bundle = res_mgr
+            .get_bundle(vec![locale!("pl")], vec!["test.ftl".into()])
+            .expect("Could not retrieve bundle");
         let mut errors = vec![];
-        let msg = bundle.get_message("valid-message").expect("Message exists");
-        let pattern = msg.value().expect("Message has a value");
-        let value = bundle.format_pattern(pattern, None, &mut errors);
-        assert_eq!(value, "This is a valid message");
-        assert!(errors.is_empty());
+        let msg = bundle.get_message("hello-world").expect("Message exists");
+        let value = bundle.format_pattern(msg.value().unwrap(), None, &mut errors);
+        assert_eq!(value, "Witaj Świecie");
+
+        assert!(res_mgr
+            .get_bundle(vec![locale!("de")], vec!["test.ftl".into()])
+            .is_err());
+
+        let bundles = res_mgr
+            .get_bundles(
+                vec![locale!("en-US"), locale!("pl")],
+                vec!["test.ftl".into()],
+            )
+            .collect::<Vec<_>>();
+        assert_eq!(bundles.len(), 2);
+        assert!(bundles.iter().all(|bundle| bundle.is_ok()));
+    }
+
+    #[test]
+    fn get_bundle_parse_errors() {
+        let res_mgr = ResourceManager::new("./tests/resources/{locale}/{res_id}".into());
+        let errors = res_mgr
+            .get_bundle(
+                vec![locale!("en-US")],
+                vec!["test.ftl".into(), "invalid.ftl".into()],
+            )
+            .err()
+            .expect("The syntax error is reported");
+
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ResourceManagerError::Parse { path, errors } => {
+                assert_eq!(path, "./tests/resources/en-US/invalid.ftl");
+                assert_eq!(errors.len(), 1);
+                assert_eq!((errors[0].line, errors[0].column), (3, 8));
+            }
+            error => panic!("Unexpected error: {}", error),
+        }
+
+        match res_mgr.get_resource("nonexistent.ftl", &locale!("en-US")) {
+            Err(ResourceManagerError::Load(LoadError::Io(path, _))) => {
+                assert_eq!(path, "./tests/resources/en-US/nonexistent.ftl")
+            }
+            _ => panic!("Expected an io error"),
+        }
     }
 }
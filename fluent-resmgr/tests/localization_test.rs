@@ -1,7 +1,9 @@
 use fluent_fallback_for_carbide::Localization;
 use fluent_resmgr_for_carbide::resource_manager::ResourceManager;
-use std::borrow::Cow;
 use icu::locid::locale;
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::thread;
 
 #[test]
 fn localization_format_value() {
@@ -19,17 +21,49 @@ fn localization_format_value() {
     let value = bundles
         .format_value_sync("hello-world", None, &mut errors)
         .unwrap();
-    assert_eq!(value, Some(Cow::Borrowed("Hello World")));
+    assert_eq!(value.value, Some(Cow::Borrowed("Hello World")));
 
     let value2 = bundles
         .format_value_sync("new-message", None, &mut errors)
         .unwrap();
-    assert_eq!(value2, Some(Cow::Borrowed("Nowa Wiadomość")));
+    assert_eq!(value2.value, Some(Cow::Borrowed("Nowa Wiadomość")));
 
     let value3 = bundles
         .format_value_sync("missing-message", None, &mut errors)
         .unwrap();
-    assert_eq!(value3, None);
+    assert_eq!(value3.value, None);
+}
+
+#[test]
+fn localization_send_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let res_mgr = ResourceManager::new("./tests/resources/{locale}/{res_id}".into());
+    let loc = Arc::new(Localization::with_env(
+        vec!["test.ftl".into()],
+        true,
+        vec!["en-US".parse().unwrap()],
+        res_mgr,
+    ));
+    assert_send_sync(&loc);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let loc = Arc::clone(&loc);
+            thread::spawn(move || {
+                let mut errors = vec![];
+                let value = loc
+                    .bundles()
+                    .format_value_sync("hello-world", None, &mut errors)
+                    .unwrap();
+                value.value.map(Cow::into_owned)
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), Some("Hello World".to_string()));
+    }
 }
 
 #[test]
@@ -82,3 +116,54 @@ fn resmgr_get_bundles() {
 
     assert!(bundles_iter.next().is_none(), "The iterator is consumed.");
 }
+
+#[test]
+fn resmgr_subtag_placeholders() {
+    let res_mgr =
+        ResourceManager::new("./tests/multilevel/{language}/{script}/{region}/{res_id}".into());
+
+    let cases = [
+        (locale!("sr-Latn"), "Zdravo"),
+        (locale!("sr-Latn-RS"), "Zdravo"),
+        (locale!("sr"), "Здраво"),
+        (locale!("sr-Cyrl"), "Здраво"),
+        (locale!("en-US"), "Howdy"),
+        (locale!("en-GB"), "Hello"),
+    ];
+
+    for (locale, expected) in cases {
+        let bundle = res_mgr
+            .get_bundle(vec![locale.clone()], vec!["test.ftl".into()])
+            .unwrap_or_else(|_| panic!("Could not get the {} bundle", locale));
+
+        let mut errors = vec![];
+        let msg = bundle.get_message("hello").expect("Message exists");
+        let pattern = msg.value().expect("Message has a value");
+        let value = bundle.format_pattern(pattern, None, &mut errors);
+        assert_eq!(value, expected);
+    }
+
+    assert!(res_mgr
+        .get_bundle(vec![locale!("de")], vec!["test.ftl".into()])
+        .is_err());
+}
+
+#[cfg(feature = "embed")]
+#[test]
+fn resmgr_embed() {
+    let res_mgr = fluent_resmgr_for_carbide::embed!("./tests/resources");
+
+    let bundle = res_mgr
+        .get_bundle(vec![locale!("en-US")], vec!["test.ftl".into()])
+        .expect("Could not retrieve bundle");
+    assert!(bundle.has_message("hello-world"));
+
+    let bundle = res_mgr
+        .get_bundle(vec![locale!("pl")], vec!["test.ftl".into()])
+        .expect("Could not retrieve bundle");
+    assert!(bundle.has_message("new-message"));
+
+    assert!(res_mgr
+        .get_bundle(vec![locale!("pl")], vec!["invalid.ftl".into()])
+        .is_err());
+}
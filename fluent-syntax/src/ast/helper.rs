@@ -1,7 +1,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use super::Comment;
+use super::{Comment, Span};
 // This is a helper struct used to properly deserialize referential
 // JSON comments which are single continous String, into a vec of
 // content slices.
@@ -9,17 +9,26 @@ use super::Comment;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(untagged))]
 pub enum CommentDef<S> {
-    Single { content: S },
-    Multi { content: Vec<S> },
+    Single {
+        content: S,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
+    Multi {
+        content: Vec<S>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
 }
 
 impl<S> From<CommentDef<S>> for Comment<S> {
     fn from(input: CommentDef<S>) -> Self {
         match input {
-            CommentDef::Single { content } => Self {
+            CommentDef::Single { content, span } => Self {
                 content: vec![content],
+                span,
             },
-            CommentDef::Multi { content } => Self { content },
+            CommentDef::Multi { content, span } => Self { content, span },
         }
     }
 }
@@ -80,6 +80,12 @@
 //!
 //! Fluent AST preserves blocks containing invaid syntax as [`Entry::Junk`].
 //!
+//! ## Spans
+//!
+//! Every node has a [`Span`] with the byte offsets of its source, a message or term
+//! with a comment starts at the comment. Enums, like [`Expression`], return the span
+//! of their variant from a `span` method.
+//!
 //! ## White space
 //!
 //! At the moment, AST does not preserve white space. In result only a
@@ -88,6 +94,7 @@ mod helper;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 
 /// Root node of a Fluent Translation List.
 ///
@@ -115,6 +122,8 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Resource<S> {
     pub body: Vec<Entry<S>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// A top-level node representing an entry of a [`Resource`].
@@ -202,7 +211,11 @@ pub enum Entry<S> {
     Comment(Comment<S>),
     GroupComment(Comment<S>),
     ResourceComment(Comment<S>),
-    Junk { content: S },
+    Junk {
+        content: S,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
 }
 
 /// Message node represents the most common [`Entry`] in an FTL [`Resource`].
@@ -260,6 +273,8 @@ pub struct Message<S> {
     pub value: Option<Pattern<S>>,
     pub attributes: Vec<Attribute<S>>,
     pub comment: Option<Comment<S>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// A Fluent [`Term`].
@@ -314,6 +329,8 @@ pub struct Term<S> {
     pub value: Pattern<S>,
     pub attributes: Vec<Attribute<S>>,
     pub comment: Option<Comment<S>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// Pattern contains a value of a [`Message`], [`Term`] or an [`Attribute`].
@@ -391,6 +408,8 @@ pub struct Term<S> {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Pattern<S> {
     pub elements: Vec<PatternElement<S>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// PatternElement is an element of a [`Pattern`].
@@ -468,8 +487,16 @@ pub struct Pattern<S> {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub enum PatternElement<S> {
-    TextElement { value: S },
-    Placeable { expression: Expression<S> },
+    TextElement {
+        value: S,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
+    Placeable {
+        expression: Expression<S>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
 }
 
 /// Attribute represents a part of a [`Message`] or [`Term`].
@@ -540,6 +567,8 @@ pub enum PatternElement<S> {
 pub struct Attribute<S> {
     pub id: Identifier<S>,
     pub value: Pattern<S>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// Identifier is part of nodes such as [`Message`], [`Term`] and [`Attribute`].
@@ -588,6 +617,8 @@ pub struct Attribute<S> {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Identifier<S> {
     pub name: S,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// Variant is a single branch of a value in a [`Select`](Expression::Select) expression.
@@ -674,6 +705,8 @@ pub struct Variant<S> {
     pub key: VariantKey<S>,
     pub value: Pattern<S>,
     pub default: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// A key of a [`Variant`].
@@ -756,8 +789,16 @@ pub struct Variant<S> {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub enum VariantKey<S> {
-    Identifier { name: S },
-    NumberLiteral { value: S },
+    Identifier {
+        name: S,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
+    NumberLiteral {
+        value: S,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
 }
 
 /// Fluent [`Comment`].
@@ -803,6 +844,8 @@ pub enum VariantKey<S> {
 #[cfg_attr(feature = "serde", serde(from = "helper::CommentDef<S>"))]
 pub struct Comment<S> {
     pub content: Vec<S>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// List of arguments for a [`FunctionReference`](InlineExpression::FunctionReference) or a
@@ -885,6 +928,8 @@ pub struct Comment<S> {
 pub struct CallArguments<S> {
     pub positional: Vec<InlineExpression<S>>,
     pub named: Vec<NamedArgument<S>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// A key-value pair used in [`CallArguments`].
@@ -954,6 +999,8 @@ pub struct CallArguments<S> {
 pub struct NamedArgument<S> {
     pub name: Identifier<S>,
     pub value: InlineExpression<S>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub span: Span,
 }
 
 /// A subset of expressions which can be used as [`Placeable`](PatternElement::Placeable),
@@ -1053,7 +1100,11 @@ pub enum InlineExpression<S> {
     ///     }
     /// );
     /// ```
-    StringLiteral { value: S },
+    StringLiteral {
+        value: S,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
     /// A number literal.
     ///
     /// # Example
@@ -1099,7 +1150,11 @@ pub enum InlineExpression<S> {
     ///     }
     /// );
     /// ```
-    NumberLiteral { value: S },
+    NumberLiteral {
+        value: S,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
     /// A function reference.
     ///
     /// # Example
@@ -1151,6 +1206,8 @@ pub enum InlineExpression<S> {
     FunctionReference {
         id: Identifier<S>,
         arguments: CallArguments<S>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
     },
     /// A reference to another message.
     ///
@@ -1203,6 +1260,8 @@ pub enum InlineExpression<S> {
     MessageReference {
         id: Identifier<S>,
         attribute: Option<Identifier<S>>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
     },
     /// A reference to a term.
     ///
@@ -1257,6 +1316,8 @@ pub enum InlineExpression<S> {
         id: Identifier<S>,
         attribute: Option<Identifier<S>>,
         arguments: Option<CallArguments<S>>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
     },
     /// A reference to a variable.
     ///
@@ -1305,7 +1366,11 @@ pub enum InlineExpression<S> {
     ///     }
     /// );
     /// ```
-    VariableReference { id: Identifier<S> },
+    VariableReference {
+        id: Identifier<S>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
     /// A placeable which may contain another expression.
     ///
     /// # Example
@@ -1357,7 +1422,11 @@ pub enum InlineExpression<S> {
     ///     }
     /// );
     /// ```
-    Placeable { expression: Box<Expression<S>> },
+    Placeable {
+        expression: Box<Expression<S>>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
+    },
 }
 
 /// An expression that is either a select expression or an inline expression.
@@ -1448,6 +1517,8 @@ pub enum Expression<S> {
     Select {
         selector: InlineExpression<S>,
         variants: Vec<Variant<S>>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        span: Span,
     },
 
     /// An inline expression such as `${ username }`:
@@ -1457,3 +1528,109 @@ pub enum Expression<S> {
     /// ```
     Inline(InlineExpression<S>),
 }
+
+/// The position of a node in the source it was parsed from, as a range of byte offsets.
+///
+/// Nodes that are created by hand, rather than parsed, use the default empty span.
+/// Spans take part in the comparison of nodes, [`strip_spans`](crate::visitor::strip_spans)
+/// resets them to compare only the content.
+///
+/// # Example
+///
+/// ```
+/// use fluent_syntax::parser;
+/// use fluent_syntax::ast;
+///
+/// let ftl = "key = Hello, { $name }!";
+///
+/// let resource = parser::parse(ftl)
+///     .expect("Failed to parse an FTL resource.");
+///
+/// if let ast::Entry::Message(message) = &resource.body[0] {
+///     let value = message.value.as_ref().expect("The message has a value.");
+///
+///     assert_eq!(&ftl[value.span.range()], "Hello, { $name }!");
+///     assert_eq!(&ftl[value.elements[1].span().range()], "{ $name }");
+/// }
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub struct Span {
+    /// The byte offset of the start of the node.
+    pub start: usize,
+    /// The byte offset just past the end of the node.
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(range: Range<usize>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Returns the span as a range, to slice the source with.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(range: Range<usize>) -> Self {
+        Self::new(range)
+    }
+}
+
+impl<S> Entry<S> {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Message(message) => message.span,
+            Self::Term(term) => term.span,
+            Self::Comment(comment)
+            | Self::GroupComment(comment)
+            | Self::ResourceComment(comment) => comment.span,
+            Self::Junk { span, .. } => *span,
+        }
+    }
+}
+
+impl<S> PatternElement<S> {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::TextElement { span, .. } | Self::Placeable { span, .. } => *span,
+        }
+    }
+}
+
+impl<S> VariantKey<S> {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Identifier { span, .. } | Self::NumberLiteral { span, .. } => *span,
+        }
+    }
+}
+
+impl<S> InlineExpression<S> {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::StringLiteral { span, .. }
+            | Self::NumberLiteral { span, .. }
+            | Self::FunctionReference { span, .. }
+            | Self::MessageReference { span, .. }
+            | Self::TermReference { span, .. }
+            | Self::VariableReference { span, .. }
+            | Self::Placeable { span, .. } => *span,
+        }
+    }
+}
+
+impl<S> Expression<S> {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Select { span, .. } => *span,
+            Self::Inline(expression) => expression.span(),
+        }
+    }
+}
@@ -8,6 +8,10 @@
 //! The crate provides a [`parser`] module which allows for parsing of an
 //! input string to an Abstract Syntax Tree defined in the [`ast`] module.
 //!
+//! The [`serializer`] module turns an Abstract Syntax Tree back into FTL, the
+//! [`pretty_printer`] module does so with normalized formatting, and the
+//! [`visitor`] module walks it, to inspect or transform the nodes.
+//!
 //! The [`unicode`] module exposes a set of helper functions used to decode
 //! escaped unicode literals according to Fluent specification.
 //!
@@ -48,5 +52,7 @@
 //! ```
 pub mod ast;
 pub mod parser;
+pub mod pretty_printer;
 pub mod serializer;
 pub mod unicode;
+pub mod visitor;
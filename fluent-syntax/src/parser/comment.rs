@@ -16,6 +16,8 @@ where
     pub(super) fn get_comment(&mut self) -> Result<(ast::Comment<S>, Level)> {
         let mut level = Level::None;
         let mut content = vec![];
+        let start = self.ptr;
+        let mut end = self.ptr;
 
         while self.ptr < self.length {
             let line_level = self.get_comment_level();
@@ -30,9 +32,11 @@ where
             level = line_level;
 
             if self.ptr == self.length {
+                end = self.ptr;
                 break;
             } else if self.is_eol() {
                 content.push(self.get_comment_line());
+                end = self.ptr;
             } else {
                 if let Err(e) = self.expect_byte(b' ') {
                     if content.is_empty() {
@@ -43,11 +47,16 @@ where
                     }
                 }
                 content.push(self.get_comment_line());
+                end = self.ptr;
             }
             self.skip_eol();
         }
 
-        Ok((ast::Comment { content }, level))
+        let comment = ast::Comment {
+            content,
+            span: ast::Span::new(start..end),
+        };
+        Ok((comment, level))
     }
 
     pub(super) fn skip_comment(&mut self) {
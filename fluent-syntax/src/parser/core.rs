@@ -34,7 +34,7 @@ where
         let mut body = vec![];
 
         self.skip_blank_block();
-        let mut last_comment = None;
+        let mut last_comment: Option<ast::Comment<S>> = None;
         let mut last_blank_count = 0;
 
         while self.ptr < self.length {
@@ -44,9 +44,11 @@ where
             if let Some(comment) = last_comment.take() {
                 match entry {
                     Ok(ast::Entry::Message(ref mut msg)) if last_blank_count < 2 => {
+                        msg.span.start = comment.span.start;
                         msg.comment = Some(comment);
                     }
                     Ok(ast::Entry::Term(ref mut term)) if last_blank_count < 2 => {
+                        term.span.start = comment.span.start;
                         term.comment = Some(comment);
                     }
                     _ => {
@@ -67,7 +69,10 @@ where
                     err.slice = Some(entry_start..self.ptr);
                     errors.push(err);
                     let content = self.source.slice(entry_start..self.ptr);
-                    body.push(ast::Entry::Junk { content });
+                    body.push(ast::Entry::Junk {
+                        content,
+                        span: ast::Span::new(entry_start..self.ptr),
+                    });
                 }
             }
             last_blank_count = self.skip_blank_block();
@@ -76,10 +81,14 @@ where
         if let Some(last_comment) = last_comment.take() {
             body.push(ast::Entry::Comment(last_comment));
         }
+        let resource = ast::Resource {
+            body,
+            span: ast::Span::new(0..self.length),
+        };
         if errors.is_empty() {
-            Ok(ast::Resource { body })
+            Ok(resource)
         } else {
-            Err((ast::Resource { body }, errors))
+            Err((resource, errors))
         }
     }
 
@@ -118,11 +127,18 @@ where
             );
         }
 
+        let end = attributes
+            .last()
+            .map(|attribute| attribute.span.end)
+            .or_else(|| pattern.as_ref().map(|pattern| pattern.span.end))
+            .unwrap_or(self.ptr);
+
         Ok(ast::Message {
             id,
             value: pattern,
             attributes,
             comment: None,
+            span: ast::Span::new(entry_start..end),
         })
     }
 
@@ -140,11 +156,16 @@ where
         let attributes = self.get_attributes();
 
         if let Some(value) = value {
+            let end = attributes
+                .last()
+                .map_or(value.span.end, |attribute| attribute.span.end);
+
             Ok(ast::Term {
                 id,
                 value,
                 attributes,
                 comment: None,
+                span: ast::Span::new(entry_start..end),
             })
         } else {
             error!(
@@ -178,13 +199,19 @@ where
     }
 
     fn get_attribute(&mut self) -> Result<ast::Attribute<S>> {
+        // The `.` has already been taken.
+        let start = self.ptr - 1;
         let id = self.get_identifier()?;
         self.skip_blank_inline();
         self.expect_byte(b'=')?;
         let pattern = self.get_pattern()?;
 
         match pattern {
-            Some(pattern) => Ok(ast::Attribute { id, value: pattern }),
+            Some(pattern) => Ok(ast::Attribute {
+                id,
+                span: ast::Span::new(start..pattern.span.end),
+                value: pattern,
+            }),
             None => error!(ErrorKind::MissingValue, self.ptr),
         }
     }
@@ -197,10 +224,14 @@ where
             ptr += 1;
         }
 
-        let name = self.source.slice(self.ptr - 1..ptr);
+        let start = self.ptr - 1;
+        let name = self.source.slice(start..ptr);
         self.ptr = ptr;
 
-        ast::Identifier { name }
+        ast::Identifier {
+            name,
+            span: ast::Span::new(start..ptr),
+        }
     }
 
     pub(super) fn get_identifier(&mut self) -> Result<ast::Identifier<S>> {
@@ -228,13 +259,17 @@ where
     fn get_variant_key(&mut self) -> Result<ast::VariantKey<S>> {
         self.skip_blank();
 
+        let start = self.ptr;
         let key = if self.is_number_start() {
             ast::VariantKey::NumberLiteral {
                 value: self.get_number_literal()?,
+                span: ast::Span::new(start..self.ptr),
             }
         } else {
+            let id = self.get_identifier()?;
             ast::VariantKey::Identifier {
-                name: self.get_identifier()?.name,
+                name: id.name,
+                span: id.span,
             }
         };
 
@@ -250,6 +285,7 @@ where
         let mut has_default = false;
 
         loop {
+            let start = self.ptr;
             let default = self.take_byte_if(b'*');
             if default {
                 if has_default {
@@ -270,6 +306,7 @@ where
             if let Some(value) = value {
                 variants.push(ast::Variant {
                     key,
+                    span: ast::Span::new(start..value.span.end),
                     value,
                     default,
                 });
@@ -57,9 +57,13 @@ where
 
         let variants = self.get_variants()?;
 
+        let start = exp.span().start;
+        let end = variants.last().map_or(self.ptr, |variant| variant.span.end);
+
         Ok(ast::Expression::Select {
             selector: exp,
             variants,
+            span: ast::Span::new(start..end),
         })
     }
 
@@ -67,6 +71,7 @@ where
         &mut self,
         only_literal: bool,
     ) -> Result<ast::InlineExpression<S>> {
+        let expression_start = self.ptr;
         match get_current_byte!(self) {
             Some(b'"') => {
                 self.ptr += 1; // "
@@ -100,11 +105,17 @@ where
 
                 self.expect_byte(b'"')?;
                 let slice = self.source.slice(start..self.ptr - 1);
-                Ok(ast::InlineExpression::StringLiteral { value: slice })
+                Ok(ast::InlineExpression::StringLiteral {
+                    value: slice,
+                    span: ast::Span::new(expression_start..self.ptr),
+                })
             }
-            Some(b) if b.is_ascii_digit() => {
+            Some(b) if b.is_ascii_digit() || (only_literal && *b == b'-') => {
                 let num = self.get_number_literal()?;
-                Ok(ast::InlineExpression::NumberLiteral { value: num })
+                Ok(ast::InlineExpression::NumberLiteral {
+                    value: num,
+                    span: ast::Span::new(expression_start..self.ptr),
+                })
             }
             Some(b'-') if !only_literal => {
                 self.ptr += 1; // -
@@ -113,21 +124,33 @@ where
                     let id = self.get_identifier_unchecked();
                     let attribute = self.get_attribute_accessor()?;
                     let arguments = self.get_call_arguments()?;
+                    let end = match (&attribute, &arguments) {
+                        (_, Some(arguments)) => arguments.span.end,
+                        (Some(attribute), None) => attribute.span.end,
+                        (None, None) => id.span.end,
+                    };
                     Ok(ast::InlineExpression::TermReference {
                         id,
                         attribute,
                         arguments,
+                        span: ast::Span::new(expression_start..end),
                     })
                 } else {
                     self.ptr -= 1;
                     let num = self.get_number_literal()?;
-                    Ok(ast::InlineExpression::NumberLiteral { value: num })
+                    Ok(ast::InlineExpression::NumberLiteral {
+                        value: num,
+                        span: ast::Span::new(expression_start..self.ptr),
+                    })
                 }
             }
             Some(b'$') if !only_literal => {
                 self.ptr += 1; // $
                 let id = self.get_identifier()?;
-                Ok(ast::InlineExpression::VariableReference { id })
+                Ok(ast::InlineExpression::VariableReference {
+                    span: ast::Span::new(expression_start..id.span.end),
+                    id,
+                })
             }
             Some(b) if b.is_ascii_alphabetic() => {
                 self.ptr += 1;
@@ -138,10 +161,19 @@ where
                         return error!(ErrorKind::ForbiddenCallee, self.ptr);
                     }
 
-                    Ok(ast::InlineExpression::FunctionReference { id, arguments })
+                    Ok(ast::InlineExpression::FunctionReference {
+                        id,
+                        span: ast::Span::new(expression_start..arguments.span.end),
+                        arguments,
+                    })
                 } else {
                     let attribute = self.get_attribute_accessor()?;
-                    Ok(ast::InlineExpression::MessageReference { id, attribute })
+                    let end = attribute.as_ref().unwrap_or(&id).span.end;
+                    Ok(ast::InlineExpression::MessageReference {
+                        id,
+                        attribute,
+                        span: ast::Span::new(expression_start..end),
+                    })
                 }
             }
             Some(b'{') if !only_literal => {
@@ -149,6 +181,7 @@ where
                 let exp = self.get_placeable()?;
                 Ok(ast::InlineExpression::Placeable {
                     expression: Box::new(exp),
+                    span: ast::Span::new(expression_start..self.ptr),
                 })
             }
             _ if only_literal => error!(ErrorKind::ExpectedLiteral, self.ptr),
@@ -158,6 +191,7 @@ where
 
     pub fn get_call_arguments(&mut self) -> Result<Option<ast::CallArguments<S>>> {
         self.skip_blank();
+        let start = self.ptr;
         if !self.take_byte_if(b'(') {
             return Ok(None);
         }
@@ -178,6 +212,7 @@ where
             if let ast::InlineExpression::MessageReference {
                 ref id,
                 attribute: None,
+                ..
             } = expr
             {
                 self.skip_blank();
@@ -194,9 +229,8 @@ where
 
                     argument_names.push(id.name.clone());
                     named.push(ast::NamedArgument {
-                        name: ast::Identifier {
-                            name: id.name.clone(),
-                        },
+                        name: id.clone(),
+                        span: ast::Span::new(id.span.start..val.span().end),
                         value: val,
                     });
                 } else {
@@ -219,6 +253,10 @@ where
 
         self.expect_byte(b')')?;
 
-        Ok(Some(ast::CallArguments { positional, named }))
+        Ok(Some(ast::CallArguments {
+            positional,
+            named,
+            span: ast::Span::new(start..self.ptr),
+        }))
     }
 }
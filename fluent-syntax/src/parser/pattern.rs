@@ -24,7 +24,8 @@ enum TextElementPosition {
 // cheaper since they'll happen on the pointers, rather than extracted slices.
 #[derive(Debug)]
 enum PatternElementPlaceholders<S> {
-    Placeable(ast::Expression<S>),
+    // (expression, start, end)
+    Placeable(ast::Expression<S>, usize, usize),
     // (start, end, indent, position)
     TextElement(usize, usize, usize, TextElementPosition),
 }
@@ -57,13 +58,18 @@ where
         };
 
         while self.ptr < self.length {
+            let placeable_start = self.ptr;
             if self.take_byte_if(b'{') {
                 if text_element_role == TextElementPosition::LineStart {
                     common_indent = Some(0);
                 }
                 let exp = self.get_placeable()?;
                 last_non_blank = Some(elements.len());
-                elements.push(PatternElementPlaceholders::Placeable(exp));
+                elements.push(PatternElementPlaceholders::Placeable(
+                    exp,
+                    placeable_start,
+                    self.ptr,
+                ));
                 text_element_role = TextElementPosition::Continuation;
             } else {
                 let slice_start = self.ptr;
@@ -122,13 +128,16 @@ where
         }
 
         if let Some(last_non_blank) = last_non_blank {
-            let elements = elements
+            let elements: Vec<_> = elements
                 .into_iter()
                 .take(last_non_blank + 1)
                 .enumerate()
                 .map(|(i, elem)| match elem {
-                    PatternElementPlaceholders::Placeable(expression) => {
-                        ast::PatternElement::Placeable { expression }
+                    PatternElementPlaceholders::Placeable(expression, start, end) => {
+                        ast::PatternElement::Placeable {
+                            expression,
+                            span: ast::Span::new(start..end),
+                        }
                     }
                     PatternElementPlaceholders::TextElement(start, end, indent, role) => {
                         let start = if role == TextElementPosition::LineStart {
@@ -143,11 +152,19 @@ where
                         if last_non_blank == i {
                             value.trim();
                         }
-                        ast::PatternElement::TextElement { value }
+                        let end = start + value.as_ref().len();
+                        ast::PatternElement::TextElement {
+                            value,
+                            span: ast::Span::new(start..end),
+                        }
                     }
                 })
                 .collect();
-            return Ok(Some(ast::Pattern { elements }));
+            let span = match (elements.first(), elements.last()) {
+                (Some(first), Some(last)) => ast::Span::new(first.span().start..last.span().end),
+                _ => ast::Span::default(),
+            };
+            return Ok(Some(ast::Pattern { elements, span }));
         }
 
         Ok(None)
@@ -34,16 +34,23 @@ where
                     err.slice = Some(entry_start..self.ptr);
                     errors.push(err);
                     let content = self.source.slice(entry_start..self.ptr);
-                    body.push(ast::Entry::Junk { content });
+                    body.push(ast::Entry::Junk {
+                        content,
+                        span: ast::Span::new(entry_start..self.ptr),
+                    });
                 }
             }
             self.skip_blank_block();
         }
 
+        let resource = ast::Resource {
+            body,
+            span: ast::Span::new(0..self.length),
+        };
         if errors.is_empty() {
-            Ok(ast::Resource { body })
+            Ok(resource)
         } else {
-            Err((ast::Resource { body }, errors))
+            Err((resource, errors))
         }
     }
 
@@ -0,0 +1,159 @@
+//! Fluent Translation List formatting utilities
+//!
+//! This module normalizes the layout of a Fluent Translation List, the way a
+//! formatter does for code. It builds on the [`serializer`](crate::serializer), and
+//! in addition controls the blank lines between entries and can tidy up comments.
+//!
+//! The output parses to the same abstract syntax tree as the input, except for
+//! [`Junk`](Entry::Junk), which is dropped, and the comments, when
+//! [`normalize_comments`](PrettyPrintOptions::normalize_comments) is set.
+//!
+//! # Example
+//!
+//! ```
+//! use fluent_syntax::parser;
+//! use fluent_syntax::pretty_printer::{pretty_print, PrettyPrintOptions};
+//!
+//! let ftl = r#"## Greetings
+//! hello=Hello!
+//!
+//!
+//! welcome = Welcome, { $user }!
+//! ## Farewells
+//! bye = Bye!
+//! "#;
+//!
+//! let resource = parser::parse(ftl).expect("Failed to parse an FTL resource.");
+//!
+//! let formatted = pretty_print(&resource, PrettyPrintOptions::default());
+//!
+//! let expected = r#"## Greetings
+//!
+//! hello = Hello!
+//!
+//! welcome = Welcome, { $user }!
+//!
+//!
+//! ## Farewells
+//!
+//! bye = Bye!
+//! "#;
+//!
+//! assert_eq!(expected, formatted);
+//! ```
+
+use crate::ast::*;
+use crate::parser::matches_fluent_ws;
+use crate::serializer::{serialize_entry, Options};
+
+/// Formats an abstract syntax tree representing a Fluent Translation List into a
+/// String with consistent whitespace.
+pub fn pretty_print(resource: &Resource<&str>, options: PrettyPrintOptions) -> String {
+    let mut output = String::new();
+    let mut previous: Option<&Entry<&str>> = None;
+
+    for entry in &resource.body {
+        if let Entry::Junk { .. } = entry {
+            continue;
+        }
+
+        if let Some(previous) = previous {
+            for _ in 0..options.blank_lines_before(previous, entry) {
+                output.push('\n');
+            }
+        }
+
+        let serialized = if options.normalize_comments {
+            serialize_entry(&normalize_entry_comments(entry), Options::default())
+        } else {
+            serialize_entry(entry, Options::default())
+        };
+        // The serializer ends standalone comments with a blank line of its own.
+        output.push_str(serialized.trim_end_matches('\n'));
+        output.push('\n');
+
+        previous = Some(entry);
+    }
+
+    output
+}
+
+/// Options for pretty-printing an abstract syntax tree.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PrettyPrintOptions {
+    /// The number of blank lines between two entries. Defaults to 1.
+    ///
+    /// A comment is always followed by at least one blank line, so that it
+    /// does not become part of the next entry.
+    pub blank_lines_between_messages: u8,
+    /// The number of blank lines before a group or resource comment. Defaults to 2.
+    pub blank_lines_between_groups: u8,
+    /// Whether to strip trailing whitespace from the lines of comments and
+    /// leading and trailing empty lines from comments. Defaults to `false`.
+    pub normalize_comments: bool,
+}
+
+impl Default for PrettyPrintOptions {
+    fn default() -> Self {
+        Self {
+            blank_lines_between_messages: 1,
+            blank_lines_between_groups: 2,
+            normalize_comments: false,
+        }
+    }
+}
+
+impl PrettyPrintOptions {
+    fn blank_lines_before(&self, previous: &Entry<&str>, entry: &Entry<&str>) -> u8 {
+        let blank_lines = match entry {
+            Entry::GroupComment(_) | Entry::ResourceComment(_) => self.blank_lines_between_groups,
+            _ => self.blank_lines_between_messages,
+        };
+
+        match previous {
+            Entry::Comment(_) | Entry::GroupComment(_) | Entry::ResourceComment(_) => {
+                blank_lines.max(1)
+            }
+            _ => blank_lines,
+        }
+    }
+}
+
+fn normalize_entry_comments<'s>(entry: &Entry<&'s str>) -> Entry<&'s str> {
+    let mut entry = entry.clone();
+    match &mut entry {
+        Entry::Message(Message {
+            comment: Some(comment),
+            ..
+        })
+        | Entry::Term(Term {
+            comment: Some(comment),
+            ..
+        })
+        | Entry::Comment(comment)
+        | Entry::GroupComment(comment)
+        | Entry::ResourceComment(comment) => normalize_comment(comment),
+        _ => {}
+    }
+    entry
+}
+
+fn normalize_comment(comment: &mut Comment<&str>) {
+    for line in comment.content.iter_mut() {
+        *line = line.trim_end_matches(matches_fluent_ws);
+    }
+
+    let start = comment
+        .content
+        .iter()
+        .position(|line| !line.is_empty())
+        .unwrap_or(0);
+    let end = comment
+        .content
+        .iter()
+        .rposition(|line| !line.is_empty())
+        .map_or(comment.content.len().min(1), |end| end + 1);
+
+    comment.content.truncate(end);
+    comment.content.drain(..start);
+}
@@ -21,7 +21,7 @@
 //! assert_eq!(ftl, serialized);
 //! ```
 
-use crate::{ast::*, parser::Slice, parser::matches_fluent_ws};
+use crate::{ast::*, parser::matches_fluent_ws, parser::Slice};
 use std::fmt::Write;
 
 /// Serializes an abstract syntax tree representing a Fluent Translation List into a
@@ -63,6 +63,14 @@ pub fn serialize_with_options<'s, S: Slice<'s>>(
     ser.into_serialized_text()
 }
 
+/// Serializes a single entry, without the blank line that separates it from
+/// the entries before it.
+pub(crate) fn serialize_entry<'s, S: Slice<'s>>(entry: &Entry<S>, options: Options) -> String {
+    let mut ser = Serializer::new(options);
+    ser.serialize_entry(entry);
+    ser.into_serialized_text()
+}
+
 #[derive(Debug)]
 struct Serializer {
     writer: TextWriter,
@@ -73,7 +81,10 @@ struct Serializer {
 impl Serializer {
     fn new(options: Options) -> Self {
         Serializer {
-            writer: TextWriter::default(),
+            writer: TextWriter {
+                indent_width: options.indent.max(1),
+                ..Default::default()
+            },
             options,
             state: State::default(),
         }
@@ -81,21 +92,25 @@ impl Serializer {
 
     fn serialize_resource<'s, S: Slice<'s>>(&mut self, res: &Resource<S>) {
         for entry in &res.body {
-            match entry {
-                Entry::Message(msg) => self.serialize_message(msg),
-                Entry::Term(term) => self.serialize_term(term),
-                Entry::Comment(comment) => self.serialize_free_comment(comment, "#"),
-                Entry::GroupComment(comment) => self.serialize_free_comment(comment, "##"),
-                Entry::ResourceComment(comment) => self.serialize_free_comment(comment, "###"),
-                Entry::Junk { content } => {
-                    if self.options.with_junk {
-                        self.serialize_junk(content.as_ref())
-                    }
+            self.serialize_entry(entry);
+        }
+    }
+
+    fn serialize_entry<'s, S: Slice<'s>>(&mut self, entry: &Entry<S>) {
+        match entry {
+            Entry::Message(msg) => self.serialize_message(msg),
+            Entry::Term(term) => self.serialize_term(term),
+            Entry::Comment(comment) => self.serialize_free_comment(comment, "#"),
+            Entry::GroupComment(comment) => self.serialize_free_comment(comment, "##"),
+            Entry::ResourceComment(comment) => self.serialize_free_comment(comment, "###"),
+            Entry::Junk { content, .. } => {
+                if self.options.with_junk {
+                    self.serialize_junk(content.as_ref())
                 }
-            };
+            }
+        };
 
-            self.state.wrote_non_junk_entry = !matches!(entry, Entry::Junk { .. });
-        }
+        self.state.wrote_non_junk_entry = !matches!(entry, Entry::Junk { .. });
     }
 
     fn into_serialized_text(self) -> String {
@@ -118,11 +133,7 @@ impl Serializer {
         for line in &comment.content {
             self.writer.write_literal(prefix);
 
-            if !line
-                .as_ref()
-                .trim_matches(matches_fluent_ws)
-                .is_empty()
-            {
+            if !line.as_ref().trim_matches(matches_fluent_ws).is_empty() {
                 self.writer.write_literal(" ");
                 self.writer.write_literal(line.as_ref());
             }
@@ -207,9 +218,9 @@ impl Serializer {
 
     fn serialize_element<'s, S: Slice<'s>>(&mut self, elem: &PatternElement<S>) {
         match elem {
-            PatternElement::TextElement { value } => self.writer.write_literal(value.as_ref()),
-            PatternElement::Placeable { expression } => match expression {
-                Expression::Inline(InlineExpression::Placeable { expression }) => {
+            PatternElement::TextElement { value, .. } => self.writer.write_literal(value.as_ref()),
+            PatternElement::Placeable { expression, .. } => match expression {
+                Expression::Inline(InlineExpression::Placeable { expression, .. }) => {
                     // A placeable inside a placeable is a special case because we
                     // don't want the braces to look silly (e.g. "{ { Foo() } }").
                     self.writer.write_literal("{{ ");
@@ -235,31 +246,34 @@ impl Serializer {
     fn serialize_expression<'s, S: Slice<'s>>(&mut self, expr: &Expression<S>) {
         match expr {
             Expression::Inline(inline) => self.serialize_inline_expression(inline),
-            Expression::Select { selector, variants } => {
-                self.serialize_select_expression(selector, variants)
-            }
+            Expression::Select {
+                selector, variants, ..
+            } => self.serialize_select_expression(selector, variants),
         }
     }
 
     fn serialize_inline_expression<'s, S: Slice<'s>>(&mut self, expr: &InlineExpression<S>) {
         match expr {
-            InlineExpression::StringLiteral { value } => {
+            InlineExpression::StringLiteral { value, .. } => {
                 self.writer.write_literal("\"");
                 self.writer.write_literal(value.as_ref());
                 self.writer.write_literal("\"");
             }
-            InlineExpression::NumberLiteral { value } => self.writer.write_literal(value.as_ref()),
+            InlineExpression::NumberLiteral { value, .. } => {
+                self.writer.write_literal(value.as_ref())
+            }
             InlineExpression::VariableReference {
-                id: Identifier { name: value },
+                id: Identifier { name: value, .. },
+                ..
             } => {
                 self.writer.write_literal("$");
                 self.writer.write_literal(value.as_ref());
             }
-            InlineExpression::FunctionReference { id, arguments } => {
+            InlineExpression::FunctionReference { id, arguments, .. } => {
                 self.writer.write_literal(id.name.as_ref());
                 self.serialize_call_arguments(arguments);
             }
-            InlineExpression::MessageReference { id, attribute } => {
+            InlineExpression::MessageReference { id, attribute, .. } => {
                 self.writer.write_literal(id.name.as_ref());
 
                 if let Some(attr) = attribute.as_ref() {
@@ -271,6 +285,7 @@ impl Serializer {
                 id,
                 attribute,
                 arguments,
+                ..
             } => {
                 self.writer.write_literal("-");
                 self.writer.write_literal(id.name.as_ref());
@@ -283,7 +298,7 @@ impl Serializer {
                     self.serialize_call_arguments(args);
                 }
             }
-            InlineExpression::Placeable { expression } => {
+            InlineExpression::Placeable { expression, .. } => {
                 self.writer.write_literal("{");
                 self.serialize_expression(expression);
                 self.writer.write_literal("}");
@@ -323,7 +338,8 @@ impl Serializer {
 
     fn serialize_variant_key<'s, S: Slice<'s>>(&mut self, key: &VariantKey<S>) {
         match key {
-            VariantKey::NumberLiteral { value } | VariantKey::Identifier { name: value } => {
+            VariantKey::NumberLiteral { value, .. }
+            | VariantKey::Identifier { name: value, .. } => {
                 self.writer.write_literal(value.as_ref())
             }
         }
@@ -365,13 +381,13 @@ impl<'s, S: Slice<'s>> Pattern<S> {
 
     fn is_multiline(&self) -> bool {
         self.elements.iter().any(|elem| match elem {
-            PatternElement::TextElement { value } => value.as_ref().contains('\n'),
-            PatternElement::Placeable { expression } => is_select_expr(expression),
+            PatternElement::TextElement { value, .. } => value.as_ref().contains('\n'),
+            PatternElement::Placeable { expression, .. } => is_select_expr(expression),
         })
     }
 
     fn has_leading_text_dot(&self) -> bool {
-        if let Some(PatternElement::TextElement { value }) = self.elements.get(0) {
+        if let Some(PatternElement::TextElement { value, .. }) = self.elements.first() {
             value.as_ref().starts_with('.')
         } else {
             false
@@ -382,7 +398,7 @@ impl<'s, S: Slice<'s>> Pattern<S> {
 fn is_select_expr<'s, S: Slice<'s>>(expr: &Expression<S>) -> bool {
     match expr {
         Expression::Select { .. } => true,
-        Expression::Inline(InlineExpression::Placeable { expression }) => {
+        Expression::Inline(InlineExpression::Placeable { expression, .. }) => {
             is_select_expr(expression)
         }
         Expression::Inline(_) => false,
@@ -390,10 +406,21 @@ fn is_select_expr<'s, S: Slice<'s>>(expr: &Expression<S>) -> bool {
 }
 
 /// Options for serializing an abstract syntax tree.
-#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Options {
     /// Whether invalid text fragments should be serialized, too.
     pub with_junk: bool,
+    /// The number of spaces per level of indentation, at least 1. Defaults to 4.
+    pub indent: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            with_junk: false,
+            indent: 4,
+        }
+    }
 }
 
 #[derive(Debug, Default, PartialEq)]
@@ -401,10 +428,21 @@ struct State {
     wrote_non_junk_entry: bool,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 struct TextWriter {
     buffer: String,
     indent_level: usize,
+    indent_width: usize,
+}
+
+impl Default for TextWriter {
+    fn default() -> Self {
+        Self {
+            buffer: String::new(),
+            indent_level: 0,
+            indent_width: Options::default().indent,
+        }
+    }
 }
 
 impl TextWriter {
@@ -420,8 +458,8 @@ impl TextWriter {
     }
 
     fn write_indent(&mut self) {
-        for _ in 0..self.indent_level {
-            self.buffer.push_str("    ");
+        for _ in 0..self.indent_level * self.indent_width {
+            self.buffer.push(' ');
         }
     }
 
@@ -482,12 +520,20 @@ mod test {
     macro_rules! text_message {
         ($name:expr, $value:expr) => {
             Entry::Message(Message {
-                id: Identifier { name: $name },
+                id: Identifier {
+                    name: $name,
+                    span: Span::default(),
+                },
                 value: Some(Pattern {
-                    elements: vec![PatternElement::TextElement { value: $value }],
+                    elements: vec![PatternElement::TextElement {
+                        value: $value,
+                        span: Span::default(),
+                    }],
+                    span: Span::default(),
                 }),
                 attributes: vec![],
                 comment: None,
+                span: Span::default(),
             })
         };
     }
@@ -510,14 +556,14 @@ mod test {
     impl<'a> PatternElement<&'a str> {
         fn as_text(&mut self) -> &mut &'a str {
             match self {
-                Self::TextElement { value } => value,
+                Self::TextElement { value, .. } => value,
                 _ => panic!("Expected TextElement"),
             }
         }
 
         fn as_expression(&mut self) -> &mut Expression<&'a str> {
             match self {
-                Self::Placeable { expression } => expression,
+                Self::Placeable { expression, .. } => expression,
                 _ => panic!("Expected Placeable"),
             }
         }
@@ -532,7 +578,7 @@ mod test {
         }
         fn as_inline_variable_id(&mut self) -> &mut Identifier<&'a str> {
             match self {
-                Self::Inline(InlineExpression::VariableReference { id }) => id,
+                Self::Inline(InlineExpression::VariableReference { id, .. }) => id,
                 _ => panic!("Expected Inline"),
             }
         }
@@ -563,18 +609,31 @@ mod test {
         let mut ast = parse(message).expect("failed to parse ftl resource");
 
         let one_variant = Variant {
-            key: VariantKey::Identifier { name: "one" },
+            key: VariantKey::Identifier {
+                name: "one",
+                span: Span::default(),
+            },
             value: Pattern {
                 elements: vec![
                     PatternElement::Placeable {
                         expression: Expression::Inline(InlineExpression::VariableReference {
-                            id: Identifier { name: "num" },
+                            id: Identifier {
+                                name: "num",
+                                span: Span::default(),
+                            },
+                            span: Span::default(),
                         }),
+                        span: Span::default(),
+                    },
+                    PatternElement::TextElement {
+                        value: " bar",
+                        span: Span::default(),
                     },
-                    PatternElement::TextElement { value: " bar" },
                 ],
+                span: Span::default(),
             },
             default: false,
+            span: Span::default(),
         };
         ast.body[0].as_message().as_pattern().elements[0]
             .as_expression()
@@ -634,6 +693,7 @@ mod test {
         let mut ast = parse("foo = bar\n").expect("failed to parse ftl resource");
         ast.body[0].as_message().comment.replace(Comment {
             content: vec!["great message!"],
+            span: Span::default(),
         });
         assert_eq!("# great message!\nfoo = bar\n", serialize(&ast));
     }
@@ -0,0 +1,684 @@
+//! Fluent Translation List traversal utilities
+//!
+//! This module provides the [`Visitor`] and [`VisitorMut`] traits, which walk an
+//! abstract syntax tree node by node. Each trait has a method per node type, and the
+//! default implementation of each method visits the children of the node by calling
+//! the matching `walk_*` function. Overriding a method lets a tool act on a kind of
+//! node, and calling the `walk_*` function from the override keeps the traversal going.
+//!
+//! # Example
+//!
+//! ```
+//! use fluent_syntax::ast;
+//! use fluent_syntax::parser;
+//! use fluent_syntax::visitor::{self, Visitor};
+//!
+//! #[derive(Default)]
+//! struct Variables<'ast> {
+//!     names: Vec<&'ast str>,
+//! }
+//!
+//! impl<'ast> Visitor<'ast, &'ast str> for Variables<'ast> {
+//!     fn visit_inline_expression(&mut self, expression: &'ast ast::InlineExpression<&'ast str>) {
+//!         if let ast::InlineExpression::VariableReference { id, .. } = expression {
+//!             self.names.push(id.name);
+//!         }
+//!         visitor::walk_inline_expression(self, expression);
+//!     }
+//! }
+//!
+//! let ftl = "hello = Hello, { $name }! You have { NUMBER($count) } messages.\n";
+//! let resource = parser::parse(ftl).expect("Failed to parse an FTL resource.");
+//!
+//! let mut variables = Variables::default();
+//! variables.visit_resource(&resource);
+//!
+//! assert_eq!(variables.names, vec!["name", "count"]);
+//! ```
+
+use crate::ast::*;
+
+/// Visits the nodes of an abstract syntax tree by reference.
+///
+/// The nodes are visited in the order in which they appear in the source.
+pub trait Visitor<'ast, S: 'ast> {
+    fn visit_resource(&mut self, resource: &'ast Resource<S>) {
+        walk_resource(self, resource)
+    }
+
+    fn visit_entry(&mut self, entry: &'ast Entry<S>) {
+        walk_entry(self, entry)
+    }
+
+    fn visit_message(&mut self, message: &'ast Message<S>) {
+        walk_message(self, message)
+    }
+
+    fn visit_term(&mut self, term: &'ast Term<S>) {
+        walk_term(self, term)
+    }
+
+    /// Called for the comments of messages and terms, and for standalone comments,
+    /// group comments and resource comments.
+    fn visit_comment(&mut self, comment: &'ast Comment<S>) {
+        walk_comment(self, comment)
+    }
+
+    fn visit_attribute(&mut self, attribute: &'ast Attribute<S>) {
+        walk_attribute(self, attribute)
+    }
+
+    /// Called for the ids of messages, terms and attributes, and for every identifier
+    /// in expressions, like the names of variables, functions and named arguments.
+    fn visit_identifier(&mut self, identifier: &'ast Identifier<S>) {
+        walk_identifier(self, identifier)
+    }
+
+    fn visit_pattern(&mut self, pattern: &'ast Pattern<S>) {
+        walk_pattern(self, pattern)
+    }
+
+    fn visit_pattern_element(&mut self, element: &'ast PatternElement<S>) {
+        walk_pattern_element(self, element)
+    }
+
+    fn visit_expression(&mut self, expression: &'ast Expression<S>) {
+        walk_expression(self, expression)
+    }
+
+    fn visit_inline_expression(&mut self, expression: &'ast InlineExpression<S>) {
+        walk_inline_expression(self, expression)
+    }
+
+    fn visit_variant(&mut self, variant: &'ast Variant<S>) {
+        walk_variant(self, variant)
+    }
+
+    fn visit_variant_key(&mut self, key: &'ast VariantKey<S>) {
+        walk_variant_key(self, key)
+    }
+
+    fn visit_call_arguments(&mut self, arguments: &'ast CallArguments<S>) {
+        walk_call_arguments(self, arguments)
+    }
+
+    fn visit_named_argument(&mut self, argument: &'ast NamedArgument<S>) {
+        walk_named_argument(self, argument)
+    }
+
+    /// Called for the span of every node, after the node itself and before its children.
+    fn visit_span(&mut self, _span: &'ast Span) {}
+}
+
+pub fn walk_resource<'ast, S, V>(visitor: &mut V, resource: &'ast Resource<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&resource.span);
+    for entry in &resource.body {
+        visitor.visit_entry(entry);
+    }
+}
+
+pub fn walk_entry<'ast, S, V>(visitor: &mut V, entry: &'ast Entry<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    match entry {
+        Entry::Message(message) => visitor.visit_message(message),
+        Entry::Term(term) => visitor.visit_term(term),
+        Entry::Comment(comment)
+        | Entry::GroupComment(comment)
+        | Entry::ResourceComment(comment) => visitor.visit_comment(comment),
+        Entry::Junk { span, .. } => visitor.visit_span(span),
+    }
+}
+
+pub fn walk_message<'ast, S, V>(visitor: &mut V, message: &'ast Message<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&message.span);
+    if let Some(comment) = &message.comment {
+        visitor.visit_comment(comment);
+    }
+    visitor.visit_identifier(&message.id);
+    if let Some(value) = &message.value {
+        visitor.visit_pattern(value);
+    }
+    for attribute in &message.attributes {
+        visitor.visit_attribute(attribute);
+    }
+}
+
+pub fn walk_term<'ast, S, V>(visitor: &mut V, term: &'ast Term<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&term.span);
+    if let Some(comment) = &term.comment {
+        visitor.visit_comment(comment);
+    }
+    visitor.visit_identifier(&term.id);
+    visitor.visit_pattern(&term.value);
+    for attribute in &term.attributes {
+        visitor.visit_attribute(attribute);
+    }
+}
+
+pub fn walk_comment<'ast, S, V>(visitor: &mut V, comment: &'ast Comment<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&comment.span);
+}
+
+pub fn walk_identifier<'ast, S, V>(visitor: &mut V, identifier: &'ast Identifier<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&identifier.span);
+}
+
+pub fn walk_attribute<'ast, S, V>(visitor: &mut V, attribute: &'ast Attribute<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&attribute.span);
+    visitor.visit_identifier(&attribute.id);
+    visitor.visit_pattern(&attribute.value);
+}
+
+pub fn walk_pattern<'ast, S, V>(visitor: &mut V, pattern: &'ast Pattern<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&pattern.span);
+    for element in &pattern.elements {
+        visitor.visit_pattern_element(element);
+    }
+}
+
+pub fn walk_pattern_element<'ast, S, V>(visitor: &mut V, element: &'ast PatternElement<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    match element {
+        PatternElement::TextElement { span, .. } => visitor.visit_span(span),
+        PatternElement::Placeable { expression, span } => {
+            visitor.visit_span(span);
+            visitor.visit_expression(expression);
+        }
+    }
+}
+
+pub fn walk_expression<'ast, S, V>(visitor: &mut V, expression: &'ast Expression<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    match expression {
+        Expression::Select {
+            selector,
+            variants,
+            span,
+        } => {
+            visitor.visit_span(span);
+            visitor.visit_inline_expression(selector);
+            for variant in variants {
+                visitor.visit_variant(variant);
+            }
+        }
+        Expression::Inline(expression) => visitor.visit_inline_expression(expression),
+    }
+}
+
+pub fn walk_inline_expression<'ast, S, V>(visitor: &mut V, expression: &'ast InlineExpression<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    match expression {
+        InlineExpression::StringLiteral { span, .. }
+        | InlineExpression::NumberLiteral { span, .. } => visitor.visit_span(span),
+        InlineExpression::FunctionReference {
+            id,
+            arguments,
+            span,
+        } => {
+            visitor.visit_span(span);
+            visitor.visit_identifier(id);
+            visitor.visit_call_arguments(arguments);
+        }
+        InlineExpression::MessageReference {
+            id,
+            attribute,
+            span,
+        } => {
+            visitor.visit_span(span);
+            visitor.visit_identifier(id);
+            if let Some(attribute) = attribute {
+                visitor.visit_identifier(attribute);
+            }
+        }
+        InlineExpression::TermReference {
+            id,
+            attribute,
+            arguments,
+            span,
+        } => {
+            visitor.visit_span(span);
+            visitor.visit_identifier(id);
+            if let Some(attribute) = attribute {
+                visitor.visit_identifier(attribute);
+            }
+            if let Some(arguments) = arguments {
+                visitor.visit_call_arguments(arguments);
+            }
+        }
+        InlineExpression::VariableReference { id, span } => {
+            visitor.visit_span(span);
+            visitor.visit_identifier(id);
+        }
+        InlineExpression::Placeable { expression, span } => {
+            visitor.visit_span(span);
+            visitor.visit_expression(expression);
+        }
+    }
+}
+
+pub fn walk_variant<'ast, S, V>(visitor: &mut V, variant: &'ast Variant<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&variant.span);
+    visitor.visit_variant_key(&variant.key);
+    visitor.visit_pattern(&variant.value);
+}
+
+pub fn walk_variant_key<'ast, S, V>(visitor: &mut V, key: &'ast VariantKey<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    match key {
+        VariantKey::Identifier { span, .. } | VariantKey::NumberLiteral { span, .. } => {
+            visitor.visit_span(span)
+        }
+    }
+}
+
+pub fn walk_call_arguments<'ast, S, V>(visitor: &mut V, arguments: &'ast CallArguments<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&arguments.span);
+    for argument in &arguments.positional {
+        visitor.visit_inline_expression(argument);
+    }
+    for argument in &arguments.named {
+        visitor.visit_named_argument(argument);
+    }
+}
+
+pub fn walk_named_argument<'ast, S, V>(visitor: &mut V, argument: &'ast NamedArgument<S>)
+where
+    V: Visitor<'ast, S> + ?Sized,
+{
+    visitor.visit_span(&argument.span);
+    visitor.visit_identifier(&argument.name);
+    visitor.visit_inline_expression(&argument.value);
+}
+
+/// Visits the nodes of an abstract syntax tree by mutable reference, so that they can
+/// be changed in place.
+///
+/// The nodes are visited in the same order as with [`Visitor`].
+///
+/// # Example
+///
+/// ```
+/// use fluent_syntax::ast;
+/// use fluent_syntax::parser;
+/// use fluent_syntax::serializer;
+/// use fluent_syntax::visitor::{self, VisitorMut};
+///
+/// struct StripComments;
+///
+/// impl<S> VisitorMut<S> for StripComments {
+///     fn visit_resource_mut(&mut self, resource: &mut ast::Resource<S>) {
+///         resource.body.retain(|entry| {
+///             !matches!(
+///                 entry,
+///                 ast::Entry::Comment(_) | ast::Entry::GroupComment(_) | ast::Entry::ResourceComment(_)
+///             )
+///         });
+///         visitor::walk_resource_mut(self, resource);
+///     }
+///
+///     fn visit_message_mut(&mut self, message: &mut ast::Message<S>) {
+///         message.comment = None;
+///     }
+/// }
+///
+/// let ftl = "## Greetings\n\n# The greeting.\nhello = Hello\n";
+/// let mut resource = parser::parse(ftl).expect("Failed to parse an FTL resource.");
+///
+/// StripComments.visit_resource_mut(&mut resource);
+///
+/// assert_eq!(serializer::serialize(&resource), "hello = Hello\n");
+/// ```
+pub trait VisitorMut<S> {
+    fn visit_resource_mut(&mut self, resource: &mut Resource<S>) {
+        walk_resource_mut(self, resource)
+    }
+
+    fn visit_entry_mut(&mut self, entry: &mut Entry<S>) {
+        walk_entry_mut(self, entry)
+    }
+
+    fn visit_message_mut(&mut self, message: &mut Message<S>) {
+        walk_message_mut(self, message)
+    }
+
+    fn visit_term_mut(&mut self, term: &mut Term<S>) {
+        walk_term_mut(self, term)
+    }
+
+    /// Called for the comments of messages and terms, and for standalone comments,
+    /// group comments and resource comments.
+    fn visit_comment_mut(&mut self, comment: &mut Comment<S>) {
+        walk_comment_mut(self, comment)
+    }
+
+    fn visit_attribute_mut(&mut self, attribute: &mut Attribute<S>) {
+        walk_attribute_mut(self, attribute)
+    }
+
+    /// Called for the ids of messages, terms and attributes, and for every identifier
+    /// in expressions, like the names of variables, functions and named arguments.
+    fn visit_identifier_mut(&mut self, identifier: &mut Identifier<S>) {
+        walk_identifier_mut(self, identifier)
+    }
+
+    fn visit_pattern_mut(&mut self, pattern: &mut Pattern<S>) {
+        walk_pattern_mut(self, pattern)
+    }
+
+    fn visit_pattern_element_mut(&mut self, element: &mut PatternElement<S>) {
+        walk_pattern_element_mut(self, element)
+    }
+
+    fn visit_expression_mut(&mut self, expression: &mut Expression<S>) {
+        walk_expression_mut(self, expression)
+    }
+
+    fn visit_inline_expression_mut(&mut self, expression: &mut InlineExpression<S>) {
+        walk_inline_expression_mut(self, expression)
+    }
+
+    fn visit_variant_mut(&mut self, variant: &mut Variant<S>) {
+        walk_variant_mut(self, variant)
+    }
+
+    fn visit_variant_key_mut(&mut self, key: &mut VariantKey<S>) {
+        walk_variant_key_mut(self, key)
+    }
+
+    fn visit_call_arguments_mut(&mut self, arguments: &mut CallArguments<S>) {
+        walk_call_arguments_mut(self, arguments)
+    }
+
+    fn visit_named_argument_mut(&mut self, argument: &mut NamedArgument<S>) {
+        walk_named_argument_mut(self, argument)
+    }
+
+    /// Called for the span of every node, after the node itself and before its children.
+    fn visit_span_mut(&mut self, _span: &mut Span) {}
+}
+
+/// Resets the span of every node to [`Span::default`].
+///
+/// The derived `PartialEq` of the nodes compares their spans, so two resources that only
+/// differ in whitespace, or a parsed resource and one built by hand, are equal only once
+/// their spans are stripped.
+///
+/// # Example
+///
+/// ```
+/// use fluent_syntax::parser;
+/// use fluent_syntax::visitor;
+///
+/// let mut resource = parser::parse("hello = Hello\n").expect("Failed to parse an FTL resource.");
+/// let mut indented = parser::parse("\n\nhello =\n    Hello\n").expect("Failed to parse an FTL resource.");
+/// assert_ne!(resource, indented);
+///
+/// visitor::strip_spans(&mut resource);
+/// visitor::strip_spans(&mut indented);
+/// assert_eq!(resource, indented);
+/// ```
+pub fn strip_spans<S>(resource: &mut Resource<S>) {
+    StripSpans.visit_resource_mut(resource)
+}
+
+struct StripSpans;
+
+impl<S> VisitorMut<S> for StripSpans {
+    fn visit_span_mut(&mut self, span: &mut Span) {
+        *span = Span::default();
+    }
+}
+
+pub fn walk_resource_mut<S, V>(visitor: &mut V, resource: &mut Resource<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut resource.span);
+    for entry in &mut resource.body {
+        visitor.visit_entry_mut(entry);
+    }
+}
+
+pub fn walk_entry_mut<S, V>(visitor: &mut V, entry: &mut Entry<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    match entry {
+        Entry::Message(message) => visitor.visit_message_mut(message),
+        Entry::Term(term) => visitor.visit_term_mut(term),
+        Entry::Comment(comment)
+        | Entry::GroupComment(comment)
+        | Entry::ResourceComment(comment) => visitor.visit_comment_mut(comment),
+        Entry::Junk { span, .. } => visitor.visit_span_mut(span),
+    }
+}
+
+pub fn walk_message_mut<S, V>(visitor: &mut V, message: &mut Message<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut message.span);
+    if let Some(comment) = &mut message.comment {
+        visitor.visit_comment_mut(comment);
+    }
+    visitor.visit_identifier_mut(&mut message.id);
+    if let Some(value) = &mut message.value {
+        visitor.visit_pattern_mut(value);
+    }
+    for attribute in &mut message.attributes {
+        visitor.visit_attribute_mut(attribute);
+    }
+}
+
+pub fn walk_term_mut<S, V>(visitor: &mut V, term: &mut Term<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut term.span);
+    if let Some(comment) = &mut term.comment {
+        visitor.visit_comment_mut(comment);
+    }
+    visitor.visit_identifier_mut(&mut term.id);
+    visitor.visit_pattern_mut(&mut term.value);
+    for attribute in &mut term.attributes {
+        visitor.visit_attribute_mut(attribute);
+    }
+}
+
+pub fn walk_comment_mut<S, V>(visitor: &mut V, comment: &mut Comment<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut comment.span);
+}
+
+pub fn walk_identifier_mut<S, V>(visitor: &mut V, identifier: &mut Identifier<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut identifier.span);
+}
+
+pub fn walk_attribute_mut<S, V>(visitor: &mut V, attribute: &mut Attribute<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut attribute.span);
+    visitor.visit_identifier_mut(&mut attribute.id);
+    visitor.visit_pattern_mut(&mut attribute.value);
+}
+
+pub fn walk_pattern_mut<S, V>(visitor: &mut V, pattern: &mut Pattern<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut pattern.span);
+    for element in &mut pattern.elements {
+        visitor.visit_pattern_element_mut(element);
+    }
+}
+
+pub fn walk_pattern_element_mut<S, V>(visitor: &mut V, element: &mut PatternElement<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    match element {
+        PatternElement::TextElement { span, .. } => visitor.visit_span_mut(span),
+        PatternElement::Placeable { expression, span } => {
+            visitor.visit_span_mut(span);
+            visitor.visit_expression_mut(expression);
+        }
+    }
+}
+
+pub fn walk_expression_mut<S, V>(visitor: &mut V, expression: &mut Expression<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    match expression {
+        Expression::Select {
+            selector,
+            variants,
+            span,
+        } => {
+            visitor.visit_span_mut(span);
+            visitor.visit_inline_expression_mut(selector);
+            for variant in variants {
+                visitor.visit_variant_mut(variant);
+            }
+        }
+        Expression::Inline(expression) => visitor.visit_inline_expression_mut(expression),
+    }
+}
+
+pub fn walk_inline_expression_mut<S, V>(visitor: &mut V, expression: &mut InlineExpression<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    match expression {
+        InlineExpression::StringLiteral { span, .. }
+        | InlineExpression::NumberLiteral { span, .. } => visitor.visit_span_mut(span),
+        InlineExpression::FunctionReference {
+            id,
+            arguments,
+            span,
+        } => {
+            visitor.visit_span_mut(span);
+            visitor.visit_identifier_mut(id);
+            visitor.visit_call_arguments_mut(arguments);
+        }
+        InlineExpression::MessageReference {
+            id,
+            attribute,
+            span,
+        } => {
+            visitor.visit_span_mut(span);
+            visitor.visit_identifier_mut(id);
+            if let Some(attribute) = attribute {
+                visitor.visit_identifier_mut(attribute);
+            }
+        }
+        InlineExpression::TermReference {
+            id,
+            attribute,
+            arguments,
+            span,
+        } => {
+            visitor.visit_span_mut(span);
+            visitor.visit_identifier_mut(id);
+            if let Some(attribute) = attribute {
+                visitor.visit_identifier_mut(attribute);
+            }
+            if let Some(arguments) = arguments {
+                visitor.visit_call_arguments_mut(arguments);
+            }
+        }
+        InlineExpression::VariableReference { id, span } => {
+            visitor.visit_span_mut(span);
+            visitor.visit_identifier_mut(id);
+        }
+        InlineExpression::Placeable { expression, span } => {
+            visitor.visit_span_mut(span);
+            visitor.visit_expression_mut(expression);
+        }
+    }
+}
+
+pub fn walk_variant_mut<S, V>(visitor: &mut V, variant: &mut Variant<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut variant.span);
+    visitor.visit_variant_key_mut(&mut variant.key);
+    visitor.visit_pattern_mut(&mut variant.value);
+}
+
+pub fn walk_variant_key_mut<S, V>(visitor: &mut V, key: &mut VariantKey<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    match key {
+        VariantKey::Identifier { span, .. } | VariantKey::NumberLiteral { span, .. } => {
+            visitor.visit_span_mut(span)
+        }
+    }
+}
+
+pub fn walk_call_arguments_mut<S, V>(visitor: &mut V, arguments: &mut CallArguments<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut arguments.span);
+    for argument in &mut arguments.positional {
+        visitor.visit_inline_expression_mut(argument);
+    }
+    for argument in &mut arguments.named {
+        visitor.visit_named_argument_mut(argument);
+    }
+}
+
+pub fn walk_named_argument_mut<S, V>(visitor: &mut V, argument: &mut NamedArgument<S>)
+where
+    V: VisitorMut<S> + ?Sized,
+{
+    visitor.visit_span_mut(&mut argument.span);
+    visitor.visit_identifier_mut(&mut argument.name);
+    visitor.visit_inline_expression_mut(&mut argument.value);
+}
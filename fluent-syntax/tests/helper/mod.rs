@@ -1,4 +1,5 @@
 use fluent_syntax::ast;
+use fluent_syntax::visitor;
 
 fn adapt_comment(comment: &mut ast::Comment<String>, _crlf: bool) {
     //XXX: We don't handle CRLF comments yet
@@ -13,7 +14,7 @@ fn adapt_pattern(pattern: &mut ast::Pattern<String>, crlf: bool) {
     let mut elements = vec![];
     for element in &pattern.elements {
         match element {
-            ast::PatternElement::TextElement { value } => {
+            ast::PatternElement::TextElement { value, .. } => {
                 let mut start = 0;
                 let len = value.as_bytes().len();
                 for (i, b) in value.as_bytes().iter().enumerate() {
@@ -22,15 +23,22 @@ fn adapt_pattern(pattern: &mut ast::Pattern<String>, crlf: bool) {
                             if i > start {
                                 let chunk = &value.as_bytes()[start..=i - 1];
                                 let value = String::from_utf8_lossy(chunk).to_string();
-                                elements.push(ast::PatternElement::TextElement { value });
+                                elements.push(ast::PatternElement::TextElement {
+                                    value,
+                                    span: ast::Span::default(),
+                                });
                             }
                             elements.push(ast::PatternElement::TextElement {
                                 value: "\n".to_string(),
+                                span: ast::Span::default(),
                             });
                         } else {
                             let chunk = &value.as_bytes()[start..=i];
                             let value = String::from_utf8_lossy(chunk).to_string();
-                            elements.push(ast::PatternElement::TextElement { value });
+                            elements.push(ast::PatternElement::TextElement {
+                                value,
+                                span: ast::Span::default(),
+                            });
                         }
                         start = i + 1;
                     }
@@ -38,13 +46,19 @@ fn adapt_pattern(pattern: &mut ast::Pattern<String>, crlf: bool) {
                 if start < len {
                     let chunk = &value.as_bytes()[start..len];
                     let value = String::from_utf8_lossy(chunk).to_string();
-                    elements.push(ast::PatternElement::TextElement { value });
+                    elements.push(ast::PatternElement::TextElement {
+                        value,
+                        span: ast::Span::default(),
+                    });
                 }
             }
-            ast::PatternElement::Placeable { expression } => {
+            ast::PatternElement::Placeable { expression, .. } => {
                 let mut expression = expression.clone();
                 adapt_expression(&mut expression, crlf);
-                elements.push(ast::PatternElement::Placeable { expression });
+                elements.push(ast::PatternElement::Placeable {
+                    expression,
+                    span: ast::Span::default(),
+                });
             }
         }
     }
@@ -112,3 +126,8 @@ pub fn strip_comments(ast: &mut ast::Resource<String>) {
         }
     }
 }
+
+/// The reference fixtures have no spans, so they are reset before comparing.
+pub fn strip_spans(ast: &mut ast::Resource<String>) {
+    visitor::strip_spans(ast);
+}
@@ -7,7 +7,7 @@ use std::io;
 use fluent_syntax::ast;
 use fluent_syntax::parser::{parse, parse_runtime};
 
-use helper::{adapt_ast, strip_comments};
+use helper::{adapt_ast, strip_comments, strip_spans};
 
 fn read_file(path: &str, trim: bool) -> Result<String, io::Error> {
     let s = fs::read_to_string(path)?;
@@ -30,10 +30,11 @@ fn parse_fixtures_compare() {
         let ftl_file = read_file(&path, false).unwrap();
 
         println!("Parsing: {:#?}", path);
-        let target_ast = match parse(ftl_file) {
+        let mut target_ast = match parse(ftl_file) {
             Ok(res) => res,
             Err((res, _errors)) => res,
         };
+        strip_spans(&mut target_ast);
 
         let mut ref_ast: ast::Resource<String> =
             serde_json::from_str(reference_file.as_str()).unwrap();
@@ -75,10 +76,11 @@ fn parse_bench_fixtures() {
         let ftl_file = read_file(&path, false).unwrap();
 
         println!("Parsing: {:#?}", path);
-        let target_ast = match parse(ftl_file) {
+        let mut target_ast = match parse(ftl_file) {
             Ok(res) => res,
             Err((res, _errors)) => res,
         };
+        strip_spans(&mut target_ast);
 
         let mut ref_ast: ast::Resource<String> =
             serde_json::from_str(reference_file.as_str()).unwrap();
@@ -109,10 +111,11 @@ fn parse_bench_fixtures() {
             let ftl_file = read_file(&path, false).unwrap();
 
             println!("Parsing: {:#?}", path);
-            let target_ast = match parse(ftl_file.clone()) {
+            let mut target_ast = match parse(ftl_file.clone()) {
                 Ok(res) => res,
                 Err((res, _errors)) => res,
             };
+            strip_spans(&mut target_ast);
 
             let mut ref_ast: ast::Resource<String> =
                 serde_json::from_str(reference_file.as_str()).unwrap();
@@ -124,10 +127,11 @@ fn parse_bench_fixtures() {
             }
 
             // Skipping comments
-            let target_ast = match parse_runtime(ftl_file) {
+            let mut target_ast = match parse_runtime(ftl_file) {
                 Ok(res) => res,
                 Err((res, _errors)) => res,
             };
+            strip_spans(&mut target_ast);
 
             strip_comments(&mut ref_ast);
 
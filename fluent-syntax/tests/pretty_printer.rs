@@ -0,0 +1,115 @@
+use fluent_syntax_for_carbide::ast::Resource;
+use fluent_syntax_for_carbide::parser::parse;
+use fluent_syntax_for_carbide::pretty_printer::{pretty_print, PrettyPrintOptions};
+use fluent_syntax_for_carbide::visitor::strip_spans;
+
+const FTL: &str = r#"### Resource comment
+## Group comment
+# Standalone comment
+
+# Message comment
+hello=Hello, { $name }!
+    .title = { -brand(case:"title") }
+-brand = Fluent
+
+
+
+emails = { $count ->
+  [one] One email
+ *[other] { $count } emails
+}
+## Second group
+bye = Bye!
+"#;
+
+fn without_spans(mut resource: Resource<&str>) -> Resource<&str> {
+    strip_spans(&mut resource);
+    resource
+}
+
+#[test]
+fn pretty_print_default() {
+    let resource = parse(FTL).expect("Failed to parse an FTL resource.");
+    let formatted = pretty_print(&resource, PrettyPrintOptions::default());
+
+    let expected = r#"### Resource comment
+
+
+## Group comment
+
+# Standalone comment
+
+# Message comment
+hello = Hello, { $name }!
+    .title = { -brand(case: "title") }
+
+-brand = Fluent
+
+emails =
+    { $count ->
+        [one] One email
+       *[other] { $count } emails
+    }
+
+
+## Second group
+
+bye = Bye!
+"#;
+    assert_eq!(formatted, expected);
+
+    let reparsed = parse(formatted.as_str()).expect("Failed to parse the formatted resource.");
+    assert_eq!(without_spans(reparsed), without_spans(resource));
+    assert_eq!(
+        pretty_print(&parse(expected).unwrap(), PrettyPrintOptions::default()),
+        expected
+    );
+}
+
+#[test]
+fn pretty_print_without_blank_lines() {
+    let resource = parse(FTL).expect("Failed to parse an FTL resource.");
+    let options = PrettyPrintOptions {
+        blank_lines_between_messages: 0,
+        blank_lines_between_groups: 0,
+        ..Default::default()
+    };
+    let formatted = pretty_print(&resource, options);
+
+    let reparsed = parse(formatted.as_str()).expect("Failed to parse the formatted resource.");
+    assert_eq!(without_spans(reparsed), without_spans(resource));
+    assert!(
+        formatted.contains("    .title = { -brand(case: \"title\") }\n-brand = Fluent\nemails =")
+    );
+    assert!(formatted.contains("# Standalone comment\n\n# Message comment\nhello"));
+}
+
+#[test]
+fn pretty_print_normalize_comments() {
+    let ftl = "#\n# Trailing space   \n#\n# Second line\n#\nhello = Hello!\n";
+    let resource = parse(ftl).expect("Failed to parse an FTL resource.");
+
+    let options = PrettyPrintOptions {
+        normalize_comments: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        pretty_print(&resource, options),
+        "# Trailing space\n#\n# Second line\nhello = Hello!\n"
+    );
+    assert_eq!(
+        pretty_print(&resource, PrettyPrintOptions::default()),
+        "#\n# Trailing space   \n#\n# Second line\n#\nhello = Hello!\n"
+    );
+}
+
+#[test]
+fn pretty_print_skips_junk() {
+    let ftl = "hello = Hello!\n= broken\nbye = Bye!\n";
+    let (resource, _) = parse(ftl).expect_err("The resource has junk.");
+
+    assert_eq!(
+        pretty_print(&resource, PrettyPrintOptions::default()),
+        "hello = Hello!\n\nbye = Bye!\n"
+    );
+}
@@ -6,6 +6,7 @@ use std::path::Path;
 
 use fluent_syntax_for_carbide::parser::parse;
 use fluent_syntax_for_carbide::serializer::{serialize, serialize_with_options, Options};
+use fluent_syntax_for_carbide::visitor::strip_spans;
 
 /// List of files that currently do not roundtrip correctly.
 ///
@@ -30,9 +31,16 @@ fn clone_without_junk<'a>(original: &Resource<&'a str>) -> Resource<&'a str> {
             .filter(|entry| !matches!(entry, Entry::Junk { .. }))
             .cloned()
             .collect(),
+        span: original.span,
     }
 }
 
+/// Resets the spans, which change when the whitespace of the source is normalized.
+fn without_spans(mut resource: Resource<&str>) -> Resource<&str> {
+    strip_spans(&mut resource);
+    resource
+}
+
 #[test]
 fn roundtrip_normalized_fixtures() {
     for entry in glob("./tests/fixtures/normalized/*.ftl").expect("Failed to read glob pattern") {
@@ -58,14 +66,22 @@ fn roundtrip_unnormalized_fixtures() {
         let content = fs::read_to_string(&path).expect("Failed to read file");
         let parsed = parse(content.as_str()).unwrap_or_else(|(res, _)| res);
         let parsed_without_junk = clone_without_junk(&parsed);
-        let reserialized = serialize_with_options(&parsed, Options { with_junk: true });
-        let reserialized_without_junk =
-            serialize_with_options(&parsed, Options { with_junk: false });
+        let reserialized = serialize_with_options(
+            &parsed,
+            Options {
+                with_junk: true,
+                ..Default::default()
+            },
+        );
+        let reserialized_without_junk = serialize_with_options(&parsed, Options::default());
         let reparsed = parse(reserialized.as_str()).unwrap_or_else(|(res, _)| res);
         let reparsed_without_junk =
             parse(reserialized_without_junk.as_str()).unwrap_or_else(|(res, _)| res);
 
-        assert_eq!(reparsed_without_junk, parsed_without_junk);
-        assert_eq!(reparsed, parsed);
+        assert_eq!(
+            without_spans(reparsed_without_junk),
+            without_spans(parsed_without_junk)
+        );
+        assert_eq!(without_spans(reparsed), without_spans(parsed));
     }
 }
@@ -0,0 +1,310 @@
+use fluent_syntax_for_carbide::ast::*;
+use fluent_syntax_for_carbide::parser::parse;
+use fluent_syntax_for_carbide::serializer::{serialize_with_options, Options};
+use fluent_syntax_for_carbide::visitor::strip_spans;
+use proptest::prelude::*;
+
+fn identifier() -> impl Strategy<Value = Identifier<String>> {
+    "[a-z][a-z0-9_-]{0,6}".prop_map(|name| Identifier {
+        name,
+        span: Span::default(),
+    })
+}
+
+fn function_identifier() -> impl Strategy<Value = Identifier<String>> {
+    "[A-Z][A-Z0-9_]{0,5}".prop_map(|name| Identifier {
+        name,
+        span: Span::default(),
+    })
+}
+
+/// Text without leading or trailing spaces, which the parser would not keep.
+fn text() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9,!?]([a-zA-Z0-9,!? ]{0,10}[a-zA-Z0-9,!?])?"
+}
+
+fn number() -> impl Strategy<Value = String> {
+    "-?[0-9]{1,3}(\\.[0-9]{1,2})?"
+}
+
+fn literal() -> impl Strategy<Value = InlineExpression<String>> {
+    prop_oneof![
+        "[a-zA-Z0-9 ]{0,8}".prop_map(|value| InlineExpression::StringLiteral {
+            value,
+            span: Span::default()
+        }),
+        number().prop_map(|value| InlineExpression::NumberLiteral {
+            value,
+            span: Span::default()
+        }),
+    ]
+}
+
+fn reference() -> impl Strategy<Value = InlineExpression<String>> {
+    prop_oneof![
+        identifier().prop_map(|id| InlineExpression::VariableReference {
+            id,
+            span: Span::default()
+        }),
+        (identifier(), proptest::option::of(identifier())).prop_map(|(id, attribute)| {
+            InlineExpression::MessageReference {
+                id,
+                attribute,
+                span: Span::default(),
+            }
+        }),
+        // Term attributes can only be used as selectors.
+        identifier().prop_map(|id| InlineExpression::TermReference {
+            id,
+            attribute: None,
+            arguments: None,
+            span: Span::default(),
+        }),
+    ]
+}
+
+fn call_arguments() -> impl Strategy<Value = CallArguments<String>> {
+    (
+        prop::collection::vec(prop_oneof![literal(), reference()], 0..3),
+        prop::collection::vec((identifier(), literal()), 0..3),
+    )
+        .prop_map(|(positional, mut named)| {
+            // Named arguments must be unique.
+            named.sort_by(|a, b| a.0.name.cmp(&b.0.name));
+            named.dedup_by(|a, b| a.0 == b.0);
+            CallArguments {
+                positional,
+                named: named
+                    .into_iter()
+                    .map(|(name, value)| NamedArgument {
+                        name,
+                        value,
+                        span: Span::default(),
+                    })
+                    .collect(),
+                span: Span::default(),
+            }
+        })
+}
+
+fn inline_expression() -> impl Strategy<Value = InlineExpression<String>> {
+    let call = prop_oneof![
+        (function_identifier(), call_arguments()).prop_map(|(id, arguments)| {
+            InlineExpression::FunctionReference {
+                id,
+                arguments,
+                span: Span::default(),
+            }
+        }),
+        (identifier(), call_arguments()).prop_map(|(id, arguments)| {
+            InlineExpression::TermReference {
+                id,
+                attribute: None,
+                arguments: Some(arguments),
+                span: Span::default(),
+            }
+        }),
+    ];
+    let leaf = prop_oneof![literal(), reference(), call];
+
+    prop_oneof![
+        4 => leaf.clone(),
+        1 => leaf.prop_map(|expression| InlineExpression::Placeable {
+            expression: Box::new(Expression::Inline(expression)),
+            span: Span::default(),
+        }),
+    ]
+}
+
+/// Joins adjacent text elements, like the parser does.
+fn pattern_from(elements: Vec<PatternElement<String>>) -> Pattern<String> {
+    let mut joined: Vec<PatternElement<String>> = vec![];
+    for element in elements {
+        match (joined.last_mut(), element) {
+            (
+                Some(PatternElement::TextElement { value: last, .. }),
+                PatternElement::TextElement { value, .. },
+            ) => {
+                last.push(' ');
+                last.push_str(&value);
+            }
+            (_, element) => joined.push(element),
+        }
+    }
+    Pattern {
+        elements: joined,
+        span: Span::default(),
+    }
+}
+
+fn inline_pattern() -> impl Strategy<Value = Pattern<String>> {
+    prop::collection::vec(
+        prop_oneof![
+            text().prop_map(|value| PatternElement::TextElement {
+                value,
+                span: Span::default()
+            }),
+            inline_expression().prop_map(|expression| PatternElement::Placeable {
+                expression: Expression::Inline(expression),
+                span: Span::default(),
+            }),
+        ],
+        1..4,
+    )
+    .prop_map(pattern_from)
+}
+
+fn key_name(key: &VariantKey<String>) -> &str {
+    match key {
+        VariantKey::Identifier { name, .. } => name,
+        VariantKey::NumberLiteral { value, .. } => value,
+    }
+}
+
+fn select_expression() -> impl Strategy<Value = Expression<String>> {
+    let selector = prop_oneof![
+        identifier().prop_map(|id| InlineExpression::VariableReference {
+            id,
+            span: Span::default()
+        }),
+        (function_identifier(), call_arguments()).prop_map(|(id, arguments)| {
+            InlineExpression::FunctionReference {
+                id,
+                arguments,
+                span: Span::default(),
+            }
+        }),
+        (identifier(), identifier()).prop_map(|(id, attribute)| InlineExpression::TermReference {
+            id,
+            attribute: Some(attribute),
+            arguments: None,
+            span: Span::default(),
+        }),
+    ];
+    let key = prop_oneof![
+        identifier().prop_map(|Identifier { name, .. }| VariantKey::Identifier {
+            name,
+            span: Span::default()
+        }),
+        "[0-9]{1,2}".prop_map(|value| VariantKey::NumberLiteral {
+            value,
+            span: Span::default()
+        }),
+    ];
+
+    (
+        selector,
+        prop::collection::vec((key, inline_pattern()), 1..4),
+        any::<prop::sample::Index>(),
+    )
+        .prop_map(|(selector, mut variants, default)| {
+            variants.sort_by(|a, b| key_name(&a.0).cmp(key_name(&b.0)));
+            variants.dedup_by(|a, b| key_name(&a.0) == key_name(&b.0));
+            let default = default.index(variants.len());
+            Expression::Select {
+                selector,
+                variants: variants
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (key, value))| Variant {
+                        key,
+                        value,
+                        default: i == default,
+                        span: Span::default(),
+                    })
+                    .collect(),
+                span: Span::default(),
+            }
+        })
+}
+
+fn pattern() -> impl Strategy<Value = Pattern<String>> {
+    prop::collection::vec(
+        prop_oneof![
+            3 => text().prop_map(|value| PatternElement::TextElement { value, span: Span::default() }),
+            2 => inline_expression().prop_map(|expression| PatternElement::Placeable {
+                expression: Expression::Inline(expression),
+                span: Span::default(),
+            }),
+            1 => select_expression().prop_map(|expression| PatternElement::Placeable { expression, span: Span::default() }),
+        ],
+        1..4,
+    )
+    .prop_map(pattern_from)
+}
+
+fn comment() -> impl Strategy<Value = Comment<String>> {
+    prop::collection::vec("([a-zA-Z0-9]([a-zA-Z0-9 ]{0,10}[a-zA-Z0-9])?)?", 1..3).prop_map(
+        |content| Comment {
+            content,
+            span: Span::default(),
+        },
+    )
+}
+
+fn attributes() -> impl Strategy<Value = Vec<Attribute<String>>> {
+    prop::collection::vec((identifier(), pattern()), 0..3).prop_map(|attributes| {
+        attributes
+            .into_iter()
+            .map(|(id, value)| Attribute {
+                id,
+                value,
+                span: Span::default(),
+            })
+            .collect()
+    })
+}
+
+fn entry() -> impl Strategy<Value = Entry<String>> {
+    prop_oneof![
+        (
+            identifier(),
+            proptest::option::of(pattern()),
+            attributes(),
+            proptest::option::of(comment())
+        )
+            .prop_filter(
+                "A message needs a value or an attribute",
+                |(_, value, attributes, _)| { value.is_some() || !attributes.is_empty() }
+            )
+            .prop_map(|(id, value, attributes, comment)| Entry::Message(Message {
+                id,
+                value,
+                attributes,
+                comment,
+                span: Span::default(),
+            })),
+        (
+            identifier(),
+            pattern(),
+            attributes(),
+            proptest::option::of(comment())
+        )
+            .prop_map(|(id, value, attributes, comment)| Entry::Term(Term {
+                id,
+                value,
+                attributes,
+                comment,
+                span: Span::default(),
+            })),
+        comment().prop_map(Entry::Comment),
+        comment().prop_map(Entry::GroupComment),
+        comment().prop_map(Entry::ResourceComment),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn roundtrip_arbitrary_resources(
+        body in prop::collection::vec(entry(), 0..6),
+        indent in 1usize..8,
+    ) {
+        let resource = Resource { body, span: Span::default() };
+        let serialized = serialize_with_options(&resource, Options { indent, ..Default::default() });
+        let mut reparsed = parse(serialized.clone()).unwrap_or_else(|(res, _)| res);
+        // The generated nodes have no spans.
+        strip_spans(&mut reparsed);
+
+        prop_assert_eq!(reparsed, resource, "Serialized as:\n{}", serialized);
+    }
+}
@@ -0,0 +1,121 @@
+use fluent_syntax_for_carbide::ast;
+use fluent_syntax_for_carbide::parser::{parse, parse_runtime};
+use fluent_syntax_for_carbide::visitor::{self, Visitor};
+
+const FTL: &str = r#"## Group
+
+# Comment
+hello = Hello, { $name }!
+    .title = { -brand(case: "title") }
+-brand = Fluent
+emails = { $count ->
+        [one] One email
+       *[other] { NUMBER($count, minimumFractionDigits: 2) } emails
+    }
+broken = {
+"#;
+
+/// Collects the source of the nodes that are visited.
+struct Sources<'ast> {
+    source: &'ast str,
+    patterns: Vec<&'ast str>,
+    expressions: Vec<&'ast str>,
+    text: Vec<(&'ast str, &'ast str)>,
+}
+
+impl<'ast> Visitor<'ast, &'ast str> for Sources<'ast> {
+    fn visit_pattern(&mut self, pattern: &'ast ast::Pattern<&'ast str>) {
+        self.patterns.push(&self.source[pattern.span.range()]);
+        visitor::walk_pattern(self, pattern);
+    }
+
+    fn visit_pattern_element(&mut self, element: &'ast ast::PatternElement<&'ast str>) {
+        if let ast::PatternElement::TextElement { value, span } = element {
+            self.text.push((value, &self.source[span.range()]));
+        }
+        visitor::walk_pattern_element(self, element);
+    }
+
+    fn visit_inline_expression(&mut self, expression: &'ast ast::InlineExpression<&'ast str>) {
+        self.expressions
+            .push(&self.source[expression.span().range()]);
+        visitor::walk_inline_expression(self, expression);
+    }
+}
+
+#[test]
+fn spans_of_entries() {
+    let (resource, _) = parse(FTL).expect_err("The resource has junk.");
+
+    let sources: Vec<&str> = resource
+        .body
+        .iter()
+        .map(|entry| &FTL[entry.span().range()])
+        .collect();
+
+    assert_eq!(
+        sources,
+        vec![
+            "## Group",
+            "# Comment\nhello = Hello, { $name }!\n    .title = { -brand(case: \"title\") }",
+            "-brand = Fluent",
+            "emails = { $count ->\n        [one] One email\n       *[other] { NUMBER($count, minimumFractionDigits: 2) } emails\n    }",
+            "broken = {\n",
+        ]
+    );
+    assert_eq!(resource.span.range(), 0..FTL.len());
+}
+
+#[test]
+fn spans_of_nodes() {
+    let resource = parse_runtime(FTL).unwrap_or_else(|(res, _)| res);
+
+    let mut sources = Sources {
+        source: FTL,
+        patterns: vec![],
+        expressions: vec![],
+        text: vec![],
+    };
+    sources.visit_resource(&resource);
+
+    assert_eq!(
+        sources.patterns,
+        vec![
+            "Hello, { $name }!",
+            "{ -brand(case: \"title\") }",
+            "Fluent",
+            "{ $count ->\n        [one] One email\n       *[other] { NUMBER($count, minimumFractionDigits: 2) } emails\n    }",
+            "One email",
+            "{ NUMBER($count, minimumFractionDigits: 2) } emails",
+        ]
+    );
+    assert_eq!(
+        sources.expressions,
+        vec![
+            "$name",
+            "-brand(case: \"title\")",
+            "\"title\"",
+            "$count",
+            "NUMBER($count, minimumFractionDigits: 2)",
+            "$count",
+            "2",
+        ]
+    );
+    for (value, source) in sources.text {
+        assert_eq!(value, source);
+    }
+
+    if let ast::Entry::Message(message) = &resource.body[2] {
+        let select = message.value.as_ref().unwrap().elements[0].span();
+        assert_eq!(&FTL[select.range()], sources.patterns[3]);
+
+        if let ast::PatternElement::Placeable { expression, .. } =
+            &message.value.as_ref().unwrap().elements[0]
+        {
+            assert_eq!(
+                &FTL[expression.span().range()],
+                "$count ->\n        [one] One email\n       *[other] { NUMBER($count, minimumFractionDigits: 2) } emails"
+            );
+        }
+    }
+}
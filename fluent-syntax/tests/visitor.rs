@@ -0,0 +1,139 @@
+use fluent_syntax_for_carbide::ast;
+use fluent_syntax_for_carbide::parser::parse;
+use fluent_syntax_for_carbide::serializer::serialize;
+use fluent_syntax_for_carbide::visitor::{self, Visitor, VisitorMut};
+
+const FTL: &str = r#"### Resource comment
+
+## Group comment
+
+# Message comment
+hello = Hello, { $name }!
+    .title = { -brand(case: "title") }
+-brand = Fluent
+emails = { $count ->
+        [one] One email from { other.attr }
+       *[other] { NUMBER($count, minimumFractionDigits: 0) } emails
+    }
+"#;
+
+#[derive(Default)]
+struct IdentifierCollector<'ast> {
+    identifiers: Vec<&'ast str>,
+    comments: usize,
+}
+
+impl<'ast> Visitor<'ast, &'ast str> for IdentifierCollector<'ast> {
+    fn visit_comment(&mut self, _comment: &'ast ast::Comment<&'ast str>) {
+        self.comments += 1;
+    }
+
+    fn visit_identifier(&mut self, identifier: &'ast ast::Identifier<&'ast str>) {
+        self.identifiers.push(identifier.name);
+    }
+}
+
+#[test]
+fn visitor_collects_identifiers() {
+    let resource = parse(FTL).expect("Failed to parse an FTL resource.");
+
+    let mut collector = IdentifierCollector::default();
+    collector.visit_resource(&resource);
+
+    assert_eq!(
+        collector.identifiers,
+        vec![
+            "hello",
+            "name",
+            "title",
+            "brand",
+            "case",
+            "brand",
+            "emails",
+            "count",
+            "other",
+            "attr",
+            "NUMBER",
+            "count",
+            "minimumFractionDigits",
+        ]
+    );
+    assert_eq!(collector.comments, 3);
+}
+
+struct StripComments;
+
+impl<S> VisitorMut<S> for StripComments {
+    fn visit_resource_mut(&mut self, resource: &mut ast::Resource<S>) {
+        resource.body.retain(|entry| {
+            !matches!(
+                entry,
+                ast::Entry::Comment(_)
+                    | ast::Entry::GroupComment(_)
+                    | ast::Entry::ResourceComment(_)
+            )
+        });
+        visitor::walk_resource_mut(self, resource);
+    }
+
+    fn visit_message_mut(&mut self, message: &mut ast::Message<S>) {
+        message.comment = None;
+        visitor::walk_message_mut(self, message);
+    }
+
+    fn visit_term_mut(&mut self, term: &mut ast::Term<S>) {
+        term.comment = None;
+        visitor::walk_term_mut(self, term);
+    }
+}
+
+#[test]
+fn visitor_mut_strips_comments() {
+    let mut resource = parse(FTL).expect("Failed to parse an FTL resource.");
+
+    StripComments.visit_resource_mut(&mut resource);
+
+    assert!(serialize(&resource).starts_with("hello = Hello, { $name }!\n"));
+    assert_eq!(resource.body.len(), 3);
+}
+
+struct RenameMessage<'s> {
+    from: &'s str,
+    to: &'s str,
+}
+
+impl<'s> VisitorMut<&'s str> for RenameMessage<'s> {
+    fn visit_message_mut(&mut self, message: &mut ast::Message<&'s str>) {
+        if message.id.name == self.from {
+            message.id.name = self.to;
+        }
+        visitor::walk_message_mut(self, message);
+    }
+
+    fn visit_inline_expression_mut(&mut self, expression: &mut ast::InlineExpression<&'s str>) {
+        if let ast::InlineExpression::MessageReference { id, .. } = expression {
+            if id.name == self.from {
+                id.name = self.to;
+            }
+        }
+        visitor::walk_inline_expression_mut(self, expression);
+    }
+}
+
+#[test]
+fn visitor_mut_renames_messages() {
+    let mut resource =
+        parse("other = Other\n    .attr = Attribute\nref = { other.attr } and { { other } }\n")
+            .expect("Failed to parse an FTL resource.");
+
+    RenameMessage {
+        from: "other",
+        to: "renamed",
+    }
+    .visit_resource_mut(&mut resource);
+
+    assert_eq!(
+        serialize(&resource),
+        "renamed = Other\n    .attr = Attribute\nref = { renamed.attr } and {{ renamed }}\n"
+    );
+}
@@ -77,6 +77,15 @@
 
 pub use fluent_bundle_for_carbide::*;
 
+/// Embeds an `.ftl` file as a [`FluentResource`], reporting its syntax errors at
+/// compile time.
+///
+/// ```ignore
+/// let res = fluent::include_ftl!("./translations/en-US/main.ftl");
+/// ```
+#[cfg(feature = "macros")]
+pub use fluent_macros_for_carbide::include_ftl;
+
 /// A helper macro to simplify creation of FluentArgs.
 ///
 /// # Example
@@ -0,0 +1,19 @@
+#[cfg(feature = "macros")]
+#[test]
+fn test_include_ftl() {
+    use fluent_for_carbide::{fluent_args, FluentBundle};
+
+    let res = fluent_for_carbide::include_ftl!("./tests/resources/main.ftl");
+    assert_eq!(res.source(), include_str!("./resources/main.ftl"));
+
+    let mut bundle = FluentBundle::default();
+    bundle.set_use_isolating(false);
+    bundle.add_resource(res).unwrap();
+
+    let msg = bundle.get_message("intro").unwrap();
+    let mut errors = vec![];
+    let args = fluent_args!["name" => "John"];
+    let value = bundle.format_pattern(msg.value().unwrap(), Some(&args), &mut errors);
+    assert_eq!(value, "Welcome, John.");
+    assert!(errors.is_empty());
+}
@@ -0,0 +1,2 @@
+hello-world = Hello, world!
+intro = Welcome, { $name }.